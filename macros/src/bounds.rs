@@ -0,0 +1,93 @@
+use super::macro_args::{MacroArgs, VariantArgs};
+use darling::FromAttributes;
+use proc_macro2::Ident;
+use syn::{Data, Field, GenericArgument, Generics, PathArguments, Type, TypeParamBound};
+
+/// Returns whether a type uses a type parameter, ignoring `PhantomData`, which doesn't read or write anything.
+fn uses_type_param(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            let uses_qself = match &type_path.qself {
+                Some(qself) => uses_type_param(&qself.ty, param),
+                None => false,
+            };
+            let starts_with_param = type_path.qself.is_none()
+                && matches!(type_path.path.segments.first(), Some(segment) if segment.ident == *param);
+
+            uses_qself
+                || starts_with_param
+                || type_path.path.segments.iter().any(|segment| {
+                    if segment.ident == "PhantomData" {
+                        return false;
+                    }
+
+                    match &segment.arguments {
+                        PathArguments::AngleBracketed(args) => {
+                            args.args.iter().any(|arg| match arg {
+                                GenericArgument::Type(ty) => uses_type_param(ty, param),
+                                _ => false,
+                            })
+                        }
+                        _ => false,
+                    }
+                })
+        }
+        Type::Array(array) => uses_type_param(&array.elem, param),
+        Type::Slice(slice) => uses_type_param(&slice.elem, param),
+        Type::Reference(reference) => uses_type_param(&reference.elem, param),
+        Type::Ptr(ptr) => uses_type_param(&ptr.elem, param),
+        Type::Paren(paren) => uses_type_param(&paren.elem, param),
+        Type::Group(group) => uses_type_param(&group.elem, param),
+        Type::Tuple(tuple) => tuple.elems.iter().any(|elem| uses_type_param(elem, param)),
+        _ => false,
+    }
+}
+
+/// Returns the fields that are read or written with the stream methods, which excludes the fields of the catch_all variant of an enum.
+fn data_fields(data: &Data) -> Vec<&Field> {
+    match data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .filter(|variant| {
+                let args = VariantArgs::from_attributes(&variant.attrs).unwrap_or_default();
+                !args.catch_all
+            })
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => vec![],
+    }
+}
+
+/// Adds `bound` to each type parameter used by a field that's read or written,
+/// so generic types don't need to repeat the bound on their definition.
+/// Skipped fields and fields that `is_custom` returns true for, such as `with` fields, don't add bounds.
+pub fn add_field_bounds(
+    generics: &Generics,
+    data: &Data,
+    bound: TypeParamBound,
+    is_custom: fn(&MacroArgs) -> bool,
+) -> syn::Result<Generics> {
+    let mut field_types = vec![];
+    for field in data_fields(data) {
+        let args = MacroArgs::from_attributes(&field.attrs)?;
+
+        if !args.skip && !is_custom(&args) {
+            field_types.push(args.map_from()?.unwrap_or_else(|| field.ty.clone()));
+        }
+    }
+
+    let mut generics = generics.clone();
+    for param in generics.type_params_mut() {
+        let is_used = field_types
+            .iter()
+            .any(|field_type| uses_type_param(field_type, &param.ident));
+
+        if is_used {
+            param.bounds.push(bound.clone());
+        }
+    }
+
+    Ok(generics)
+}
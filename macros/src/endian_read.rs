@@ -1,26 +1,145 @@
-use super::macro_args::MacroArgs;
+use super::{
+    bounds::add_field_bounds,
+    endian_write::create_len_of,
+    macro_args::{check_rest, field_name, ContainerArgs, MacroArgs},
+    tagged_enum::{TaggedEnum, TaggedVariant},
+};
 use darling::FromAttributes;
 use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span};
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
 use syn::{
-    parse_macro_input, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput, Field,
-    Fields, Type, TypeArray,
+    parse_macro_input, parse_quote, Data, DataStruct, DeriveInput, Field, Fields, Lit, Type,
+    TypeArray,
 };
 
+/// Named fields are read into a variable of the same name, and tuple fields into `field_0`, `field_1`, and so on.
+pub fn create_field_ident(index: usize, field: &Field) -> Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => format_ident!("field_{}", index),
+    }
+}
+
+/// Errors with `Error::InvalidData` if an integer field doesn't match its magic,
+/// or with `Error::UnexpectedByte` at the first byte of a byte string field that doesn't match.
+fn create_magic_check(
+    struct_name: &str,
+    field_name: &str,
+    field: &Field,
+    field_ident: &Ident,
+    magic: Option<&Lit>,
+) -> proc_macro2::TokenStream {
+    let field_type = &field.ty;
+
+    match magic {
+        Some(magic @ Lit::Int(_)) => quote! {
+            if #field_ident != #magic {
+                return Err(::no_std_io::Error::InvalidData {
+                    offset: field_offset,
+                    expected: #magic as u64,
+                    found: #field_ident as u64,
+                    description: Some("Invalid magic"),
+                }
+                .in_field(#struct_name, #field_name));
+            }
+        },
+        Some(magic) => quote! {
+            let magic: #field_type = *#magic;
+            if let Some(index) = (0..magic.len()).find(|index| #field_ident[*index] != magic[*index]) {
+                return Err(::no_std_io::Error::UnexpectedByte {
+                    offset: field_offset + index,
+                    expected: magic[index],
+                    found: #field_ident[index],
+                }
+                .in_field(#struct_name, #field_name));
+            }
+        },
+        None => quote! {},
+    }
+}
+
 fn create_field(
+    struct_name: &str,
+    index: usize,
     field: &Field,
+    field_ident: &Ident,
+    len_from: Option<&Ident>,
     field_method: &proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    let field_ident = field.ident.as_ref().expect("Field should have identity");
-    let pad_before = match MacroArgs::from_attributes(&field.attrs).ok() {
-        Some(MacroArgs { pad_before }) => {
-            quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_before); }
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_name = field_name(index, field);
+    let args = MacroArgs::from_attributes(&field.attrs)?;
+    // A strict len_of field is checked once every field is read, so its offset has to be in scope then.
+    let is_strict = match (args.strict, &args.len_of) {
+        (false, _) => false,
+        (true, None) => {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "strict needs a len_of attribute, but {} doesn't have one",
+                    field_name
+                ),
+            ))
         }
-        _ => quote! {},
+        (true, Some(_)) if args.skip || args.rest || args.default_on_eof => {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "strict len_of fields are checked after they're read, so {} can't be skip, rest, or default_on_eof",
+                    field_name
+                ),
+            ))
+        }
+        (true, Some(_)) => true,
+    };
+    let align = match args.align()? {
+        Some(align) => quote! {
+            ::no_std_io::StreamReader::align_to(&mut stream, #align)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+        None => quote! {},
+    };
+    let MacroArgs {
+        pad_before,
+        pad_after,
+        skip,
+        default_on_eof,
+        rest,
+        ..
+    } = args;
+    let pad_before = match pad_before {
+        0 => quote! {},
+        _ => quote! {
+            ::no_std_io::Cursor::checked_increment_by(&mut stream, #pad_before)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+    };
+    let pad_after = match pad_after {
+        0 => quote! {},
+        _ => quote! {
+            ::no_std_io::StreamReader::skip(&mut stream, #pad_after)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
     };
 
-    let field_method = match &field.ty {
+    if skip {
+        return Ok(quote! {
+            #pad_before
+            #align
+            let #field_ident = ::core::default::Default::default();
+            #pad_after
+        });
+    }
+
+    // Mapped fields are read as their map_from type, then converted.
+    let field_type = &field.ty;
+    let wire_type = args.map_from()?.unwrap_or_else(|| field_type.clone());
+    let read_with = args.read_with()?;
+    let field_method = match &wire_type {
+        _ if read_with.is_some() => syn::Ident::new(
+            &field_method.to_string().replace("read_stream", "read"),
+            Span::call_site(),
+        ),
         Type::Array(TypeArray { elem, .. }) if &elem.to_token_stream().to_string() != "u8" => {
             syn::Ident::new(
                 &field_method.to_string().replace("read", "read_array"),
@@ -30,73 +149,390 @@ fn create_field(
         _ => syn::Ident::new(&field_method.to_string(), Span::call_site()),
     };
 
-    quote! {
+    let magic = args.magic()?;
+    let map = args.map()?;
+    let validate = args.validate()?;
+    let field_offset = match (magic, &map, &validate) {
+        _ if is_strict => {
+            let len_of_offset = format_ident!("len_of_offset_{}", index);
+            quote! {
+                let field_offset = ::no_std_io::Cursor::get_index(&stream);
+                let #len_of_offset = field_offset;
+            }
+        }
+        (Some(_), _, _) | (_, Some((_, true)), _) | (_, _, Some(_)) => {
+            quote! { let field_offset = ::no_std_io::Cursor::get_index(&stream); }
+        }
+        _ => quote! {},
+    };
+    let check_magic = create_magic_check(struct_name, &field_name, field, field_ident, magic);
+
+    // A with module reads the whole field, so it takes the place of len_from and rest.
+    let read_value = match (&read_with, len_from) {
+        (Some(with), _) => quote! { #with::#field_method(&mut stream) },
+        (None, Some(len_from)) => {
+            let vec_method = syn::Ident::new(
+                &field_method
+                    .to_string()
+                    .replace("read_stream", "read_stream_count_vec"),
+                Span::call_site(),
+            );
+            quote! {
+                <usize as ::core::convert::TryFrom<_>>::try_from(#len_from)
+                    .map_err(|_| ::no_std_io::Error::InvalidRead {
+                        message: "Count does not fit in a usize",
+                    })
+                    .and_then(|count| ::no_std_io::StreamReader::#vec_method(&mut stream, count))
+            }
+        }
+        (None, None) => quote! { ::no_std_io::StreamReader::#field_method(&mut stream) },
+    };
+
+    let map_value = match map {
+        Some((map, false)) => quote! { let #field_ident: #field_type = #map(#field_ident); },
+        Some((try_map, true)) => quote! {
+            let #field_ident: #field_type = ::no_std_io::ResultExt::context_offset(
+                #try_map(#field_ident),
+                field_offset,
+                bytes.len(),
+            )
+            .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+        None => quote! {},
+    };
+
+    let validate = match validate {
+        Some(validate) => quote! {
+            ::no_std_io::ResultExt::context_offset(#validate(&#field_ident), field_offset, bytes.len())
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+        None => quote! {},
+    };
+
+    if rest && read_with.is_none() {
+        return Ok(quote! {
+            #pad_before
+            #align
+            let mut #field_ident: #field_type = ::core::default::Default::default();
+            while !::no_std_io::StreamRemaining::is_at_end(&stream) {
+                #field_ident.push(
+                    ::no_std_io::StreamReader::#field_method(&mut stream)
+                        .map_err(|error| error.in_field(#struct_name, #field_name))?,
+                );
+            }
+            #pad_after
+        });
+    }
+
+    let read_field = quote! {
         #pad_before
-        let #field_ident = ::no_std_io::StreamReader::#field_method(&mut stream)?;
+        #align
+        #field_offset
+        let #field_ident: #wire_type = #read_value
+            .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        #map_value
+        #validate
+        #check_magic
+        #pad_after
+    };
+
+    if default_on_eof {
+        return Ok(quote! {
+            let #field_ident = if ::no_std_io::StreamRemaining::is_at_end(&stream) {
+                ::core::default::Default::default()
+            } else {
+                #read_field
+                #field_ident
+            };
+        });
+    }
+
+    Ok(read_field)
+}
+
+/// Checks that only the final fields have the `default_on_eof` attribute, since the data can only end early once.
+/// Skipped fields aren't read, so they can be anywhere.
+fn check_default_on_eof(struct_name: &str, fields: &Fields) -> syn::Result<()> {
+    let mut has_default_on_eof = false;
+
+    for field in fields.iter() {
+        let args = MacroArgs::from_attributes(&field.attrs)?;
+
+        if args.default_on_eof {
+            has_default_on_eof = true;
+        } else if has_default_on_eof && !args.skip {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "Fields of {} after a default_on_eof field need to be default_on_eof too",
+                    struct_name
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn create_fields(
+    struct_name: &str,
+    fields: &Fields,
+    field_idents: &[Ident],
+    field_method: &proc_macro2::TokenStream,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    check_default_on_eof(struct_name, fields)?;
+    check_rest(struct_name, fields)?;
+
+    let mut field_tokens = fields
+        .iter()
+        .zip(field_idents)
+        .enumerate()
+        .map(|(index, (field, field_ident))| {
+            let args = MacroArgs::from_attributes(&field.attrs)?;
+            let len_from = args
+                .len_from(fields, index)?
+                .map(|position| &field_idents[position]);
+            create_field(
+                struct_name,
+                index,
+                field,
+                field_ident,
+                len_from,
+                field_method,
+            )
+        })
+        .collect::<syn::Result<Vec<proc_macro2::TokenStream>>>()?;
+
+    for (index, (field, field_ident)) in fields.iter().zip(field_idents).enumerate() {
+        let args = MacroArgs::from_attributes(&field.attrs)?;
+        let (position, is_bytes) = match args.len_of(fields, index)? {
+            Some(len_of) if args.strict => len_of,
+            _ => continue,
+        };
+        let field_name = field_name(index, field);
+        let len_field = fields
+            .iter()
+            .nth(position)
+            .expect("len_of should be a field");
+        let len_ident = &field_idents[position];
+        let len = create_len_of(is_bytes, len_field, &quote! { &#len_ident })?;
+        let len_of_offset = format_ident!("len_of_offset_{}", index);
+
+        field_tokens.push(quote! {
+            {
+                let len: usize = #len;
+                if <usize as ::core::convert::TryFrom<_>>::try_from(#field_ident).ok() != Some(len) {
+                    return Err(::no_std_io::Error::InvalidData {
+                        offset: #len_of_offset,
+                        expected: len as u64,
+                        found: <u64 as ::core::convert::TryFrom<_>>::try_from(#field_ident).unwrap_or(u64::MAX),
+                        description: Some("Length does not match the len_of field"),
+                    }
+                    .in_field(#struct_name, #field_name));
+                }
+            }
+        });
+    }
+
+    Ok(field_tokens)
+}
+
+/// Creates the value from the variables the fields were read into, such as `Self { first, second }`.
+pub fn create_constructor(
+    path: proc_macro2::TokenStream,
+    fields: &Fields,
+    field_values: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote! { #path { #(#names: #field_values),* } }
+        }
+        Fields::Unnamed(_) => quote! { #path(#(#field_values),*) },
+        Fields::Unit => quote! { #path },
     }
 }
 
 fn create_method_impl(
-    fields: &Punctuated<Field, Comma>,
+    struct_name: &str,
+    fields: &Fields,
     impl_method: proc_macro2::TokenStream,
     field_method: proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    let field_tokens = fields
-        .iter()
-        .map(|field| create_field(field, &field_method))
-        .collect::<Vec<proc_macro2::TokenStream>>();
+) -> syn::Result<proc_macro2::TokenStream> {
     let field_idents = fields
         .iter()
-        .map(|field| field.ident.as_ref().expect("Field should have identity"))
-        .collect::<Vec<&Ident>>();
+        .enumerate()
+        .map(|(index, field)| create_field_ident(index, field))
+        .collect::<Vec<Ident>>();
+    let field_tokens = create_fields(struct_name, fields, &field_idents, &field_method)?;
+    let field_values = field_idents
+        .iter()
+        .map(|ident| ident.to_token_stream())
+        .collect::<Vec<proc_macro2::TokenStream>>();
+    let result = create_constructor(quote! { Self }, fields, &field_values);
 
-    quote! {
+    Ok(quote! {
         #[inline(always)]
         fn #impl_method(bytes: &[u8]) -> Result<::no_std_io::ReadOutput<Self>, ::no_std_io::Error> {
             let mut stream = ::no_std_io::StreamContainer::new(bytes);
             #(#field_tokens)*
-            let result = Self {
-                #(#field_idents),*
-            };
+            let result = #result;
             let bytes_read = ::no_std_io::Cursor::get_index(&stream);
 
             Ok(::no_std_io::ReadOutput::new(result, bytes_read))
         }
-    }
+    })
 }
 
-pub fn impl_endian_read(tokens: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(tokens as DeriveInput);
+fn create_variant_arm(
+    enum_name: &str,
+    variant: &TaggedVariant,
+    field_method: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let ident = &variant.ident;
+    let path = quote! { Self::#ident };
+
+    match &variant.tag {
+        Some(tag) => {
+            let struct_name = variant.field_context_name(enum_name);
+            let field_idents = variant.field_bindings();
+            let field_tokens =
+                create_fields(&struct_name, &variant.fields, &field_idents, field_method)?;
+            let field_values = field_idents
+                .iter()
+                .map(|ident| ident.to_token_stream())
+                .collect::<Vec<proc_macro2::TokenStream>>();
+            let result = create_constructor(path, &variant.fields, &field_values);
+
+            Ok(quote! {
+                #tag => {
+                    #(#field_tokens)*
+                    #result
+                }
+            })
+        }
+        None => {
+            let field_values = [
+                quote! { tag },
+                quote! { ::core::convert::From::from(&bytes[index..]) },
+            ];
+            let result = create_constructor(path, &variant.fields, &field_values);
+
+            Ok(quote! {
+                _ => {
+                    let index = ::no_std_io::Cursor::get_index(&stream);
+                    ::no_std_io::Cursor::set_index(&mut stream, bytes.len());
+                    #result
+                }
+            })
+        }
+    }
+}
 
-    let named_fields = match input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
-            ..
-        }) => fields.named,
-        _ => panic!("Only structs can derive EndianRead"),
+fn create_enum_method_impl(
+    enum_name: &str,
+    tagged_enum: &TaggedEnum,
+    impl_method: proc_macro2::TokenStream,
+    field_method: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let tag_type = &tagged_enum.tag_type;
+    let tagged_arms = tagged_enum
+        .variants
+        .iter()
+        .filter(|variant| variant.tag.is_some())
+        .map(|variant| create_variant_arm(enum_name, variant, &field_method))
+        .collect::<syn::Result<Vec<proc_macro2::TokenStream>>>()?;
+    let fallback_arm = match tagged_enum
+        .variants
+        .iter()
+        .find(|variant| variant.tag.is_none())
+    {
+        Some(variant) => create_variant_arm(enum_name, variant, &field_method)?,
+        None => quote! {
+            _ => {
+                return Err(::no_std_io::Error::UnknownTag {
+                    offset: 0,
+                    tag: tag as u64,
+                });
+            }
+        },
     };
 
-    let try_read_le = create_method_impl(
-        &named_fields,
-        quote! { try_read_le },
-        quote! { read_stream_le },
-    );
+    Ok(quote! {
+        #[inline(always)]
+        fn #impl_method(bytes: &[u8]) -> Result<::no_std_io::ReadOutput<Self>, ::no_std_io::Error> {
+            let mut stream = ::no_std_io::StreamContainer::new(bytes);
+            let tag: #tag_type = ::no_std_io::StreamReader::#field_method(&mut stream)
+                .map_err(|error| error.in_field(#enum_name, "tag"))?;
+            let result = match tag {
+                #(#tagged_arms)*
+                #fallback_arm
+            };
+            let bytes_read = ::no_std_io::Cursor::get_index(&stream);
 
-    let try_read_be = create_method_impl(
-        &named_fields,
-        quote! { try_read_be },
-        quote! { read_stream_be },
-    );
+            Ok(::no_std_io::ReadOutput::new(result, bytes_read))
+        }
+    })
+}
 
+fn expand_endian_read(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let struct_name = name.to_string();
+    let container_args = ContainerArgs::from_attributes(&input.attrs)?;
+    let (le_method, be_method) = container_args.field_methods("read_stream")?;
+    let generics = add_field_bounds(
+        &input.generics,
+        &input.data,
+        parse_quote! { ::no_std_io::EndianRead },
+        |args| args.with.is_some() || args.read_with.is_some(),
+    )?;
 
-    let modified = quote! {
+    let (try_read_le, try_read_be) = match input.data {
+        Data::Struct(DataStruct { fields, .. }) => (
+            create_method_impl(&struct_name, &fields, quote! { try_read_le }, le_method)?,
+            create_method_impl(&struct_name, &fields, quote! { try_read_be }, be_method)?,
+        ),
+        Data::Enum(data) => {
+            let tagged_enum =
+                TaggedEnum::new("EndianRead", &name, container_args.tag_type.as_ref(), data)?;
+            (
+                create_enum_method_impl(
+                    &struct_name,
+                    &tagged_enum,
+                    quote! { try_read_le },
+                    le_method,
+                )?,
+                create_enum_method_impl(
+                    &struct_name,
+                    &tagged_enum,
+                    quote! { try_read_be },
+                    be_method,
+                )?,
+            )
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Only structs and enums can derive EndianRead",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
         impl #impl_generics ::no_std_io::EndianRead for #name #ty_generics #where_clause {
             #try_read_le
             #try_read_be
         }
-    };
+    })
+}
+
+pub fn impl_endian_read(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
 
-    modified.into()
+    match expand_endian_read(input) {
+        Ok(modified) => modified.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
 }
@@ -0,0 +1,301 @@
+use super::{
+    bounds::add_field_bounds,
+    endian_read::{create_constructor, create_field_ident},
+    macro_args::{check_rest, field_name, ContainerArgs, MacroArgs},
+};
+use darling::FromAttributes;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input, parse_quote, Data, DataStruct, DeriveInput, Field, Fields, Generics, Ident,
+    Lifetime, Type,
+};
+
+/// Returns the lifetime of the bytes the struct borrows from, which is its only lifetime parameter.
+fn borrowed_lifetime(name: &Ident, generics: &Generics) -> syn::Result<Lifetime> {
+    let mut lifetimes = generics.lifetimes();
+
+    match (lifetimes.next(), lifetimes.next()) {
+        (Some(lifetime), None) => Ok(lifetime.lifetime.clone()),
+        _ => Err(syn::Error::new_spanned(
+            name,
+            format!(
+                "{} needs exactly one lifetime parameter to derive EndianReadBorrowed, such as {}<'a>",
+                name, name
+            ),
+        )),
+    }
+}
+
+/// Returns the name of the first attribute the derive can't handle, since borrowed fields are read from the data as-is.
+fn unsupported_attribute(args: &MacroArgs) -> Option<&'static str> {
+    let attributes = [
+        ("default_on_eof", args.default_on_eof),
+        ("len_of", args.len_of.is_some()),
+        ("len_kind", args.len_kind.is_some()),
+        ("strict", args.strict),
+        ("magic", args.magic.is_some()),
+        ("with", args.with.is_some()),
+        ("read_with", args.read_with.is_some()),
+        ("write_with", args.write_with.is_some()),
+        ("map_from", args.map_from.is_some()),
+        ("map", args.map.is_some()),
+        ("try_map", args.try_map.is_some()),
+        ("unmap", args.unmap.is_some()),
+        ("validate", args.validate.is_some()),
+    ];
+
+    attributes
+        .iter()
+        .find(|(_, is_set)| *is_set)
+        .map(|(attribute, _)| *attribute)
+}
+
+/// Returns whether a field borrows a slice of the data, such as `&'a [u8]` or `&'a str`,
+/// which would take every remaining byte unless its length comes from `len_from`.
+fn is_borrowed_slice(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => match reference.elem.as_ref() {
+            Type::Slice(_) => true,
+            Type::Path(path) => path.qself.is_none() && path.path.is_ident("str"),
+            _ => false,
+        },
+        Type::Paren(paren) => is_borrowed_slice(&paren.elem),
+        Type::Group(group) => is_borrowed_slice(&group.elem),
+        _ => false,
+    }
+}
+
+fn create_field(
+    struct_name: &str,
+    index: usize,
+    field: &Field,
+    field_ident: &Ident,
+    len_from: Option<&Ident>,
+    lifetime: &Lifetime,
+    field_method: &Ident,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_name = field_name(index, field);
+    let args = MacroArgs::from_attributes(&field.attrs)?;
+
+    if let Some(attribute) = unsupported_attribute(&args) {
+        return Err(syn::Error::new_spanned(
+            field,
+            format!(
+                "{} isn't supported by EndianReadBorrowed, but {}.{} uses it",
+                attribute, struct_name, field_name
+            ),
+        ));
+    }
+
+    if is_borrowed_slice(&field.ty) && !args.skip && !args.rest && args.len_from.is_none() {
+        return Err(syn::Error::new_spanned(
+            field,
+            format!(
+                "{}.{} borrows a slice, so it needs len_from, or rest if it's the last field",
+                struct_name, field_name
+            ),
+        ));
+    }
+
+    let align = match args.align()? {
+        Some(align) => quote! {
+            ::no_std_io::StreamReader::align_to(&mut stream, #align)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+        None => quote! {},
+    };
+    let MacroArgs {
+        pad_before,
+        pad_after,
+        skip,
+        ..
+    } = args;
+    let pad_before = match pad_before {
+        0 => quote! {},
+        _ => quote! {
+            ::no_std_io::Cursor::checked_increment_by(&mut stream, #pad_before)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+    };
+    let pad_after = match pad_after {
+        0 => quote! {},
+        _ => quote! {
+            ::no_std_io::StreamReader::skip(&mut stream, #pad_after)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+    };
+
+    if skip {
+        return Ok(quote! {
+            #pad_before
+            #align
+            let #field_ident = ::core::default::Default::default();
+            #pad_after
+        });
+    }
+
+    // A len_from field is read from a window of that many bytes, and the window is consumed even if the field reads less of it.
+    // Other fields, including rest fields, are given the rest of the data.
+    let (field_bytes, read_bytes) = match len_from {
+        Some(len_from) => (
+            quote! {
+                <usize as ::core::convert::TryFrom<_>>::try_from(#len_from)
+                    .map_err(|_| ::no_std_io::Error::InvalidRead {
+                        message: "Count does not fit in a usize",
+                    })
+                    .and_then(|count| {
+                        index
+                            .checked_add(count)
+                            .filter(|end| *end <= bytes.len())
+                            .map(|end| &bytes[index..end])
+                            .ok_or(::no_std_io::Error::InvalidSize {
+                                wanted_size: count,
+                                offset: index,
+                                data_len: bytes.len(),
+                            })
+                    })
+                    .map_err(|error| error.in_field(#struct_name, #field_name))?
+            },
+            quote! { field_bytes.len() },
+        ),
+        None => (
+            quote! { bytes.get(index..).unwrap_or_default() },
+            quote! { output.get_read_bytes() },
+        ),
+    };
+    let field_type = &field.ty;
+
+    Ok(quote! {
+        #pad_before
+        #align
+        let index = ::no_std_io::Cursor::get_index(&stream);
+        let field_bytes: &#lifetime [u8] = #field_bytes;
+        let output = ::no_std_io::ResultExt::context_offset(
+            <#field_type as ::no_std_io::EndianReadBorrowed<#lifetime>>::#field_method(field_bytes),
+            index,
+            bytes.len(),
+        )
+        .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        ::no_std_io::Cursor::increment_by(&mut stream, #read_bytes);
+        let #field_ident = output.into_data();
+        #pad_after
+    })
+}
+
+fn create_method_impl(
+    struct_name: &str,
+    fields: &Fields,
+    lifetime: &Lifetime,
+    impl_method: proc_macro2::TokenStream,
+    field_method: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    check_rest(struct_name, fields)?;
+
+    let field_method = format_ident!("{}_borrowed", field_method.to_string());
+    let field_idents = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| create_field_ident(index, field))
+        .collect::<Vec<Ident>>();
+    let field_tokens = fields
+        .iter()
+        .zip(&field_idents)
+        .enumerate()
+        .map(|(index, (field, field_ident))| {
+            let args = MacroArgs::from_attributes(&field.attrs)?;
+            let len_from = args
+                .len_from(fields, index)?
+                .map(|position| &field_idents[position]);
+            create_field(
+                struct_name,
+                index,
+                field,
+                field_ident,
+                len_from,
+                lifetime,
+                &field_method,
+            )
+        })
+        .collect::<syn::Result<Vec<proc_macro2::TokenStream>>>()?;
+    let field_values = field_idents
+        .iter()
+        .map(|ident| ident.to_token_stream())
+        .collect::<Vec<proc_macro2::TokenStream>>();
+    let result = create_constructor(quote! { Self }, fields, &field_values);
+
+    Ok(quote! {
+        #[inline(always)]
+        fn #impl_method(bytes: &#lifetime [u8]) -> Result<::no_std_io::ReadOutput<Self>, ::no_std_io::Error> {
+            let mut stream = ::no_std_io::StreamContainer::new(bytes);
+            #(#field_tokens)*
+            let result = #result;
+            let bytes_read = ::no_std_io::Cursor::get_index(&stream);
+
+            Ok(::no_std_io::ReadOutput::new(result, bytes_read))
+        }
+    })
+}
+
+fn expand_endian_read_borrowed(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = input.ident;
+    let struct_name = name.to_string();
+    let container_args = ContainerArgs::from_attributes(&input.attrs)?;
+    let (le_method, be_method) = container_args.field_methods("try_read")?;
+
+    let fields = match input.data {
+        Data::Struct(DataStruct { ref fields, .. }) => fields,
+        Data::Enum(data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                "Only structs can derive EndianReadBorrowed",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Only structs can derive EndianReadBorrowed",
+            ))
+        }
+    };
+
+    let lifetime = borrowed_lifetime(&name, &input.generics)?;
+    let generics = add_field_bounds(
+        &input.generics,
+        &input.data,
+        parse_quote! { ::no_std_io::EndianReadBorrowed<#lifetime> },
+        |_| false,
+    )?;
+    let try_read_le = create_method_impl(
+        &struct_name,
+        fields,
+        &lifetime,
+        quote! { try_read_le_borrowed },
+        le_method,
+    )?;
+    let try_read_be = create_method_impl(
+        &struct_name,
+        fields,
+        &lifetime,
+        quote! { try_read_be_borrowed },
+        be_method,
+    )?;
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::no_std_io::EndianReadBorrowed<#lifetime> for #name #ty_generics #where_clause {
+            #try_read_le
+            #try_read_be
+        }
+    })
+}
+
+pub fn impl_endian_read_borrowed(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    match expand_endian_read_borrowed(input) {
+        Ok(modified) => modified.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
@@ -1,54 +1,236 @@
-use super::macro_args::MacroArgs;
+use super::{
+    bounds::add_field_bounds,
+    macro_args::{check_rest, field_name, ContainerArgs, MacroArgs},
+    tagged_enum::{TaggedEnum, TaggedVariant},
+};
 use darling::FromAttributes;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
 use syn::{
-    self, parse_macro_input, punctuated::Punctuated, token::Comma, Data, DataStruct, DeriveInput,
-    Field, Fields, Type, TypeArray,
+    self, parse_macro_input, parse_quote, Data, DataStruct, DeriveInput, Field, Fields, Index, Lit,
+    Member, Type, TypeArray,
 };
 
-fn create_get_size_field(field: &Field) -> proc_macro2::TokenStream {
-    let field_ident = field.ident.as_ref().expect("Field should have identity");
-    let pad_before = match MacroArgs::from_attributes(&field.attrs).ok() {
-        Some(MacroArgs { pad_before }) => pad_before,
-        _ => 0,
+/// Named fields are accessed by name, and tuple fields by their position, such as `self.0`.
+fn create_field_member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    }
+}
+
+/// Creates a reference to each field, such as `&self.first` or `&self.0`.
+fn create_field_values(fields: &Fields) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let member = create_field_member(index, field);
+            quote! { &self.#member }
+        })
+        .collect()
+}
+
+/// Creates the fields of an enum variant, which are already bound by reference in a match arm.
+fn create_variant_values(variant: &TaggedVariant) -> Vec<proc_macro2::TokenStream> {
+    variant
+        .field_bindings()
+        .iter()
+        .map(|binding| binding.to_token_stream())
+        .collect()
+}
+
+/// Mapped fields are converted back to their map_from type, which is what's measured and written.
+fn create_unmap(
+    args: &MacroArgs,
+    field: &Field,
+    field_value: &proc_macro2::TokenStream,
+) -> syn::Result<(Type, proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+    let wire_type = args.map_from()?.unwrap_or_else(|| field.ty.clone());
+
+    match args.unmap()? {
+        Some(unmap) => Ok((
+            wire_type.clone(),
+            quote! { let value: #wire_type = #unmap(#field_value); },
+            quote! { &value },
+        )),
+        None => Ok((wire_type, quote! {}, field_value.clone())),
+    }
+}
+
+/// Creates the number of bytes a field is written as, not counting its padding or alignment.
+fn create_field_size(
+    args: &MacroArgs,
+    wire_type: &Type,
+    field_value: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let write_with = args.write_with()?;
+    let sum_values = quote! {
+        ::core::iter::Iterator::sum::<usize>(
+            ::core::iter::IntoIterator::into_iter(#field_value).map(::no_std_io::EndianWrite::get_size),
+        )
     };
 
-    let field_size = match &field.ty {
+    Ok(match wire_type {
+        _ if args.skip => quote! { 0 },
+        _ if write_with.is_some() => quote! { #write_with::size(#field_value) },
+        _ if args.len_from.is_some() || args.rest => sum_values,
         Type::Array(TypeArray { elem, .. }) if &elem.to_token_stream().to_string() != "u8" => {
-            quote! {
-                for val in &self.#field_ident {
-                    size += ::no_std_io::EndianWrite::get_size(val);
-                }
-            }
+            sum_values
         }
-        _ => {
-            quote! {
-                size += ::no_std_io::EndianWrite::get_size(&self.#field_ident);
-            }
+        _ => quote! { ::no_std_io::EndianWrite::get_size(#field_value) },
+    })
+}
+
+/// Creates the length a `len_of` field holds for the field it names, which is either the number of values or the number of bytes.
+pub fn create_len_of(
+    is_bytes: bool,
+    field: &Field,
+    field_value: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    if !is_bytes {
+        return Ok(quote! { (#field_value).len() });
+    }
+
+    let args = MacroArgs::from_attributes(&field.attrs)?;
+    let (wire_type, unmap, field_value) = create_unmap(&args, field, field_value)?;
+    let field_size = create_field_size(&args, &wire_type, &field_value)?;
+
+    Ok(quote! {
+        {
+            #unmap
+            #field_size
+        }
+    })
+}
+
+fn create_get_size_field(
+    field: &Field,
+    field_value: &proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let args = MacroArgs::from_attributes(&field.attrs)?;
+    // The size so far is the stream index before this field is written, so the alignment padding is exact.
+    let align = match args.align()? {
+        Some(align) => quote! { size += (#align - size % #align) % #align; },
+        None => quote! {},
+    };
+    let MacroArgs {
+        pad_before,
+        pad_after,
+        skip,
+        ..
+    } = args;
+
+    let (wire_type, unmap, field_value) = create_unmap(&args, field, field_value)?;
+    let field_size = match skip {
+        true => quote! { let _ = #field_value; },
+        false => {
+            let field_size = create_field_size(&args, &wire_type, &field_value)?;
+            quote! { size += #field_size; }
         }
     };
 
-    quote! {
+    Ok(quote! {
         size += #pad_before;
+        #align
+        #unmap
         #field_size
-    }
+        size += #pad_after;
+    })
 }
 
 fn create_write_field(
+    struct_name: &str,
+    index: usize,
     field: &Field,
+    field_value: &proc_macro2::TokenStream,
+    len_of: Option<&proc_macro2::TokenStream>,
     field_method: &proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    let field_ident = field.ident.as_ref().expect("Field should have identity");
-    let pad_before = match MacroArgs::from_attributes(&field.attrs).ok() {
-        Some(MacroArgs { pad_before }) => {
-            quote! { ::no_std_io::Cursor::increment_by(&mut stream, #pad_before); }
-        }
-        _ => quote! {},
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_name = field_name(index, field);
+    let args = MacroArgs::from_attributes(&field.attrs)?;
+    let align = match args.align()? {
+        Some(align) => quote! {
+            ::no_std_io::StreamWriter::pad_to_alignment(&mut stream, #align, 0)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+        None => quote! {},
+    };
+    let MacroArgs {
+        pad_before,
+        pad_after,
+        skip,
+        rest,
+        ..
+    } = args;
+    let is_slice = args.len_from.is_some() || rest;
+    let pad_before = match pad_before {
+        0 => quote! {},
+        _ => quote! {
+            ::no_std_io::Cursor::checked_increment_by(&mut stream, #pad_before)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+    };
+    let pad_after = match pad_after {
+        0 => quote! {},
+        _ => quote! {
+            ::no_std_io::StreamWriter::pad(&mut stream, #pad_after)
+                .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+    };
+
+    if skip {
+        return Ok(quote! {
+          #pad_before
+          #align
+          let _ = #field_value;
+          #pad_after
+        });
+    }
+
+    // Validating before the field is written keeps invalid values from being written.
+    let validate = match args.validate()? {
+        Some(validate) => quote! {
+            let field_offset = ::no_std_io::Cursor::get_index(&stream);
+            ::no_std_io::ResultExt::context_offset(
+                #validate(#field_value),
+                field_offset,
+                ::no_std_io::StreamRemaining::len(&stream),
+            )
+            .map_err(|error| error.in_field(#struct_name, #field_name))?;
+        },
+        None => quote! {},
     };
 
-    let field_method = match &field.ty {
+    // A with module writes the whole field, so it takes the place of the stream methods for len_from and rest.
+    let (wire_type, unmap, unmapped_value) = create_unmap(&args, field, field_value)?;
+    // A len_of field writes the length of the field it names instead of its own value.
+    let (unmap, unmapped_value) = match len_of {
+        Some(len_of) => (
+            quote! {
+                let value: #wire_type = <#wire_type as ::core::convert::TryFrom<usize>>::try_from(#len_of)
+                    .map_err(|_| ::no_std_io::Error::InvalidWrite {
+                        message: "Length does not fit in the len_of field",
+                    })
+                    .map_err(|error| error.in_field(#struct_name, #field_name))?;
+            },
+            quote! { &value },
+        ),
+        None => (unmap, unmapped_value),
+    };
+    let write_with = args.write_with()?;
+    let field_method = match &wire_type {
+        _ if write_with.is_some() => syn::Ident::new(
+            &field_method.to_string().replace("write_stream", "write"),
+            Span::call_site(),
+        ),
+        _ if is_slice => syn::Ident::new(
+            &field_method
+                .to_string()
+                .replace("write_stream", "write_slice_stream"),
+            Span::call_site(),
+        ),
         Type::Array(TypeArray { elem, .. }) if &elem.to_token_stream().to_string() != "u8" => {
             syn::Ident::new(
                 &field_method.to_string().replace("write", "write_array"),
@@ -58,23 +240,125 @@ fn create_write_field(
         _ => syn::Ident::new(&field_method.to_string(), Span::call_site()),
     };
 
-    quote! {
+    // Magic fields always write their constant, regardless of the value of the field.
+    let field_type = &field.ty;
+    let (magic, field_value) = match args.magic()? {
+        Some(magic) => {
+            let magic = match magic {
+                Lit::Int(_) => quote! { #magic },
+                _ => quote! { *#magic },
+            };
+            (
+                quote! {
+                    let _ = #field_value;
+                    let magic: #field_type = #magic;
+                },
+                quote! { &magic },
+            )
+        }
+        None => (quote! {}, unmapped_value),
+    };
+
+    let write_value = match write_with {
+        Some(with) => quote! { #with::#field_method(&mut stream, #field_value) },
+        None => quote! { ::no_std_io::StreamWriter::#field_method(&mut stream, #field_value) },
+    };
+
+    Ok(quote! {
       #pad_before
-      ::no_std_io::StreamWriter::#field_method(&mut stream, &self.#field_ident)?;
+      #align
+      #validate
+      #unmap
+      #magic
+      #write_value
+        .map_err(|error| error.in_field(#struct_name, #field_name))?;
+      #pad_after
+    })
+}
+
+fn create_get_size_fields(
+    fields: &Fields,
+    field_values: &[proc_macro2::TokenStream],
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    fields
+        .iter()
+        .zip(field_values)
+        .map(|(field, field_value)| create_get_size_field(field, field_value))
+        .collect()
+}
+
+fn create_write_fields(
+    struct_name: &str,
+    fields: &Fields,
+    field_values: &[proc_macro2::TokenStream],
+    field_method: &proc_macro2::TokenStream,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    check_rest(struct_name, fields)?;
+
+    let mut len_ofs = vec![None; fields.len()];
+    let mut len_written_by = vec![None; fields.len()];
+    for (index, field) in fields.iter().enumerate() {
+        let args = MacroArgs::from_attributes(&field.attrs)?;
+        if let Some((position, is_bytes)) = args.len_of(fields, index)? {
+            let len_field = fields
+                .iter()
+                .nth(position)
+                .expect("len_of should be a field");
+            len_ofs[index] = Some(create_len_of(is_bytes, len_field, &field_values[position])?);
+            len_written_by[position] = Some(index);
+        }
     }
+
+    fields
+        .iter()
+        .zip(field_values)
+        .enumerate()
+        .map(|(index, (field, field_value))| {
+            let field_name = field_name(index, field);
+            let args = MacroArgs::from_attributes(&field.attrs)?;
+            // The count isn't updated to match the values unless it's a len_of field,
+            // so a mismatch is an error instead of writing data that can't be read back.
+            let check_len = match args.len_from(fields, index)? {
+                Some(position) if len_written_by[index] != Some(position) => {
+                    let len_from = &field_values[position];
+                    quote! {
+                        if <usize as ::core::convert::TryFrom<_>>::try_from(*(#len_from)).ok() != Some((#field_value).len()) {
+                            return Err(::no_std_io::Error::InvalidWrite {
+                                message: "Count field does not match the number of values",
+                            }
+                            .in_field(#struct_name, #field_name));
+                        }
+                    }
+                }
+                _ => quote! {},
+            };
+            let write_field = create_write_field(
+                struct_name,
+                index,
+                field,
+                field_value,
+                len_ofs[index].as_ref(),
+                field_method,
+            )?;
+
+            Ok(quote! {
+                #check_len
+                #write_field
+            })
+        })
+        .collect()
 }
 
 fn create_write_method_impl(
-    fields: &Punctuated<Field, Comma>,
+    struct_name: &str,
+    fields: &Fields,
     impl_method: proc_macro2::TokenStream,
     field_method: proc_macro2::TokenStream,
-) -> proc_macro2::TokenStream {
-    let field_tokens = fields
-        .iter()
-        .map(|field| create_write_field(field, &field_method))
-        .collect::<Vec<proc_macro2::TokenStream>>();
+) -> syn::Result<proc_macro2::TokenStream> {
+    let field_values = create_field_values(fields);
+    let field_tokens = create_write_fields(struct_name, fields, &field_values, &field_method)?;
 
-    quote! {
+    Ok(quote! {
       #[inline(always)]
       fn #impl_method(&self, dst: &mut [u8]) -> Result<usize, ::no_std_io::Error> {
         let mut stream = ::no_std_io::StreamContainer::new(dst);
@@ -82,52 +366,192 @@ fn create_write_method_impl(
         let bytes_written = ::no_std_io::Cursor::get_index(&stream);
         Ok(bytes_written)
       }
-    }
+    })
 }
 
-pub fn impl_endian_write(tokens: TokenStream) -> TokenStream {
-    let input = parse_macro_input!(tokens as DeriveInput);
+fn create_enum_get_size(tagged_enum: &TaggedEnum) -> syn::Result<proc_macro2::TokenStream> {
+    let tag_type = &tagged_enum.tag_type;
+    let arms = tagged_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let pattern = variant.create_pattern();
+            let field_values = create_variant_values(variant);
+            let get_size_fields = match &variant.tag {
+                Some(_) => create_get_size_fields(&variant.fields, &field_values)?,
+                None => {
+                    let bytes = &field_values[1];
+                    vec![quote! { size += ::core::convert::AsRef::<[u8]>::as_ref(#bytes).len(); }]
+                }
+            };
 
-    let named_fields = match input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
-            ..
-        }) => fields.named,
-        _ => panic!("Only structs can derive EndianWrite"),
-    };
+            Ok(quote! {
+                #pattern => {
+                    #(#get_size_fields)*
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<proc_macro2::TokenStream>>>()?;
+
+    Ok(quote! {
+        let mut size = ::core::mem::size_of::<#tag_type>();
+        match self {
+            #(#arms)*
+        }
+        size
+    })
+}
 
-    let get_size_fields = named_fields
+fn create_enum_write_method_impl(
+    enum_name: &str,
+    tagged_enum: &TaggedEnum,
+    impl_method: proc_macro2::TokenStream,
+    field_method: proc_macro2::TokenStream,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let tag_type = &tagged_enum.tag_type;
+    let arms = tagged_enum
+        .variants
         .iter()
-        .map(create_get_size_field)
-        .collect::<Vec<proc_macro2::TokenStream>>();
+        .map(|variant| {
+            let pattern = variant.create_pattern();
+            let struct_name = variant.field_context_name(enum_name);
+            let field_values = create_variant_values(variant);
 
-    let try_write_le = create_write_method_impl(
-        &named_fields,
-        quote! { try_write_le },
-        quote! { write_stream_le },
-    );
+            let (tag, field_tokens) = match &variant.tag {
+                Some(tag) => (
+                    quote! { &#tag },
+                    create_write_fields(
+                        &struct_name,
+                        &variant.fields,
+                        &field_values,
+                        &field_method,
+                    )?,
+                ),
+                None => {
+                    let bytes = &field_values[1];
+                    let bytes_field_name = variant.field_name(1);
+                    (
+                        field_values[0].clone(),
+                        vec![quote! {
+                            ::no_std_io::StreamWriter::write_stream_bytes(
+                                &mut stream,
+                                ::core::convert::AsRef::<[u8]>::as_ref(#bytes),
+                            )
+                            .map_err(|error| error.in_field(#struct_name, #bytes_field_name))?;
+                        }],
+                    )
+                }
+            };
 
-    let try_write_be = create_write_method_impl(
-        &named_fields,
-        quote! { try_write_be },
-        quote! { write_stream_be },
-    );
+            Ok(quote! {
+                #pattern => {
+                    let tag: &#tag_type = #tag;
+                    ::no_std_io::StreamWriter::#field_method(&mut stream, tag)
+                        .map_err(|error| error.in_field(#enum_name, "tag"))?;
+                    #(#field_tokens)*
+                }
+            })
+        })
+        .collect::<syn::Result<Vec<proc_macro2::TokenStream>>>()?;
 
+    Ok(quote! {
+      #[inline(always)]
+      fn #impl_method(&self, dst: &mut [u8]) -> Result<usize, ::no_std_io::Error> {
+        let mut stream = ::no_std_io::StreamContainer::new(dst);
+        match self {
+            #(#arms)*
+        }
+        let bytes_written = ::no_std_io::Cursor::get_index(&stream);
+        Ok(bytes_written)
+      }
+    })
+}
+
+fn expand_endian_write(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let name = input.ident;
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let struct_name = name.to_string();
+    let container_args = ContainerArgs::from_attributes(&input.attrs)?;
+    let (le_method, be_method) = container_args.field_methods("write_stream")?;
+    let generics = add_field_bounds(
+        &input.generics,
+        &input.data,
+        parse_quote! { ::no_std_io::EndianWrite },
+        |args| args.with.is_some() || args.write_with.is_some(),
+    )?;
+
+    let (get_size, try_write_le, try_write_be) = match input.data {
+        Data::Struct(DataStruct { fields, .. }) => {
+            let get_size_fields = create_get_size_fields(&fields, &create_field_values(&fields))?;
+            (
+                quote! {
+                    use ::no_std_io::{FieldsGetSize as _, StaticGetSize as _};
+
+                    (&&::no_std_io::SizeOf::<Self>(::core::marker::PhantomData)).get_size(|| {
+                        let mut size = 0;
+                        #(#get_size_fields)*
+                        size
+                    })
+                },
+                create_write_method_impl(
+                    &struct_name,
+                    &fields,
+                    quote! { try_write_le },
+                    le_method,
+                )?,
+                create_write_method_impl(
+                    &struct_name,
+                    &fields,
+                    quote! { try_write_be },
+                    be_method,
+                )?,
+            )
+        }
+        Data::Enum(data) => {
+            let tagged_enum =
+                TaggedEnum::new("EndianWrite", &name, container_args.tag_type.as_ref(), data)?;
+            (
+                create_enum_get_size(&tagged_enum)?,
+                create_enum_write_method_impl(
+                    &struct_name,
+                    &tagged_enum,
+                    quote! { try_write_le },
+                    le_method,
+                )?,
+                create_enum_write_method_impl(
+                    &struct_name,
+                    &tagged_enum,
+                    quote! { try_write_be },
+                    be_method,
+                )?,
+            )
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Only structs and enums can derive EndianWrite",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
-    let modified = quote! {
+    Ok(quote! {
         impl #impl_generics ::no_std_io::EndianWrite for #name #ty_generics #where_clause {
           fn get_size(&self) -> usize {
-            let mut size = 0;
-            #(#get_size_fields)*
-            size
+            #get_size
           }
 
           #try_write_le
           #try_write_be
         }
-    };
+    })
+}
+
+pub fn impl_endian_write(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
 
-    modified.into()
+    match expand_endian_write(input) {
+        Ok(modified) => modified.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
 }
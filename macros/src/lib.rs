@@ -1,15 +1,50 @@
 use proc_macro::TokenStream;
 
+mod bounds;
 mod endian_read;
+mod endian_read_borrowed;
 mod endian_write;
 mod macro_args;
+mod static_size;
+mod tagged_enum;
 
+/// Derives `EndianRead` by reading each field in order.
+///
+/// `#[no_std_io(endian = "little")]` or `#[no_std_io(endian = "big")]` on the type fixes the byte order,
+/// so `try_read_le` and `try_read_be` both read the fields in that order.
 #[proc_macro_derive(EndianRead, attributes(no_std_io))]
 pub fn impl_endian_read(tokens: TokenStream) -> TokenStream {
     endian_read::impl_endian_read(tokens)
 }
 
+/// Derives `EndianReadBorrowed` for a struct with one lifetime parameter by reading each field in order,
+/// so `&'a [u8]` and `&'a str` fields borrow from the data instead of copying it.
+///
+/// A borrowed field with `#[no_std_io(len_from = "field")]` is read from the number of bytes in the earlier field,
+/// and a borrowed field with `#[no_std_io(rest)]` is read from the rest of the data.
+/// `&'a [u8]` and `&'a str` fields need one of the two, and only the last field can be a rest field.
+/// Padding, alignment and `skip` work the same as `EndianRead`.
+#[proc_macro_derive(EndianReadBorrowed, attributes(no_std_io))]
+pub fn impl_endian_read_borrowed(tokens: TokenStream) -> TokenStream {
+    endian_read_borrowed::impl_endian_read_borrowed(tokens)
+}
+
+/// Derives `EndianWrite` by writing each field in order.
+///
+/// `#[no_std_io(endian = "little")]` or `#[no_std_io(endian = "big")]` on the type fixes the byte order,
+/// so `try_write_le` and `try_write_be` both write the fields in that order.
 #[proc_macro_derive(EndianWrite, attributes(no_std_io))]
 pub fn impl_endian_write(tokens: TokenStream) -> TokenStream {
     endian_write::impl_endian_write(tokens)
 }
+
+/// Derives `StaticSize` for a struct by adding up the sizes of its fields, including their padding and alignment.
+///
+/// Every field that isn't skipped has to implement `StaticSize`.
+/// Fields whose size depends on their value, such as `len_from` or `rest` fields, and fields whose reads can fail,
+/// such as `magic`, `validate`, or `try_map` fields, are compile errors.
+/// Derived `EndianWrite::get_size` returns `StaticSize::SIZE` for types that implement it.
+#[proc_macro_derive(StaticSize, attributes(no_std_io))]
+pub fn impl_static_size(tokens: TokenStream) -> TokenStream {
+    static_size::impl_static_size(tokens)
+}
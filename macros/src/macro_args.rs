@@ -1,7 +1,312 @@
-use darling::FromAttributes;
+use darling::{util::SpannedValue, FromAttributes};
+use quote::{format_ident, quote};
+use syn::{Field, Fields, Lit, Path, Type};
 
-#[derive(Debug, FromAttributes)]
-#[darling(attributes(no_std_io))]
+#[derive(Debug, Default, FromAttributes)]
+#[darling(attributes(no_std_io), default)]
 pub struct MacroArgs {
     pub pad_before: usize,
+    pub pad_after: usize,
+    pub align: Option<SpannedValue<usize>>,
+    /// Leaves the field out of the data, so it's read as its default value and isn't written.
+    /// Any padding or alignment on the field is still read and written.
+    pub skip: bool,
+    /// Reads the field as its default value if the data ends before it, such as a field added in a newer version of a format.
+    /// Only the final fields of a struct can have this attribute.
+    pub default_on_eof: bool,
+    /// A constant the field must have, such as `magic = 0x4d5a` or `magic = b"RIFF"`.
+    /// Reads error if the field doesn't match, and writes always write the constant.
+    pub magic: Option<Lit>,
+    /// The name of an earlier field that holds the number of values in this `Vec` field, such as `len_from = "count"`.
+    /// Writes error if the count doesn't match the length of the `Vec`, unless the count is a `len_of` field for the `Vec`.
+    pub len_from: Option<SpannedValue<String>>,
+    /// The name of another field whose length this field holds, such as `len_of = "payload"`.
+    /// Writes ignore the value of this field and write the length of the other field instead.
+    pub len_of: Option<SpannedValue<String>>,
+    /// Whether a `len_of` field holds the number of values with `"elements"`, which is the default,
+    /// or the number of bytes the other field is written as with `"bytes"`.
+    pub len_kind: Option<SpannedValue<String>>,
+    /// Makes reads of a `len_of` field error if it doesn't match the length of the other field.
+    pub strict: bool,
+    /// Reads every remaining value in the data into this `Vec` field, such as the payload after a header.
+    /// Only the last field of a struct can have this attribute.
+    pub rest: bool,
+    /// A module with `read_le`, `read_be`, `write_le`, `write_be`, and `size` functions used for the field
+    /// instead of the stream methods, such as `with = "crate::xor"`.
+    pub with: Option<SpannedValue<String>>,
+    /// Same as `with`, but only for reads, so the module only needs `read_le` and `read_be`.
+    pub read_with: Option<SpannedValue<String>>,
+    /// Same as `with`, but only for writes, so the module only needs `write_le`, `write_be`, and `size`.
+    pub write_with: Option<SpannedValue<String>>,
+    /// The type the field is read from and written as, such as `map_from = "u16"` for an enum stored as a `u16`.
+    /// Needs `map` or `try_map` to read, and `unmap` to write.
+    pub map_from: Option<SpannedValue<String>>,
+    /// A function that converts the `map_from` value into the field, such as `map = "Kind::from_raw"`.
+    pub map: Option<SpannedValue<String>>,
+    /// Same as `map`, but the function returns a `Result<T, Error>` for conversions that can fail.
+    /// Error offsets are relative to the start of the field.
+    pub try_map: Option<SpannedValue<String>>,
+    /// A function that converts a reference to the field back into the `map_from` value, such as `unmap = "Kind::to_raw"`.
+    pub unmap: Option<SpannedValue<String>>,
+    /// A function that checks the field after it's read and before it's written, such as `validate = "check_version"`.
+    /// The function takes a reference to the field and returns a `Result<(), Error>`, where error offsets are relative to the start of the field.
+    pub validate: Option<SpannedValue<String>>,
+}
+
+/// The name of a field for errors and attributes, which is its position for tuple fields.
+pub fn field_name(index: usize, field: &Field) -> String {
+    match &field.ident {
+        Some(ident) => ident.to_string(),
+        None => index.to_string(),
+    }
+}
+
+/// Checks that only the last field has the `rest` attribute, since no data is left for the fields after it.
+pub fn check_rest(struct_name: &str, fields: &Fields) -> syn::Result<()> {
+    let last_index = fields.len().saturating_sub(1);
+
+    for (index, field) in fields.iter().enumerate() {
+        let args = MacroArgs::from_attributes(&field.attrs)?;
+
+        if args.rest && index != last_index {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!(
+                    "Only the last field of {} can be a rest field, but {} is not",
+                    struct_name,
+                    field_name(index, field)
+                ),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn parse_path(attribute: &str, path: &SpannedValue<String>) -> syn::Result<Path> {
+    syn::parse_str(path).map_err(|_| {
+        syn::Error::new(
+            path.span(),
+            format!(
+                "{} should be a path, but was {:?}",
+                attribute,
+                path.as_str()
+            ),
+        )
+    })
+}
+
+impl MacroArgs {
+    /// Returns the `align` attribute, which is checked here so a bad alignment fails at compile time.
+    pub fn align(&self) -> syn::Result<Option<usize>> {
+        match &self.align {
+            Some(align) if !align.is_power_of_two() => Err(syn::Error::new(
+                align.span(),
+                format!("align should be a power of two, but was {}", **align),
+            )),
+            Some(align) => Ok(Some(**align)),
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the position of the field named by the `len_from` attribute of the field at `index`.
+    pub fn len_from(&self, fields: &Fields, index: usize) -> syn::Result<Option<usize>> {
+        let len_from = match &self.len_from {
+            Some(len_from) => len_from,
+            None => return Ok(None),
+        };
+        let position = fields
+            .iter()
+            .take(index)
+            .enumerate()
+            .position(|(position, field)| field_name(position, field) == **len_from);
+
+        match position {
+            Some(position) => Ok(Some(position)),
+            None => Err(syn::Error::new(
+                len_from.span(),
+                format!(
+                    "len_from should name a field before the Vec, but {} is not one",
+                    len_from.as_str()
+                ),
+            )),
+        }
+    }
+
+    /// Returns the position of the field named by the `len_of` attribute of the field at `index`,
+    /// and whether its length is counted in bytes instead of values.
+    pub fn len_of(&self, fields: &Fields, index: usize) -> syn::Result<Option<(usize, bool)>> {
+        let len_of = match (&self.len_of, &self.len_kind) {
+            (Some(len_of), _) => len_of,
+            (None, Some(len_kind)) => {
+                return Err(syn::Error::new(
+                    len_kind.span(),
+                    "len_kind needs a len_of attribute",
+                ))
+            }
+            (None, None) => return Ok(None),
+        };
+        let is_bytes = match &self.len_kind {
+            None => false,
+            Some(len_kind) => match len_kind.as_str() {
+                "elements" => false,
+                "bytes" => true,
+                _ => {
+                    return Err(syn::Error::new(
+                        len_kind.span(),
+                        format!(
+                            "len_kind should be \"elements\" or \"bytes\", but was {:?}",
+                            len_kind.as_str()
+                        ),
+                    ))
+                }
+            },
+        };
+        let position = fields.iter().enumerate().position(|(position, field)| {
+            position != index && field_name(position, field) == **len_of
+        });
+
+        match position {
+            Some(position) => Ok(Some((position, is_bytes))),
+            None => Err(syn::Error::new(
+                len_of.span(),
+                format!(
+                    "len_of should name another field, but {} is not one",
+                    len_of.as_str()
+                ),
+            )),
+        }
+    }
+
+    /// Returns the module from the `read_with` or `with` attribute that reads the field.
+    pub fn read_with(&self) -> syn::Result<Option<Path>> {
+        self.read_with
+            .as_ref()
+            .or(self.with.as_ref())
+            .map(|with| parse_path("with", with))
+            .transpose()
+    }
+
+    /// Returns the module from the `write_with` or `with` attribute that writes the field.
+    pub fn write_with(&self) -> syn::Result<Option<Path>> {
+        self.write_with
+            .as_ref()
+            .or(self.with.as_ref())
+            .map(|with| parse_path("with", with))
+            .transpose()
+    }
+
+    /// Returns the type from the `map_from` attribute.
+    pub fn map_from(&self) -> syn::Result<Option<Type>> {
+        let map_from = match &self.map_from {
+            Some(map_from) => map_from,
+            None => return Ok(None),
+        };
+
+        syn::parse_str(map_from).map(Some).map_err(|_| {
+            syn::Error::new(
+                map_from.span(),
+                format!("map_from should be a type, but was {:?}", map_from.as_str()),
+            )
+        })
+    }
+
+    /// Returns the function from the `map` or `try_map` attribute, and whether it's fallible.
+    pub fn map(&self) -> syn::Result<Option<(Path, bool)>> {
+        let map_from = match &self.map_from {
+            Some(map_from) => map_from,
+            None => return Ok(None),
+        };
+
+        match (&self.map, &self.try_map) {
+            (Some(map), None) => Ok(Some((parse_path("map", map)?, false))),
+            (None, Some(try_map)) => Ok(Some((parse_path("try_map", try_map)?, true))),
+            (Some(_), Some(_)) => Err(syn::Error::new(
+                map_from.span(),
+                "map_from fields need either map or try_map, not both",
+            )),
+            (None, None) => Err(syn::Error::new(
+                map_from.span(),
+                "map_from fields need map or try_map to be read",
+            )),
+        }
+    }
+
+    /// Returns the function from the `unmap` attribute.
+    pub fn unmap(&self) -> syn::Result<Option<Path>> {
+        let map_from = match &self.map_from {
+            Some(map_from) => map_from,
+            None => return Ok(None),
+        };
+
+        match &self.unmap {
+            Some(unmap) => Ok(Some(parse_path("unmap", unmap)?)),
+            None => Err(syn::Error::new(
+                map_from.span(),
+                "map_from fields need unmap to be written",
+            )),
+        }
+    }
+
+    /// Returns the function from the `validate` attribute.
+    pub fn validate(&self) -> syn::Result<Option<Path>> {
+        self.validate
+            .as_ref()
+            .map(|validate| parse_path("validate", validate))
+            .transpose()
+    }
+
+    /// Returns the `magic` attribute, which can only be an integer or a byte string.
+    pub fn magic(&self) -> syn::Result<Option<&Lit>> {
+        match &self.magic {
+            Some(Lit::Int(_) | Lit::ByteStr(_)) | None => Ok(self.magic.as_ref()),
+            Some(magic) => Err(syn::Error::new_spanned(
+                magic,
+                "magic should be an integer or a byte string",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Default, FromAttributes)]
+#[darling(attributes(no_std_io), default)]
+pub struct ContainerArgs {
+    /// The type of the tag that selects the variant of an enum, such as `tag_type = "u8"`.
+    pub tag_type: Option<SpannedValue<String>>,
+    /// Fixes the byte order of the fields to `"little"` or `"big"`, so both the little and big endian methods use it.
+    pub endian: Option<SpannedValue<String>>,
+}
+
+impl ContainerArgs {
+    /// Returns the stream methods used for the fields of the little and big endian impls,
+    /// such as `read_stream_le` and `read_stream_be` for `read_stream`.
+    pub fn field_methods(
+        &self,
+        method: &str,
+    ) -> syn::Result<(proc_macro2::TokenStream, proc_macro2::TokenStream)> {
+        let le_method = format_ident!("{}_le", method);
+        let be_method = format_ident!("{}_be", method);
+
+        match &self.endian {
+            None => Ok((quote! { #le_method }, quote! { #be_method })),
+            Some(endian) => match endian.as_str() {
+                "little" => Ok((quote! { #le_method }, quote! { #le_method })),
+                "big" => Ok((quote! { #be_method }, quote! { #be_method })),
+                _ => Err(syn::Error::new(
+                    endian.span(),
+                    format!(
+                        "endian should be \"little\" or \"big\", but was {:?}",
+                        endian.as_str()
+                    ),
+                )),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Default, FromAttributes)]
+#[darling(attributes(no_std_io), default)]
+pub struct VariantArgs {
+    pub tag: Option<u64>,
+    pub catch_all: bool,
 }
@@ -0,0 +1,113 @@
+use super::{bounds::add_field_bounds, macro_args::MacroArgs};
+use darling::FromAttributes;
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DataStruct, DeriveInput, Field, Fields};
+
+/// Errors if the number of bytes a field takes depends on its value,
+/// or if reading the field can fail after its bytes are available, since iterators count on every read of `SIZE` bytes succeeding.
+fn check_static_field(field: &Field, args: &MacroArgs) -> syn::Result<()> {
+    if args.len_from.is_some()
+        || args.rest
+        || args.default_on_eof
+        || args.with.is_some()
+        || args.read_with.is_some()
+        || args.write_with.is_some()
+    {
+        return Err(syn::Error::new_spanned(
+            field,
+            "StaticSize can't be derived because the size of this field depends on its value",
+        ));
+    }
+
+    if args.magic.is_some() || args.validate.is_some() || args.try_map.is_some() {
+        return Err(syn::Error::new_spanned(
+            field,
+            "StaticSize can't be derived because reads of this field can fail with magic, validate, or try_map",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Adds up the `StaticSize` of the fields, including their padding and alignment.
+fn create_static_size(fields: &Fields) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    fields
+        .iter()
+        .map(|field| {
+            let args = MacroArgs::from_attributes(&field.attrs)?;
+            check_static_field(field, &args)?;
+
+            let align = match args.align()? {
+                Some(align) => quote! { size += (#align - size % #align) % #align; },
+                None => quote! {},
+            };
+            let size = match args.skip {
+                true => quote! {},
+                false => {
+                    let wire_type = args.map_from()?.unwrap_or_else(|| field.ty.clone());
+                    quote! { size += <#wire_type as ::no_std_io::StaticSize>::SIZE; }
+                }
+            };
+            let MacroArgs {
+                pad_before,
+                pad_after,
+                ..
+            } = args;
+
+            Ok(quote! {
+                size += #pad_before;
+                #align
+                #size
+                size += #pad_after;
+            })
+        })
+        .collect()
+}
+
+fn expand_static_size(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = input.ident;
+    let generics = add_field_bounds(
+        &input.generics,
+        &input.data,
+        parse_quote! { ::no_std_io::StaticSize },
+        |_| false,
+    )?;
+
+    let field_sizes = match &input.data {
+        Data::Struct(DataStruct { fields, .. }) => create_static_size(fields)?,
+        Data::Enum(data) => {
+            return Err(syn::Error::new_spanned(
+                data.enum_token,
+                "Only structs can derive StaticSize",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "Only structs can derive StaticSize",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::no_std_io::StaticSize for #name #ty_generics #where_clause {
+            const SIZE: usize = {
+                let mut size: usize = 0;
+                #(#field_sizes)*
+                size
+            };
+        }
+    })
+}
+
+pub fn impl_static_size(tokens: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(tokens as DeriveInput);
+
+    match expand_static_size(input) {
+        Ok(modified) => modified.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,133 @@
+use super::macro_args::{field_name, VariantArgs};
+use darling::{util::SpannedValue, FromAttributes};
+use proc_macro2::{Ident, Literal};
+use quote::{format_ident, quote};
+use syn::{DataEnum, Fields, Type};
+
+pub struct TaggedVariant {
+    pub ident: Ident,
+    pub fields: Fields,
+    /// The tag that selects this variant, or `None` for the catch-all variant.
+    pub tag: Option<Literal>,
+}
+
+pub struct TaggedEnum {
+    pub tag_type: Type,
+    pub variants: Vec<TaggedVariant>,
+}
+
+impl TaggedEnum {
+    /// Parses the `tag_type` attribute of an enum and the `tag` or `catch_all` attribute of each variant.
+    pub fn new(
+        trait_name: &str,
+        enum_ident: &Ident,
+        tag_type: Option<&SpannedValue<String>>,
+        data: DataEnum,
+    ) -> syn::Result<Self> {
+        let tag_type = match tag_type {
+            Some(tag_type) => syn::parse_str::<Type>(tag_type).map_err(|_| {
+                syn::Error::new(tag_type.span(), "tag_type should be a type, such as \"u8\"")
+            })?,
+            None => {
+                return Err(syn::Error::new_spanned(
+                    enum_ident,
+                    format!(
+                        "Enums need a #[no_std_io(tag_type = \"...\")] attribute to derive {}",
+                        trait_name
+                    ),
+                ))
+            }
+        };
+
+        let mut has_catch_all = false;
+        let variants = data
+            .variants
+            .into_iter()
+            .map(|variant| {
+                let args = VariantArgs::from_attributes(&variant.attrs)?;
+
+                let tag = match args {
+                    VariantArgs {
+                        tag: Some(tag),
+                        catch_all: false,
+                    } => Some(Literal::u64_unsuffixed(tag)),
+                    VariantArgs {
+                        tag: None,
+                        catch_all: true,
+                    } => {
+                        if has_catch_all {
+                            return Err(syn::Error::new_spanned(
+                                &variant.ident,
+                                "Only one variant can be the catch_all variant",
+                            ));
+                        }
+                        if variant.fields.len() != 2 {
+                            return Err(syn::Error::new_spanned(
+                                &variant.ident,
+                                "The catch_all variant should have a tag field and a field for the remaining bytes",
+                            ));
+                        }
+                        has_catch_all = true;
+                        None
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &variant.ident,
+                            format!(
+                                "Variant {} needs either a #[no_std_io(tag = ...)] or a #[no_std_io(catch_all)] attribute",
+                                variant.ident
+                            ),
+                        ))
+                    }
+                };
+
+                Ok(TaggedVariant {
+                    ident: variant.ident,
+                    fields: variant.fields,
+                    tag,
+                })
+            })
+            .collect::<syn::Result<Vec<TaggedVariant>>>()?;
+
+        Ok(Self { tag_type, variants })
+    }
+}
+
+impl TaggedVariant {
+    /// The name used for errors from the fields of this variant, such as `Packet::Data`.
+    pub fn field_context_name(&self, enum_name: &str) -> String {
+        format!("{}::{}", enum_name, self.ident)
+    }
+
+    /// The name of a field for errors, which is its position for tuple variants.
+    pub fn field_name(&self, index: usize) -> String {
+        let field = self
+            .fields
+            .iter()
+            .nth(index)
+            .expect("Variant should have the field");
+        field_name(index, field)
+    }
+
+    /// Fields are bound to `field_0`, `field_1`, and so on, so they can't shadow the generated variables.
+    pub fn field_bindings(&self) -> Vec<Ident> {
+        (0..self.fields.len())
+            .map(|index| format_ident!("field_{}", index))
+            .collect()
+    }
+
+    /// Creates a pattern that binds each field by reference, such as `Self::Data { payload: field_0 }`.
+    pub fn create_pattern(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        let bindings = self.field_bindings();
+
+        match &self.fields {
+            Fields::Named(fields) => {
+                let names = fields.named.iter().map(|field| &field.ident);
+                quote! { Self::#ident { #(#names: #bindings),* } }
+            }
+            Fields::Unnamed(_) => quote! { Self::#ident(#(#bindings),*) },
+            Fields::Unit => quote! { Self::#ident },
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use macros::EndianRead;
-use no_std_io::{Cursor, Error, ReadOutput, Reader, StreamContainer, StreamReader};
+use no_std_io::{Cursor, Error, ReadOutput, ReaderExt, StreamContainer, StreamReader};
 
 #[derive(Debug, Default, PartialEq, EndianRead)]
 struct Test {
@@ -24,7 +24,7 @@ impl<T: no_std_io::EndianRead> no_std_io::EndianRead for ListContainer<T> {
         }
 
         let count = bytes[0] as usize;
-        let mut stream = StreamContainer::new(&bytes[1..]);
+        let mut stream = StreamContainer::new_at(bytes, 1);
 
         let mut list = vec![];
 
@@ -34,7 +34,7 @@ impl<T: no_std_io::EndianRead> no_std_io::EndianRead for ListContainer<T> {
         }
 
         let result = ListContainer(list);
-        let read_bytes = stream.get_index() + 1;
+        let read_bytes = stream.get_index();
 
         Ok(ReadOutput::new(result, read_bytes))
     }
@@ -50,7 +50,7 @@ impl<T: no_std_io::EndianRead> no_std_io::EndianRead for ListContainer<T> {
         }
 
         let count = bytes[0] as usize;
-        let mut stream = StreamContainer::new(&bytes[1..]);
+        let mut stream = StreamContainer::new_at(bytes, 1);
 
         let mut list = vec![];
 
@@ -60,7 +60,7 @@ impl<T: no_std_io::EndianRead> no_std_io::EndianRead for ListContainer<T> {
         }
 
         let result = ListContainer(list);
-        let read_bytes = stream.get_index() + 1;
+        let read_bytes = stream.get_index();
 
         Ok(ReadOutput::new(result, read_bytes))
     }
@@ -112,10 +112,14 @@ fn should_error_if_there_are_not_enough_bytes() {
         .expect_err("This should have failed");
     assert_eq!(
         result,
-        Error::InvalidSize {
-            wanted_size: 4,
-            offset: 1,
-            data_len: 4
+        Error::Field {
+            struct_name: "Test",
+            field: "second",
+            source: Box::new(Error::InvalidSize {
+                wanted_size: 4,
+                offset: 1,
+                data_len: 4
+            }),
         }
     );
 }
@@ -178,6 +182,29 @@ fn should_read_nested_be() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn should_read_nested_at_an_offset() {
+    let mut bytes = vec![0u8; 0x10];
+    bytes.extend_from_slice(&[
+        0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0x11, 0x11, 0x11, 0x22, 0x33, 0x44, 0x01, 0x11, 0x22, 0x33,
+        0x44,
+    ]);
+    let mut stream = StreamContainer::new_at(bytes.as_slice(), 0x10);
+    let result: TestContainer = stream.read_stream_le().expect("Read should have worked");
+    let expected = TestContainer {
+        test: Test {
+            first: 0xaa,
+            second: 0xeeddccbb,
+            byte_array: [0x11, 0x11],
+            array: [0x2211, 0x4433],
+        },
+        list: ListContainer(vec![0x44332211]),
+    };
+
+    assert_eq!(result, expected);
+    assert_eq!(stream.get_index(), 0x20);
+}
+
 mod padding {
     use super::*;
 
@@ -295,4 +322,1441 @@ mod padding {
         ]);
         assert_eq!(result, expected);
     }
+
+    #[cfg(target_pointer_width = "64")]
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct OverflowingPadTest {
+        first: u8,
+        #[no_std_io(pad_before = 18446744073709551615)]
+        second: u8,
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn should_return_error_instead_of_wrapping_the_index() {
+        let bytes = vec![0xaa, 0xbb, 0xcc, 0xdd];
+        let error = bytes
+            .read_le::<OverflowingPadTest>(2)
+            .expect_err("Padding should have overflowed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "OverflowingPadTest",
+                field: "second",
+                source: Box::new(Error::OffsetOverflow {
+                    offset: 3,
+                    size: usize::MAX,
+                }),
+            }
+        );
+    }
+}
+
+mod field_context {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Entries {
+        count: u32,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Header {
+        magic: u16,
+        version: u16,
+        entries: Entries,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct PaddedHeader {
+        magic: u16,
+        #[no_std_io(pad_before = 4)]
+        version: u16,
+        flags: u16,
+    }
+
+    #[test]
+    fn should_name_a_middle_field_after_its_padding() {
+        let bytes = vec![0xaa, 0xbb, 0x00, 0x00];
+        let error = bytes
+            .read_le::<PaddedHeader>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "PaddedHeader",
+                field: "version",
+                source: Box::new(Error::EndOfData {
+                    offset: 6,
+                    requested: 2,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_name_the_third_field() {
+        let bytes = vec![0xaa, 0xbb, 0x01, 0x00, 0x11, 0x22];
+        let error = bytes
+            .read_le::<Header>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Header",
+                field: "entries",
+                source: Box::new(Error::Field {
+                    struct_name: "Entries",
+                    field: "count",
+                    source: Box::new(Error::InvalidSize {
+                        wanted_size: 4,
+                        offset: 4,
+                        data_len: 6,
+                    }),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_display_the_field_path() {
+        let bytes = vec![0xaa, 0xbb, 0x01, 0x00, 0x11, 0x22];
+        let error = bytes
+            .read_le::<Header>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid field Header.entries.count: Invalid size: wanted 0x4 at offset offset: 0x4, but data length is 0x6 "
+        );
+    }
+
+    #[test]
+    fn should_return_the_root_error() {
+        let bytes = vec![0xaa, 0xbb, 0x01, 0x00, 0x11, 0x22];
+        let error = bytes
+            .read_be::<Header>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error.root(),
+            &Error::InvalidSize {
+                wanted_size: 4,
+                offset: 4,
+                data_len: 6,
+            }
+        );
+    }
+
+    #[test]
+    fn should_add_the_stream_offset_to_the_root_error() {
+        let bytes = vec![0x00, 0x00, 0xaa, 0xbb, 0x01, 0x00, 0x11, 0x22];
+        let mut stream = StreamContainer::new_at(bytes.as_slice(), 2);
+        let error = stream
+            .read_stream_le::<Header>()
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error.root(),
+            &Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            }
+        );
+    }
+}
+
+mod absolute_offsets {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Entry {
+        kind: u16,
+        value: u32,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Table {
+        count: u16,
+        entries: [Entry; 2],
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct File {
+        magic: u32,
+        #[no_std_io(pad_before = 2)]
+        table: Table,
+    }
+
+    #[test]
+    fn should_report_the_absolute_offset_three_levels_deep() {
+        let bytes = vec![
+            0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x11, 0x22, 0x33, 0x44,
+            0x02, 0x00, 0x55, 0x66,
+        ];
+        let error = bytes
+            .read_le::<File>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "File",
+                field: "table",
+                source: Box::new(Error::Field {
+                    struct_name: "Table",
+                    field: "entries",
+                    source: Box::new(Error::Field {
+                        struct_name: "Entry",
+                        field: "value",
+                        source: Box::new(Error::InvalidSize {
+                            wanted_size: 4,
+                            offset: 16,
+                            data_len: 18,
+                        }),
+                    }),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_report_the_absolute_offset_from_a_stream() {
+        let bytes = vec![
+            0x00, 0x00, 0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x11,
+            0x22, 0x33, 0x44, 0x02, 0x00, 0x55, 0x66,
+        ];
+        let mut stream = StreamContainer::new_at(bytes.as_slice(), 3);
+        let error = stream
+            .read_stream_be::<File>()
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error.root(),
+            &Error::InvalidSize {
+                wanted_size: 4,
+                offset: 19,
+                data_len: 21,
+            }
+        );
+        assert_eq!(stream.get_index(), 3);
+    }
+}
+
+mod tuple_structs {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Crc(u32);
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Vec3(f32, f32, f32);
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct PaddedRecord(u8, #[no_std_io(pad_before = 2)] u16, [u16; 2]);
+
+    #[test]
+    fn should_read_a_newtype_le() {
+        let bytes = vec![0x11, 0x22, 0x33, 0x44];
+        let result: Crc = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result, Crc(0x44332211));
+    }
+
+    #[test]
+    fn should_read_a_newtype_be() {
+        let bytes = vec![0x11, 0x22, 0x33, 0x44];
+        let result: Crc = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(result, Crc(0x11223344));
+    }
+
+    #[test]
+    fn should_read_multiple_fields_le() {
+        let bytes = vec![
+            0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x40, 0x40,
+        ];
+        let result: Vec3 = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result, Vec3(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn should_read_multiple_fields_be() {
+        let bytes = vec![
+            0x3f, 0x80, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x40, 0x00, 0x00,
+        ];
+        let result: Vec3 = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(result, Vec3(1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn should_read_padded_fields_le() {
+        let bytes = vec![0xaa, 0x00, 0x00, 0xcc, 0xbb, 0x11, 0x00, 0x22, 0x00];
+        let result: PaddedRecord = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result, PaddedRecord(0xaa, 0xbbcc, [0x11, 0x22]));
+    }
+
+    #[test]
+    fn should_read_padded_fields_be() {
+        let bytes = vec![0xaa, 0x00, 0x00, 0xbb, 0xcc, 0x00, 0x11, 0x00, 0x22];
+        let result: PaddedRecord = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(result, PaddedRecord(0xaa, 0xbbcc, [0x11, 0x22]));
+    }
+
+    #[test]
+    fn should_name_fields_by_position() {
+        let bytes = vec![0x00, 0x00, 0x80, 0x3f, 0x00, 0x00];
+        let error = bytes
+            .read_le::<Vec3>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Vec3",
+                field: "1",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 4,
+                    data_len: 6,
+                }),
+            }
+        );
+    }
+}
+
+mod unit_structs {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct KeepAlive;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct EmptyNamed {}
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct EmptyTuple();
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Message {
+        tag: KeepAlive,
+        id: u16,
+        empty: EmptyNamed,
+        other: EmptyTuple,
+        value: u8,
+    }
+
+    #[test]
+    fn should_read_zero_bytes() {
+        let bytes = vec![0xaa, 0xbb];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+
+        assert_eq!(stream.read_stream_le::<KeepAlive>(), Ok(KeepAlive));
+        assert_eq!(stream.read_stream_be::<EmptyNamed>(), Ok(EmptyNamed {}));
+        assert_eq!(stream.read_stream_le::<EmptyTuple>(), Ok(EmptyTuple()));
+        assert_eq!(stream.get_index(), 0);
+    }
+
+    #[test]
+    fn should_read_from_empty_data() {
+        let bytes: Vec<u8> = vec![];
+        let result = <KeepAlive as no_std_io::EndianRead>::try_read_le(&bytes)
+            .expect("Read should have worked");
+
+        assert_eq!(result.get_read_bytes(), 0);
+    }
+
+    #[test]
+    fn should_read_as_a_field_le() {
+        let bytes = vec![0x11, 0x22, 0x33];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: Message = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Message {
+                tag: KeepAlive,
+                id: 0x2211,
+                empty: EmptyNamed {},
+                other: EmptyTuple(),
+                value: 0x33,
+            }
+        );
+        assert_eq!(stream.get_index(), 3);
+    }
+
+    #[test]
+    fn should_read_as_a_field_be() {
+        let bytes = vec![0x11, 0x22, 0x33];
+        let result: Message = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Message {
+                tag: KeepAlive,
+                id: 0x1122,
+                empty: EmptyNamed {},
+                other: EmptyTuple(),
+                value: 0x33,
+            }
+        );
+    }
+}
+
+mod padding_after {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Record {
+        #[no_std_io(pad_after = 1)]
+        kind: u8,
+        #[no_std_io(pad_after = 4)]
+        name: [u8; 4],
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Wrapper {
+        record: Record,
+        next: u16,
+    }
+
+    #[test]
+    fn should_read_le() {
+        let bytes = vec![0xaa, 0xff, 0x11, 0x22, 0x33, 0x44, 0xff, 0xff, 0xff, 0xff];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: Record = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Record {
+                kind: 0xaa,
+                name: [0x11, 0x22, 0x33, 0x44],
+            }
+        );
+        assert_eq!(stream.get_index(), 10);
+    }
+
+    #[test]
+    fn should_read_nested_be() {
+        let bytes = vec![
+            0xaa, 0xff, 0x11, 0x22, 0x33, 0x44, 0xff, 0xff, 0xff, 0xff, 0x12, 0x34,
+        ];
+        let result: Wrapper = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Wrapper {
+                record: Record {
+                    kind: 0xaa,
+                    name: [0x11, 0x22, 0x33, 0x44],
+                },
+                next: 0x1234,
+            }
+        );
+    }
+
+    #[test]
+    fn should_error_if_the_final_padding_is_missing() {
+        let bytes = vec![0xaa, 0xff, 0x11, 0x22, 0x33, 0x44, 0xff, 0xff];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let error = stream
+            .read_stream_le::<Record>()
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Record",
+                field: "name",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }),
+            }
+        );
+        assert_eq!(stream.get_index(), 0);
+    }
+}
+
+mod alignment {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct AlignedTest {
+        list: ListContainer<u8>,
+        #[no_std_io(align = 4)]
+        value: u32,
+        #[no_std_io(align = 2)]
+        last: u8,
+    }
+
+    #[test]
+    fn should_skip_to_the_next_boundary_le() {
+        let bytes = vec![0x02, 0xaa, 0xbb, 0xff, 0x11, 0x22, 0x33, 0x44, 0xcc];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: AlignedTest = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            AlignedTest {
+                list: ListContainer(vec![0xaa, 0xbb]),
+                value: 0x44332211,
+                last: 0xcc,
+            }
+        );
+        assert_eq!(stream.get_index(), 9);
+    }
+
+    #[test]
+    fn should_not_skip_if_already_aligned_be() {
+        let bytes = vec![0x03, 0xaa, 0xbb, 0xcc, 0x11, 0x22, 0x33, 0x44, 0xdd];
+        let result: AlignedTest = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            AlignedTest {
+                list: ListContainer(vec![0xaa, 0xbb, 0xcc]),
+                value: 0x11223344,
+                last: 0xdd,
+            }
+        );
+    }
+
+    #[test]
+    fn should_align_relative_to_the_start_of_the_struct() {
+        let bytes = vec![0xff, 0x01, 0xaa, 0xff, 0xff, 0x11, 0x22, 0x33, 0x44, 0xcc];
+        let mut stream = StreamContainer::new_at(bytes.as_slice(), 1);
+        let result: AlignedTest = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            AlignedTest {
+                list: ListContainer(vec![0xaa]),
+                value: 0x44332211,
+                last: 0xcc,
+            }
+        );
+        assert_eq!(stream.get_index(), 10);
+    }
+
+    #[test]
+    fn should_error_if_the_boundary_is_past_the_end() {
+        let bytes = vec![0x01, 0xaa, 0xff];
+        let error = bytes
+            .read_le::<AlignedTest>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "AlignedTest",
+                field: "value",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 2,
+                    data_len: 3,
+                }),
+            }
+        );
+    }
+}
+
+mod skip {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq)]
+    struct Cache {
+        hits: usize,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct SkipTest {
+        id: u16,
+        #[no_std_io(skip)]
+        cache: Cache,
+        #[no_std_io(skip, pad_before = 2)]
+        reserved: (),
+        value: u8,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct SkipTupleTest(u8, #[no_std_io(skip)] Cache, u8);
+
+    #[test]
+    fn should_default_skipped_fields_le() {
+        let bytes = vec![0x11, 0x22, 0xff, 0xff, 0xaa];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: SkipTest = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            SkipTest {
+                id: 0x2211,
+                cache: Cache::default(),
+                reserved: (),
+                value: 0xaa,
+            }
+        );
+        assert_eq!(stream.get_index(), 5);
+    }
+
+    #[test]
+    fn should_default_skipped_fields_be() {
+        let bytes = vec![0x11, 0x22, 0xff, 0xff, 0xaa];
+        let result: SkipTest = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            SkipTest {
+                id: 0x1122,
+                cache: Cache::default(),
+                reserved: (),
+                value: 0xaa,
+            }
+        );
+    }
+
+    #[test]
+    fn should_default_skipped_tuple_fields() {
+        let bytes = vec![0x11, 0x22];
+        let result: SkipTupleTest = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result, SkipTupleTest(0x11, Cache::default(), 0x22));
+    }
+}
+
+mod default_on_eof {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct VersionedHeader {
+        magic: u16,
+        version: u8,
+        #[no_std_io(default_on_eof)]
+        flags: u16,
+        #[no_std_io(default_on_eof, pad_before = 1)]
+        extra: u32,
+    }
+
+    #[test]
+    fn should_default_fields_missing_from_old_data() {
+        let bytes = vec![0xaa, 0xbb, 0x01];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: VersionedHeader = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            VersionedHeader {
+                magic: 0xbbaa,
+                version: 1,
+                flags: 0,
+                extra: 0,
+            }
+        );
+        assert_eq!(stream.get_index(), 3);
+    }
+
+    #[test]
+    fn should_default_only_the_missing_fields() {
+        let bytes = vec![0xaa, 0xbb, 0x02, 0x11, 0x22];
+        let result: VersionedHeader = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            VersionedHeader {
+                magic: 0xaabb,
+                version: 2,
+                flags: 0x1122,
+                extra: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_all_fields_from_new_data() {
+        let bytes = vec![0xaa, 0xbb, 0x03, 0x11, 0x22, 0xff, 0x33, 0x44, 0x55, 0x66];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: VersionedHeader = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            VersionedHeader {
+                magic: 0xbbaa,
+                version: 3,
+                flags: 0x2211,
+                extra: 0x66554433,
+            }
+        );
+        assert_eq!(stream.get_index(), 10);
+    }
+
+    #[test]
+    fn should_error_on_partial_data() {
+        let bytes = vec![0xaa, 0xbb, 0x02, 0x11];
+        let error = bytes
+            .read_le::<VersionedHeader>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "VersionedHeader",
+                field: "flags",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 3,
+                    data_len: 4,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_still_require_the_other_fields() {
+        let bytes = vec![0xaa, 0xbb];
+        let error = bytes
+            .read_le::<VersionedHeader>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error.root(),
+            &Error::EndOfData {
+                offset: 2,
+                requested: 1,
+            }
+        );
+    }
+}
+
+mod magic {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct MagicTest {
+        #[no_std_io(magic = 0x4d5a)]
+        signature: u16,
+        #[no_std_io(magic = b"RIFF")]
+        riff: [u8; 4],
+        size: u32,
+    }
+
+    #[test]
+    fn should_read_matching_magic_le() {
+        let bytes = vec![0x5a, 0x4d, 0x52, 0x49, 0x46, 0x46, 0x10, 0x00, 0x00, 0x00];
+        let result: MagicTest = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            MagicTest {
+                signature: 0x4d5a,
+                riff: *b"RIFF",
+                size: 0x10,
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_matching_magic_be() {
+        let bytes = vec![0x4d, 0x5a, 0x52, 0x49, 0x46, 0x46, 0x00, 0x00, 0x00, 0x10];
+        let result: MagicTest = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            MagicTest {
+                signature: 0x4d5a,
+                riff: *b"RIFF",
+                size: 0x10,
+            }
+        );
+    }
+
+    #[test]
+    fn should_error_if_an_integer_magic_does_not_match() {
+        let bytes = vec![
+            0x00, 0x00, 0x34, 0x12, 0x52, 0x49, 0x46, 0x46, 0x10, 0x00, 0x00, 0x00,
+        ];
+        let error = bytes
+            .read_le::<MagicTest>(2)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "MagicTest",
+                field: "signature",
+                source: Box::new(Error::InvalidData {
+                    offset: 2,
+                    expected: 0x4d5a,
+                    found: 0x1234,
+                    description: Some("Invalid magic"),
+                }),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid field MagicTest.signature: Invalid magic at offset 0x2: expected 0x4d5a, found 0x1234"
+        );
+    }
+
+    #[test]
+    fn should_error_at_the_first_byte_that_does_not_match() {
+        let bytes = vec![0x5a, 0x4d, 0x52, 0x49, 0x46, 0x58, 0x10, 0x00, 0x00, 0x00];
+        let error = bytes
+            .read_le::<MagicTest>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "MagicTest",
+                field: "riff",
+                source: Box::new(Error::UnexpectedByte {
+                    offset: 5,
+                    expected: b'F',
+                    found: 0x58,
+                }),
+            }
+        );
+    }
+}
+
+mod fixed_endian {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    #[no_std_io(endian = "little")]
+    struct LittleTest {
+        first: u16,
+        second: [u16; 2],
+        nested: Test,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    #[no_std_io(endian = "big")]
+    struct BigTest(u32);
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    #[no_std_io(tag_type = "u16", endian = "big")]
+    enum BigEnum {
+        #[no_std_io(tag = 1)]
+        Value(u16),
+    }
+
+    #[test]
+    fn should_read_little_endian_from_both_methods() {
+        let bytes = vec![
+            0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0xaa, 0x01, 0x02, 0x03, 0x04, 0xbb, 0xcc, 0x01,
+            0x02, 0x03, 0x04,
+        ];
+        let le_result: LittleTest = bytes.read_le(0).expect("Read should have worked");
+        let be_result: LittleTest = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(le_result.first, 0x2211);
+        assert_eq!(le_result.second, [0x4433, 0x6655]);
+        assert_eq!(le_result, be_result);
+    }
+
+    #[test]
+    fn should_read_big_endian_from_both_methods() {
+        let bytes = vec![0x11, 0x22, 0x33, 0x44];
+        let le_result: BigTest = bytes.read_le(0).expect("Read should have worked");
+        let be_result: BigTest = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(le_result, BigTest(0x11223344));
+        assert_eq!(le_result, be_result);
+    }
+
+    #[test]
+    fn should_read_a_fixed_endian_tag() {
+        let bytes = vec![0x00, 0x01, 0x12, 0x34];
+
+        assert_eq!(bytes.read_le::<BigEnum>(0), Ok(BigEnum::Value(0x1234)));
+        assert_eq!(bytes.read_be::<BigEnum>(0), Ok(BigEnum::Value(0x1234)));
+    }
+}
+
+mod len_of {
+    use super::*;
+    use macros::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+    struct Entry {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Loose {
+        #[no_std_io(len_of = "entries")]
+        count: u8,
+        #[no_std_io(rest)]
+        entries: Vec<Entry>,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Strict {
+        #[no_std_io(len_of = "entries", strict)]
+        count: u8,
+        #[no_std_io(rest)]
+        entries: Vec<Entry>,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct StrictBytes {
+        kind: u8,
+        #[no_std_io(len_of = "entries", len_kind = "bytes", strict)]
+        size: u16,
+        #[no_std_io(rest)]
+        entries: Vec<Entry>,
+    }
+
+    #[test]
+    fn should_read_a_mismatched_length_without_strict() {
+        let bytes = vec![0x05, 0x01, 0x11, 0x22];
+        let result: Loose = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Loose {
+                count: 5,
+                entries: vec![Entry {
+                    kind: 1,
+                    value: 0x1122,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_a_matching_length_with_strict() {
+        let bytes = vec![0x01, 0x01, 0x11, 0x22];
+        let result: Strict = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Strict {
+                count: 1,
+                entries: vec![Entry {
+                    kind: 1,
+                    value: 0x1122,
+                }],
+            }
+        );
+    }
+
+    #[test]
+    fn should_error_if_the_length_does_not_match_with_strict() {
+        let bytes = vec![0xff, 0x05, 0x01, 0x11, 0x22];
+        let error = bytes
+            .read_be::<Strict>(1)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Strict",
+                field: "count",
+                source: Box::new(Error::InvalidData {
+                    offset: 1,
+                    expected: 1,
+                    found: 5,
+                    description: Some("Length does not match the len_of field"),
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_check_the_number_of_bytes() {
+        let bytes = vec![0xaa, 0x06, 0x00, 0x01, 0x22, 0x11, 0x02, 0x44, 0x33];
+        let result: StrictBytes = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result.size, 6);
+        assert_eq!(result.entries.len(), 2);
+    }
+
+    #[test]
+    fn should_error_if_the_number_of_bytes_does_not_match() {
+        let bytes = vec![0xaa, 0x02, 0x00, 0x01, 0x22, 0x11];
+        let error = bytes
+            .read_le::<StrictBytes>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "StrictBytes",
+                field: "size",
+                source: Box::new(Error::InvalidData {
+                    offset: 1,
+                    expected: 3,
+                    found: 2,
+                    description: Some("Length does not match the len_of field"),
+                }),
+            }
+        );
+    }
+}
+
+mod len_from {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Entry {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Table {
+        count: u16,
+        #[no_std_io(len_from = "count")]
+        entries: Vec<Entry>,
+        last: u8,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Words(u8, #[no_std_io(len_from = "0")] Vec<u16>);
+
+    #[test]
+    fn should_read_zero_values() {
+        let bytes = vec![0x00, 0x00, 0xaa];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: Table = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Table {
+                count: 0,
+                entries: vec![],
+                last: 0xaa,
+            }
+        );
+        assert_eq!(stream.get_index(), 3);
+    }
+
+    #[test]
+    fn should_read_nested_values_le() {
+        let bytes = vec![0x02, 0x00, 0x01, 0x11, 0x22, 0x02, 0x33, 0x44, 0xaa];
+        let result: Table = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Table {
+                count: 2,
+                entries: vec![
+                    Entry {
+                        kind: 1,
+                        value: 0x2211,
+                    },
+                    Entry {
+                        kind: 2,
+                        value: 0x4433,
+                    },
+                ],
+                last: 0xaa,
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_tuple_fields_be() {
+        let bytes = vec![0x02, 0x11, 0x22, 0x33, 0x44];
+        let result: Words = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(result, Words(2, vec![0x1122, 0x3344]));
+    }
+
+    #[test]
+    fn should_error_if_there_are_not_enough_values() {
+        let bytes = vec![0x00, 0x00, 0x03, 0x00, 0x01, 0x11, 0x22, 0x02, 0x33];
+        let error = bytes
+            .read_le::<Table>(2)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Table",
+                field: "entries",
+                source: Box::new(Error::IncompleteCountRead {
+                    offset: 7,
+                    items_read: 1,
+                }),
+            }
+        );
+    }
+}
+
+mod rest {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Packet {
+        kind: u8,
+        length: u16,
+        #[no_std_io(rest)]
+        payload: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Words(u8, #[no_std_io(rest)] Vec<u16>);
+
+    #[test]
+    fn should_read_the_remaining_bytes() {
+        let bytes = vec![0x01, 0x00, 0x03, 0xaa, 0xbb, 0xcc];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: Packet = stream.read_stream_be().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Packet {
+                kind: 1,
+                length: 3,
+                payload: vec![0xaa, 0xbb, 0xcc],
+            }
+        );
+        assert_eq!(stream.get_index(), 6);
+    }
+
+    #[test]
+    fn should_read_an_empty_payload() {
+        let bytes = vec![0x01, 0x00, 0x00];
+        let result: Packet = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Packet {
+                kind: 1,
+                length: 0,
+                payload: vec![],
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_the_remaining_values() {
+        let bytes = vec![0x02, 0x11, 0x22, 0x33, 0x44];
+        let result: Words = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result, Words(2, vec![0x2211, 0x4433]));
+    }
+
+    #[test]
+    fn should_error_if_a_value_is_incomplete() {
+        let bytes = vec![0x02, 0x11, 0x22, 0x33];
+        let error = bytes
+            .read_le::<Words>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Words",
+                field: "1",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 3,
+                    data_len: 4,
+                }),
+            }
+        );
+    }
+}
+
+mod with {
+    use super::*;
+
+    mod xor {
+        use no_std_io::{Error, StreamReader};
+
+        pub fn read_le<S: StreamReader>(stream: &mut S) -> Result<u32, Error> {
+            Ok(stream.read_stream_le::<u32>()? ^ 0xaabbccdd)
+        }
+
+        pub fn read_be<S: StreamReader>(stream: &mut S) -> Result<u32, Error> {
+            Ok(stream.read_stream_be::<u32>()? ^ 0xaabbccdd)
+        }
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Obfuscated {
+        kind: u8,
+        #[no_std_io(with = "xor")]
+        value: u32,
+        last: u8,
+    }
+
+    #[test]
+    fn should_read_with_the_module_le() {
+        let bytes = vec![0x01, 0xdd, 0xcc, 0xbb, 0xaa, 0x02];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: Obfuscated = stream.read_stream_le().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Obfuscated {
+                kind: 1,
+                value: 0,
+                last: 2,
+            }
+        );
+        assert_eq!(stream.get_index(), 6);
+    }
+
+    #[test]
+    fn should_read_with_the_module_be() {
+        let bytes = vec![0x01, 0xaa, 0xbb, 0xcc, 0xdc, 0x02];
+        let result: Obfuscated = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Obfuscated {
+                kind: 1,
+                value: 1,
+                last: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn should_add_field_context_to_module_errors() {
+        let bytes = vec![0x01, 0xdd, 0xcc];
+        let error = bytes
+            .read_le::<Obfuscated>(0)
+            .expect_err("This should have failed");
+
+        assert!(matches!(
+            error,
+            Error::Field {
+                struct_name: "Obfuscated",
+                field: "value",
+                ..
+            }
+        ));
+    }
+}
+
+mod map {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    impl Kind {
+        fn try_from_raw(raw: u16) -> Result<Self, Error> {
+            match raw {
+                0 => Ok(Self::Small),
+                1 => Ok(Self::Large),
+                _ => Err(Error::InvalidData {
+                    offset: 0,
+                    expected: 1,
+                    found: raw as u64,
+                    description: Some("Invalid kind"),
+                }),
+            }
+        }
+    }
+
+    fn flag_from_raw(raw: u8) -> bool {
+        raw != 0
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Record {
+        id: u8,
+        #[no_std_io(map_from = "u16", try_map = "Kind::try_from_raw")]
+        kind: Kind,
+        #[no_std_io(map_from = "u8", map = "flag_from_raw")]
+        flag: bool,
+    }
+
+    #[test]
+    fn should_read_mapped_fields() {
+        let bytes = vec![0x01, 0x00, 0x01, 0x02];
+        let mut stream = StreamContainer::new(bytes.as_slice());
+        let result: Record = stream.read_stream_be().expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Record {
+                id: 1,
+                kind: Kind::Large,
+                flag: true,
+            }
+        );
+        assert_eq!(stream.get_index(), 4);
+    }
+
+    #[test]
+    fn should_error_with_the_offset_of_the_field() {
+        let bytes = vec![0x00, 0x00, 0x01, 0x07, 0x00, 0x00];
+        let error = bytes
+            .read_le::<Record>(2)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Record",
+                field: "kind",
+                source: Box::new(Error::InvalidData {
+                    offset: 3,
+                    expected: 1,
+                    found: 7,
+                    description: Some("Invalid kind"),
+                }),
+            }
+        );
+    }
+}
+
+mod validate {
+    use super::*;
+
+    fn check_version(version: &u8) -> Result<(), Error> {
+        if *version > 3 {
+            return Err(Error::InvalidData {
+                offset: 0,
+                expected: 3,
+                found: *version as u64,
+                description: Some("Unsupported version"),
+            });
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Header {
+        kind: u16,
+        #[no_std_io(validate = "check_version")]
+        version: u8,
+        size: u32,
+    }
+
+    #[test]
+    fn should_read_a_valid_field() {
+        let bytes = vec![0x01, 0x00, 0x03, 0x10, 0x00, 0x00, 0x00];
+        let result: Header = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Header {
+                kind: 1,
+                version: 3,
+                size: 0x10,
+            }
+        );
+    }
+
+    #[test]
+    fn should_error_if_a_field_is_invalid() {
+        let bytes = vec![0x00, 0x01, 0x00, 0x04, 0x10, 0x00, 0x00, 0x00];
+        let error = bytes
+            .read_le::<Header>(1)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Header",
+                field: "version",
+                source: Box::new(Error::InvalidData {
+                    offset: 3,
+                    expected: 3,
+                    found: 4,
+                    description: Some("Unsupported version"),
+                }),
+            }
+        );
+    }
+}
+
+mod generic_bounds {
+    use super::*;
+    use core::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Entry {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Wrapper<T> {
+        kind: u8,
+        inner: T,
+    }
+
+    /// A type that can't be read, so parameters it's used for can't have an `EndianRead` bound.
+    #[derive(Debug, Default, PartialEq)]
+    struct NotReadable;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Unread<T, M, S: Default> {
+        inner: T,
+        marker: PhantomData<M>,
+        #[no_std_io(skip)]
+        cached: S,
+    }
+
+    #[test]
+    fn should_read_a_primitive_parameter() {
+        let bytes = vec![0x01, 0x11, 0x22, 0x33, 0x44];
+        let result: Wrapper<u32> = bytes.read_be(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Wrapper {
+                kind: 1,
+                inner: 0x11223344,
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_a_derived_parameter() {
+        let bytes = vec![0x01, 0x02, 0x11, 0x22];
+        let result: Wrapper<Entry> = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Wrapper {
+                kind: 1,
+                inner: Entry {
+                    kind: 2,
+                    value: 0x2211,
+                },
+            }
+        );
+    }
+
+    #[test]
+    fn should_not_bound_phantom_or_skipped_parameters() {
+        let bytes = vec![0x11, 0x22];
+        let result: Unread<u16, NotReadable, NotReadable> =
+            bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Unread {
+                inner: 0x2211,
+                marker: PhantomData,
+                cached: NotReadable,
+            }
+        );
+    }
 }
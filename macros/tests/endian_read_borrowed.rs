@@ -0,0 +1,287 @@
+use macros::{EndianRead, EndianReadBorrowed};
+use no_std_io::{Error, ReaderExt};
+
+#[derive(Debug, PartialEq, EndianReadBorrowed)]
+struct Record<'a> {
+    id: u32,
+    name_len: u8,
+    #[no_std_io(len_from = "name_len")]
+    name: &'a str,
+    #[no_std_io(rest)]
+    body: &'a [u8],
+}
+
+#[test]
+fn should_read_le() {
+    let bytes = vec![
+        0x11, 0x22, 0x33, 0x44, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0xbb, 0xcc,
+    ];
+    let result: Record = bytes.read_le_borrowed(0).expect("Read should have worked");
+
+    assert_eq!(
+        result,
+        Record {
+            id: 0x44332211,
+            name_len: 4,
+            name: "name",
+            body: &[0xaa, 0xbb, 0xcc],
+        }
+    );
+}
+
+#[test]
+fn should_read_be() {
+    let bytes = vec![
+        0x11, 0x22, 0x33, 0x44, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0xbb, 0xcc,
+    ];
+    let result: Record = bytes.read_be_borrowed(0).expect("Read should have worked");
+
+    assert_eq!(
+        result,
+        Record {
+            id: 0x11223344,
+            name_len: 4,
+            name: "name",
+            body: &[0xaa, 0xbb, 0xcc],
+        }
+    );
+}
+
+#[test]
+fn should_borrow_from_the_source_bytes() {
+    let bytes = vec![
+        0x11, 0x22, 0x33, 0x44, 0x04, 0x6e, 0x61, 0x6d, 0x65, 0xaa, 0xbb, 0xcc,
+    ];
+    let result: Record = bytes.read_le_borrowed(0).expect("Read should have worked");
+
+    assert_eq!(result.name.as_ptr(), bytes[5..].as_ptr());
+    assert_eq!(result.body.as_ptr(), bytes[9..].as_ptr());
+}
+
+#[test]
+fn should_return_an_empty_rest_field() {
+    let bytes = vec![0x11, 0x22, 0x33, 0x44, 0x00];
+    let result: Record = bytes.read_le_borrowed(0).expect("Read should have worked");
+
+    assert_eq!(result.name, "");
+    assert_eq!(result.body, &[]);
+}
+
+#[test]
+fn should_error_if_the_len_from_window_is_past_the_end() {
+    let bytes = vec![0x11, 0x22, 0x33, 0x44, 0x08, 0x6e, 0x61, 0x6d, 0x65];
+    let error = bytes
+        .read_le_borrowed::<Record>(0)
+        .expect_err("This should have failed");
+
+    assert_eq!(
+        error,
+        Error::Field {
+            struct_name: "Record",
+            field: "name",
+            source: Box::new(Error::InvalidSize {
+                wanted_size: 8,
+                offset: 5,
+                data_len: 9,
+            }),
+        }
+    );
+}
+
+#[test]
+fn should_error_if_a_str_is_not_utf8() {
+    let bytes = vec![0x11, 0x22, 0x33, 0x44, 0x02, 0x61, 0xff];
+    let error = bytes
+        .read_le_borrowed::<Record>(0)
+        .expect_err("This should have failed");
+
+    assert_eq!(
+        error,
+        Error::Field {
+            struct_name: "Record",
+            field: "name",
+            source: Box::new(Error::InvalidRead {
+                message: "Invalid UTF-8 string",
+            }),
+        }
+    );
+}
+
+mod nested {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianRead)]
+    struct Header {
+        kind: u8,
+        count: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianReadBorrowed)]
+    struct Entry<'a> {
+        len: u8,
+        #[no_std_io(len_from = "len")]
+        data: &'a [u8],
+    }
+
+    #[derive(Debug, PartialEq, EndianReadBorrowed)]
+    struct Packet<'a> {
+        header: Header,
+        first: Entry<'a>,
+        second: Entry<'a>,
+    }
+
+    #[test]
+    fn should_read_owned_and_borrowed_fields() {
+        let bytes = vec![0x01, 0x00, 0x02, 0x02, 0xaa, 0xbb, 0x01, 0xcc];
+        let result: Packet = bytes.read_be_borrowed(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Packet {
+                header: Header { kind: 1, count: 2 },
+                first: Entry {
+                    len: 2,
+                    data: &[0xaa, 0xbb],
+                },
+                second: Entry {
+                    len: 1,
+                    data: &[0xcc],
+                },
+            }
+        );
+        assert_eq!(result.second.data.as_ptr(), bytes[7..].as_ptr());
+    }
+
+    #[test]
+    fn should_name_the_nested_field() {
+        let bytes = vec![0x01, 0x00, 0x02, 0x02, 0xaa, 0xbb, 0x02, 0xcc];
+        let error = bytes
+            .read_be_borrowed::<Packet>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Packet",
+                field: "second",
+                source: Box::new(Error::Field {
+                    struct_name: "Entry",
+                    field: "data",
+                    source: Box::new(Error::InvalidSize {
+                        wanted_size: 2,
+                        offset: 7,
+                        data_len: 8,
+                    }),
+                }),
+            }
+        );
+    }
+}
+
+mod len_from_window {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianReadBorrowed)]
+    struct Name<'a> {
+        #[no_std_io(rest)]
+        name: &'a str,
+    }
+
+    #[derive(Debug, PartialEq, EndianReadBorrowed)]
+    struct Padded<'a> {
+        len: u8,
+        #[no_std_io(len_from = "len")]
+        value: u8,
+        #[no_std_io(len_from = "len")]
+        name: Name<'a>,
+        last: u8,
+    }
+
+    #[test]
+    fn should_consume_the_whole_window() {
+        let bytes = vec![0x02, 0xaa, 0xff, 0x6f, 0x6b, 0xbb];
+        let result: Padded = bytes.read_le_borrowed(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Padded {
+                len: 2,
+                value: 0xaa,
+                name: Name { name: "ok" },
+                last: 0xbb,
+            }
+        );
+    }
+}
+
+mod padding {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianReadBorrowed)]
+    #[no_std_io(endian = "little")]
+    struct Padded<'a> {
+        #[no_std_io(pad_before = 1)]
+        len: u16,
+        #[no_std_io(align = 4, len_from = "len", pad_after = 2)]
+        data: &'a [u8],
+        #[no_std_io(skip)]
+        cached: u32,
+        last: u8,
+    }
+
+    #[test]
+    fn should_pad_and_align_borrowed_fields() {
+        let bytes = vec![0xff, 0x02, 0x00, 0xff, 0xaa, 0xbb, 0xff, 0xff, 0xcc];
+        let result: Padded = bytes.read_be_borrowed(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Padded {
+                len: 2,
+                data: &[0xaa, 0xbb],
+                cached: 0,
+                last: 0xcc,
+            }
+        );
+        assert_eq!(result.data.as_ptr(), bytes[4..].as_ptr());
+    }
+}
+
+mod generic_bounds {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianReadBorrowed)]
+    struct Wrapper<'a, T> {
+        inner: T,
+        #[no_std_io(rest)]
+        tail: &'a [u8],
+    }
+
+    #[test]
+    fn should_read_an_owned_parameter() {
+        let bytes = vec![0x11, 0x22, 0xaa];
+        let result: Wrapper<u16> = bytes.read_be_borrowed(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Wrapper {
+                inner: 0x1122,
+                tail: &[0xaa],
+            }
+        );
+    }
+
+    #[test]
+    fn should_read_a_borrowed_parameter() {
+        let bytes = vec![0x68, 0x69];
+        let result: Wrapper<&str> = bytes.read_be_borrowed(0).expect("Read should have worked");
+
+        assert_eq!(
+            result,
+            Wrapper {
+                inner: "hi",
+                tail: &[],
+            }
+        );
+    }
+}
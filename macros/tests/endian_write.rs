@@ -122,10 +122,14 @@ fn should_error_if_there_are_not_enough_bytes() {
 
     assert_eq!(
         result,
-        Error::InvalidSize {
-            wanted_size: 4,
-            offset: 1,
-            data_len: 4
+        Error::Field {
+            struct_name: "Test",
+            field: "second",
+            source: Box::new(Error::InvalidSize {
+                wanted_size: 4,
+                offset: 1,
+                data_len: 4
+            }),
         }
     );
 }
@@ -355,3 +359,1347 @@ mod padding {
         );
     }
 }
+
+mod size_calc {
+    use super::*;
+    use no_std_io::SizeCalcWriter;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct PaddedContainer {
+        #[no_std_io(pad_before = 3)]
+        first: u16,
+        list: ListContainer<u16>,
+        #[no_std_io(pad_before = 1)]
+        test: Test,
+    }
+
+    fn assert_same_size<T: no_std_io::EndianWrite>(value: &T) {
+        let mut bytes = vec![];
+        bytes.write_le(2, value).expect("Write should have worked");
+
+        let mut writer = SizeCalcWriter::new();
+        writer.write_le(2, value).expect("Write should have worked");
+
+        assert_eq!(writer.size(), bytes.len());
+    }
+
+    #[test]
+    fn should_match_vec_writes_for_structs() {
+        assert_same_size(&Test {
+            first: 0xaa,
+            second: 0xeeddccbb,
+            array: [0x1122, 0x3344],
+        });
+    }
+
+    #[test]
+    fn should_match_vec_writes_for_dynamic_sizes() {
+        assert_same_size(&ListContainer::<u32>(vec![
+            0x44332211, 0xddccbbaa, 0x12345678,
+        ]));
+    }
+
+    #[test]
+    fn should_match_vec_writes_for_nested_structs() {
+        assert_same_size(&TestContainer {
+            test: Test::default(),
+            list: ListContainer(vec![0xddccbbaa, 0x88776655]),
+        });
+    }
+
+    #[test]
+    fn should_match_vec_writes_for_padded_structs() {
+        assert_same_size(&PaddedContainer {
+            first: 0x1122,
+            list: ListContainer(vec![0x3344]),
+            test: Test::default(),
+        });
+    }
+
+    #[test]
+    fn should_match_vec_stream_writes() {
+        let value = TestContainer {
+            test: Test::default(),
+            list: ListContainer(vec![0xddccbbaa]),
+        };
+
+        let mut stream = StreamContainer::new(vec![]);
+        stream
+            .write_stream_be(&value)
+            .expect("Write should have worked");
+        stream
+            .write_stream_be(&value)
+            .expect("Write should have worked");
+
+        let mut writer = SizeCalcWriter::new();
+        writer
+            .write_stream_be(&value)
+            .expect("Write should have worked");
+        writer
+            .write_stream_be(&value)
+            .expect("Write should have worked");
+
+        assert_eq!(writer.size(), stream.into_raw().len());
+        assert_eq!(writer.size(), 28);
+    }
+}
+
+mod transform {
+    use super::*;
+    use no_std_io::{EndianRead, ReaderExt, TransformReader, TransformWriter};
+
+    #[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+    struct Header {
+        magic: u32,
+        #[no_std_io(pad_before = 2)]
+        size: u16,
+        flags: [u8; 2],
+    }
+
+    fn rolling_xor(offset: usize, byte: u8) -> u8 {
+        byte ^ (0x5a ^ offset as u8)
+    }
+
+    #[test]
+    fn should_round_trip_a_struct() {
+        let value = Header {
+            magic: 0x46464952,
+            size: 0x1234,
+            flags: [0xaa, 0xbb],
+        };
+
+        let mut writer = TransformWriter::new(vec![], rolling_xor);
+        writer
+            .write_le(4, &value)
+            .expect("Write should have worked");
+        let encoded = writer.into_inner();
+
+        let reader = TransformReader::new(encoded.as_slice(), rolling_xor);
+        let result = reader
+            .read_le::<Header>(4)
+            .expect("Read should have worked");
+
+        assert_eq!(result, value);
+        assert_ne!(encoded[4..8], value.magic.to_le_bytes());
+    }
+}
+
+mod field_context {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Entries {
+        count: u32,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Header {
+        magic: u16,
+        version: u16,
+        entries: Entries,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct PaddedHeader {
+        magic: u16,
+        #[no_std_io(pad_after = 4)]
+        version: u16,
+        flags: u16,
+    }
+
+    #[test]
+    fn should_name_the_field_whose_padding_failed() {
+        let value = PaddedHeader {
+            magic: 0xbbaa,
+            version: 1,
+            flags: 2,
+        };
+        let mut bytes = [0; 6];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "PaddedHeader",
+                field: "version",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 4,
+                    data_len: 6,
+                }),
+            }
+        );
+    }
+
+    #[test]
+    fn should_name_the_third_field() {
+        let value = Header {
+            magic: 0xbbaa,
+            version: 1,
+            entries: Entries { count: 2 },
+        };
+        let mut bytes = [0; 6];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Header",
+                field: "entries",
+                source: Box::new(Error::Field {
+                    struct_name: "Entries",
+                    field: "count",
+                    source: Box::new(Error::InvalidSize {
+                        wanted_size: 4,
+                        offset: 4,
+                        data_len: 6,
+                    }),
+                }),
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "Invalid field Header.entries.count: Invalid size: wanted 0x4 at offset offset: 0x4, but data length is 0x6 "
+        );
+    }
+}
+
+mod tuple_structs {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Crc(u32);
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Vec3(f32, f32, f32);
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct PaddedRecord(u8, #[no_std_io(pad_before = 2)] u16, [u16; 2]);
+
+    #[test]
+    fn should_get_size() {
+        assert_eq!(Crc(0).get_size(), 4);
+        assert_eq!(Vec3(1.0, 2.0, 3.0).get_size(), 12);
+        assert_eq!(PaddedRecord(0xaa, 0xbbcc, [0x11, 0x22]).get_size(), 9);
+    }
+
+    #[test]
+    fn should_write_a_newtype_le() {
+        let mut bytes = vec![0; 4];
+        let result = bytes
+            .write_le(0, &Crc(0x44332211))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 4);
+        assert_eq!(bytes, [0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn should_write_a_newtype_be() {
+        let mut bytes = vec![0; 4];
+        let result = bytes
+            .write_be(0, &Crc(0x11223344))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 4);
+        assert_eq!(bytes, [0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn should_write_multiple_fields_le() {
+        let mut bytes = vec![0; 12];
+        let result = bytes
+            .write_le(0, &Vec3(1.0, 2.0, 3.0))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 12);
+        assert_eq!(
+            bytes,
+            [0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x40, 0x40]
+        );
+    }
+
+    #[test]
+    fn should_write_multiple_fields_be() {
+        let mut bytes = vec![0; 12];
+        let result = bytes
+            .write_be(0, &Vec3(1.0, 2.0, 3.0))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 12);
+        assert_eq!(
+            bytes,
+            [0x3f, 0x80, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x40, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn should_write_padded_fields_le() {
+        let mut bytes = vec![0; 9];
+        let result = bytes
+            .write_le(0, &PaddedRecord(0xaa, 0xbbcc, [0x11, 0x22]))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(
+            bytes,
+            [0xaa, 0x00, 0x00, 0xcc, 0xbb, 0x11, 0x00, 0x22, 0x00]
+        );
+    }
+
+    #[test]
+    fn should_write_padded_fields_be() {
+        let mut bytes = vec![0; 9];
+        let result = bytes
+            .write_be(0, &PaddedRecord(0xaa, 0xbbcc, [0x11, 0x22]))
+            .expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(
+            bytes,
+            [0xaa, 0x00, 0x00, 0xbb, 0xcc, 0x00, 0x11, 0x00, 0x22]
+        );
+    }
+
+    #[test]
+    fn should_name_fields_by_position() {
+        let mut bytes = [0; 6];
+        let error = bytes
+            .write_le(0, &Vec3(1.0, 2.0, 3.0))
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Vec3",
+                field: "1",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 4,
+                    data_len: 6,
+                }),
+            }
+        );
+    }
+}
+
+mod unit_structs {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct KeepAlive;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct EmptyNamed {}
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct EmptyTuple();
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Message {
+        tag: KeepAlive,
+        id: u16,
+        empty: EmptyNamed,
+        other: EmptyTuple,
+        value: u8,
+    }
+
+    #[test]
+    fn should_get_size() {
+        assert_eq!(KeepAlive.get_size(), 0);
+        assert_eq!(EmptyNamed {}.get_size(), 0);
+        assert_eq!(EmptyTuple().get_size(), 0);
+    }
+
+    #[test]
+    fn should_write_zero_bytes() {
+        let mut bytes: [u8; 0] = [];
+
+        assert_eq!(KeepAlive.try_write_le(&mut bytes), Ok(0));
+        assert_eq!(EmptyNamed {}.try_write_be(&mut bytes), Ok(0));
+        assert_eq!(EmptyTuple().try_write_le(&mut bytes), Ok(0));
+    }
+
+    #[test]
+    fn should_write_as_a_field_le() {
+        let value = Message {
+            tag: KeepAlive,
+            id: 0x2211,
+            empty: EmptyNamed {},
+            other: EmptyTuple(),
+            value: 0x33,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(value.get_size(), 3);
+        assert_eq!(result, 3);
+        assert_eq!(bytes, [0x11, 0x22, 0x33]);
+    }
+
+    #[test]
+    fn should_write_as_a_field_be() {
+        let value = Message {
+            tag: KeepAlive,
+            id: 0x1122,
+            empty: EmptyNamed {},
+            other: EmptyTuple(),
+            value: 0x33,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 3);
+        assert_eq!(bytes, [0x11, 0x22, 0x33]);
+    }
+}
+
+mod padding_after {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Record {
+        #[no_std_io(pad_after = 1)]
+        kind: u8,
+        #[no_std_io(pad_after = 4)]
+        name: [u8; 4],
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Wrapper {
+        record: Record,
+        next: u16,
+    }
+
+    #[test]
+    fn should_get_size() {
+        let value = Record {
+            kind: 0xaa,
+            name: [0x11, 0x22, 0x33, 0x44],
+        };
+        assert_eq!(value.get_size(), 10);
+    }
+
+    #[test]
+    fn should_write_le() {
+        let value = Record {
+            kind: 0xaa,
+            name: [0x11, 0x22, 0x33, 0x44],
+        };
+        let mut bytes = vec![0xff; 10];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 10);
+        assert_eq!(
+            bytes,
+            [0xaa, 0x00, 0x11, 0x22, 0x33, 0x44, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn should_write_nested_be() {
+        let value = Wrapper {
+            record: Record {
+                kind: 0xaa,
+                name: [0x11, 0x22, 0x33, 0x44],
+            },
+            next: 0x1234,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 12);
+        assert_eq!(value.get_size(), 12);
+        assert_eq!(
+            bytes,
+            [0xaa, 0x00, 0x11, 0x22, 0x33, 0x44, 0x00, 0x00, 0x00, 0x00, 0x12, 0x34]
+        );
+    }
+
+    #[test]
+    fn should_error_if_the_final_padding_does_not_fit() {
+        let value = Record {
+            kind: 0xaa,
+            name: [0x11, 0x22, 0x33, 0x44],
+        };
+        let mut bytes = [0; 8];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Record",
+                field: "name",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }),
+            }
+        );
+    }
+}
+
+mod alignment {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct AlignedTest {
+        list: ListContainer<u8>,
+        #[no_std_io(align = 4)]
+        value: u32,
+        #[no_std_io(align = 2)]
+        last: u8,
+    }
+
+    #[test]
+    fn should_get_the_aligned_size() {
+        let value = AlignedTest {
+            list: ListContainer(vec![0xaa, 0xbb]),
+            value: 0x44332211,
+            last: 0xcc,
+        };
+        assert_eq!(value.get_size(), 9);
+
+        let value = AlignedTest {
+            list: ListContainer(vec![0xaa, 0xbb, 0xcc, 0xdd]),
+            value: 0x44332211,
+            last: 0xcc,
+        };
+        assert_eq!(value.get_size(), 13);
+    }
+
+    #[test]
+    fn should_fill_to_the_next_boundary_le() {
+        let value = AlignedTest {
+            list: ListContainer(vec![0xaa, 0xbb]),
+            value: 0x44332211,
+            last: 0xcc,
+        };
+        let mut bytes = vec![0xff; 9];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(
+            bytes,
+            [0x02, 0xaa, 0xbb, 0x00, 0x11, 0x22, 0x33, 0x44, 0xcc]
+        );
+    }
+
+    #[test]
+    fn should_not_fill_if_already_aligned_be() {
+        let value = AlignedTest {
+            list: ListContainer(vec![0xaa, 0xbb, 0xcc]),
+            value: 0x11223344,
+            last: 0xdd,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(value.get_size(), 9);
+        assert_eq!(
+            bytes,
+            [0x03, 0xaa, 0xbb, 0xcc, 0x11, 0x22, 0x33, 0x44, 0xdd]
+        );
+    }
+
+    #[test]
+    fn should_error_if_the_boundary_does_not_fit() {
+        let value = AlignedTest {
+            list: ListContainer(vec![0xaa]),
+            value: 0,
+            last: 0,
+        };
+        let mut bytes = [0; 3];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "AlignedTest",
+                field: "value",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 2,
+                    data_len: 3,
+                }),
+            }
+        );
+    }
+}
+
+mod skip {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq)]
+    struct Handle {
+        fd: i32,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct SkipTest {
+        id: u16,
+        #[no_std_io(skip)]
+        handle: Handle,
+        #[no_std_io(skip, pad_before = 2)]
+        reserved: (),
+        value: u8,
+    }
+
+    #[test]
+    fn should_not_count_skipped_fields() {
+        let value = SkipTest {
+            id: 0x2211,
+            handle: Handle { fd: 3 },
+            reserved: (),
+            value: 0xaa,
+        };
+        assert_eq!(value.get_size(), 5);
+    }
+
+    #[test]
+    fn should_not_write_skipped_fields_le() {
+        let value = SkipTest {
+            id: 0x2211,
+            handle: Handle { fd: 3 },
+            reserved: (),
+            value: 0xaa,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 5);
+        assert_eq!(bytes, [0x11, 0x22, 0x00, 0x00, 0xaa]);
+    }
+
+    #[test]
+    fn should_not_write_skipped_fields_be() {
+        let value = SkipTest {
+            id: 0x1122,
+            handle: Handle { fd: 3 },
+            reserved: (),
+            value: 0xaa,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 5);
+        assert_eq!(bytes, [0x11, 0x22, 0x00, 0x00, 0xaa]);
+    }
+}
+
+mod default_on_eof {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct VersionedHeader {
+        magic: u16,
+        version: u8,
+        #[no_std_io(default_on_eof)]
+        flags: u16,
+        #[no_std_io(default_on_eof)]
+        extra: u32,
+    }
+
+    #[test]
+    fn should_always_write_the_fields() {
+        let value = VersionedHeader {
+            magic: 0xbbaa,
+            version: 1,
+            flags: 0,
+            extra: 0,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(value.get_size(), 9);
+        assert_eq!(
+            bytes,
+            [0xaa, 0xbb, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]
+        );
+    }
+}
+
+mod magic {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct MagicTest {
+        #[no_std_io(magic = 0x4d5a)]
+        signature: u16,
+        #[no_std_io(magic = b"RIFF")]
+        riff: [u8; 4],
+        size: u32,
+    }
+
+    #[test]
+    fn should_write_the_magic_le() {
+        let value = MagicTest {
+            signature: 0,
+            riff: [0; 4],
+            size: 0x10,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 10);
+        assert_eq!(value.get_size(), 10);
+        assert_eq!(
+            bytes,
+            [0x5a, 0x4d, 0x52, 0x49, 0x46, 0x46, 0x10, 0x00, 0x00, 0x00]
+        );
+    }
+
+    #[test]
+    fn should_write_the_magic_be() {
+        let value = MagicTest {
+            signature: 0x1234,
+            riff: *b"WAVE",
+            size: 0x10,
+        };
+        let mut bytes = vec![];
+        bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(
+            bytes,
+            [0x4d, 0x5a, 0x52, 0x49, 0x46, 0x46, 0x00, 0x00, 0x00, 0x10]
+        );
+    }
+}
+
+mod fixed_endian {
+    use super::*;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    #[no_std_io(endian = "little")]
+    struct LittleTest {
+        first: u16,
+        second: [u16; 2],
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    #[no_std_io(endian = "big")]
+    struct BigTest(u32);
+
+    #[test]
+    fn should_write_little_endian_from_both_methods() {
+        let value = LittleTest {
+            first: 0x2211,
+            second: [0x4433, 0x6655],
+        };
+        let mut le_bytes = vec![];
+        let mut be_bytes = vec![];
+        le_bytes
+            .write_le(0, &value)
+            .expect("Write should have worked");
+        be_bytes
+            .write_be(0, &value)
+            .expect("Write should have worked");
+
+        assert_eq!(le_bytes, [0x11, 0x22, 0x33, 0x44, 0x55, 0x66]);
+        assert_eq!(le_bytes, be_bytes);
+    }
+
+    #[test]
+    fn should_write_big_endian_from_both_methods() {
+        let value = BigTest(0x11223344);
+        let mut le_bytes = vec![];
+        let mut be_bytes = vec![];
+        le_bytes
+            .write_le(0, &value)
+            .expect("Write should have worked");
+        be_bytes
+            .write_be(0, &value)
+            .expect("Write should have worked");
+
+        assert_eq!(le_bytes, [0x11, 0x22, 0x33, 0x44]);
+        assert_eq!(le_bytes, be_bytes);
+    }
+}
+
+mod len_from {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Entry {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Table {
+        count: u16,
+        #[no_std_io(len_from = "count")]
+        entries: Vec<Entry>,
+        last: u8,
+    }
+
+    #[test]
+    fn should_write_zero_values() {
+        let value = Table {
+            count: 0,
+            entries: vec![],
+            last: 0xaa,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 3);
+        assert_eq!(value.get_size(), 3);
+        assert_eq!(bytes, [0x00, 0x00, 0xaa]);
+    }
+
+    #[test]
+    fn should_write_nested_values_be() {
+        let value = Table {
+            count: 2,
+            entries: vec![
+                Entry {
+                    kind: 1,
+                    value: 0x1122,
+                },
+                Entry {
+                    kind: 2,
+                    value: 0x3344,
+                },
+            ],
+            last: 0xaa,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(value.get_size(), 9);
+        assert_eq!(
+            bytes,
+            [0x00, 0x02, 0x01, 0x11, 0x22, 0x02, 0x33, 0x44, 0xaa]
+        );
+    }
+
+    #[test]
+    fn should_error_if_the_count_does_not_match() {
+        let value = Table {
+            count: 3,
+            entries: vec![Entry {
+                kind: 1,
+                value: 0x1122,
+            }],
+            last: 0xaa,
+        };
+        let mut bytes = vec![];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Table",
+                field: "entries",
+                source: Box::new(Error::InvalidWrite {
+                    message: "Count field does not match the number of values",
+                }),
+            }
+        );
+    }
+}
+
+mod len_of {
+    use super::*;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Entry {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Table {
+        #[no_std_io(len_of = "entries")]
+        count: u16,
+        #[no_std_io(len_from = "count")]
+        entries: Vec<Entry>,
+        last: u8,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Packet {
+        #[no_std_io(len_of = "payload", len_kind = "bytes")]
+        size: u8,
+        kind: u8,
+        #[no_std_io(rest)]
+        payload: Vec<Entry>,
+    }
+
+    #[test]
+    fn should_write_the_number_of_values_instead_of_a_stale_count() {
+        let value = Table {
+            count: 7,
+            entries: vec![
+                Entry {
+                    kind: 1,
+                    value: 0x1122,
+                },
+                Entry {
+                    kind: 2,
+                    value: 0x3344,
+                },
+            ],
+            last: 0xaa,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 9);
+        assert_eq!(value.get_size(), 9);
+        assert_eq!(
+            bytes,
+            [0x00, 0x02, 0x01, 0x11, 0x22, 0x02, 0x33, 0x44, 0xaa]
+        );
+    }
+
+    #[test]
+    fn should_write_the_number_of_bytes() {
+        let value = Packet {
+            size: 0,
+            kind: 0xaa,
+            payload: vec![
+                Entry {
+                    kind: 1,
+                    value: 0x1122,
+                },
+                Entry {
+                    kind: 2,
+                    value: 0x3344,
+                },
+            ],
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 8);
+        assert_eq!(bytes, [0x06, 0xaa, 0x01, 0x22, 0x11, 0x02, 0x44, 0x33]);
+    }
+
+    #[test]
+    fn should_error_if_the_length_does_not_fit() {
+        let value = Packet {
+            size: 0,
+            kind: 0xaa,
+            payload: (0..100).map(|kind| Entry { kind, value: 0 }).collect(),
+        };
+        let mut bytes = vec![];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Packet",
+                field: "size",
+                source: Box::new(Error::InvalidWrite {
+                    message: "Length does not fit in the len_of field",
+                }),
+            }
+        );
+    }
+}
+
+mod rest {
+    use super::*;
+    use macros::EndianRead;
+    use no_std_io::{EndianWrite, ReaderExt};
+
+    #[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+    struct Packet {
+        kind: u8,
+        length: u16,
+        #[no_std_io(rest)]
+        payload: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+    struct Words(u8, #[no_std_io(rest)] Vec<u16>);
+
+    #[test]
+    fn should_write_the_payload() {
+        let value = Packet {
+            kind: 1,
+            length: 3,
+            payload: vec![0xaa, 0xbb, 0xcc],
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 6);
+        assert_eq!(value.get_size(), 6);
+        assert_eq!(bytes, [0x01, 0x00, 0x03, 0xaa, 0xbb, 0xcc]);
+    }
+
+    #[test]
+    fn should_round_trip_the_payload() {
+        let value = Words(2, vec![0x1122, 0x3344]);
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+        let result: Words = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(value.get_size(), 5);
+        assert_eq!(bytes, [0x02, 0x22, 0x11, 0x44, 0x33]);
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn should_round_trip_an_empty_payload() {
+        let value = Packet {
+            kind: 1,
+            length: 0,
+            payload: vec![],
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+        let result: Packet = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(value.get_size(), 3);
+        assert_eq!(bytes, [0x01, 0x00, 0x00]);
+        assert_eq!(result, value);
+    }
+}
+
+mod with {
+    use super::*;
+    use macros::EndianRead;
+    use no_std_io::{EndianWrite, ReaderExt};
+
+    mod xor {
+        use no_std_io::{Error, StreamReader, StreamWriter};
+
+        const KEY: u32 = 0xaabbccdd;
+
+        pub fn read_le<S: StreamReader>(stream: &mut S) -> Result<u32, Error> {
+            Ok(stream.read_stream_le::<u32>()? ^ KEY)
+        }
+
+        pub fn read_be<S: StreamReader>(stream: &mut S) -> Result<u32, Error> {
+            Ok(stream.read_stream_be::<u32>()? ^ KEY)
+        }
+
+        pub fn write_le<S: StreamWriter>(stream: &mut S, value: &u32) -> Result<(), Error> {
+            stream.write_stream_le(&(value ^ KEY))?;
+            Ok(())
+        }
+
+        pub fn write_be<S: StreamWriter>(stream: &mut S, value: &u32) -> Result<(), Error> {
+            stream.write_stream_be(&(value ^ KEY))?;
+            Ok(())
+        }
+
+        pub fn size(_value: &u32) -> usize {
+            4
+        }
+    }
+
+    mod length_prefixed {
+        use no_std_io::{Error, StreamWriter};
+
+        pub fn write_le<S: StreamWriter>(stream: &mut S, value: &[u8]) -> Result<(), Error> {
+            stream.write_stream_le(&(value.len() as u8))?;
+            stream.write_stream_bytes(value)?;
+            Ok(())
+        }
+
+        pub fn write_be<S: StreamWriter>(stream: &mut S, value: &[u8]) -> Result<(), Error> {
+            write_le(stream, value)
+        }
+
+        pub fn size(value: &[u8]) -> usize {
+            value.len() + 1
+        }
+    }
+
+    #[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+    struct Obfuscated {
+        kind: u8,
+        #[no_std_io(with = "xor")]
+        value: u32,
+        last: u8,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Name {
+        #[no_std_io(write_with = "length_prefixed")]
+        bytes: Vec<u8>,
+    }
+
+    #[test]
+    fn should_write_with_the_module_le() {
+        let value = Obfuscated {
+            kind: 1,
+            value: 0,
+            last: 2,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 6);
+        assert_eq!(value.get_size(), 6);
+        assert_eq!(bytes, [0x01, 0xdd, 0xcc, 0xbb, 0xaa, 0x02]);
+    }
+
+    #[test]
+    fn should_write_with_the_module_be() {
+        let value = Obfuscated {
+            kind: 1,
+            value: 1,
+            last: 2,
+        };
+        let mut bytes = vec![];
+        bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x01, 0xaa, 0xbb, 0xcc, 0xdc, 0x02]);
+    }
+
+    #[test]
+    fn should_round_trip_with_the_module() {
+        let value = Obfuscated {
+            kind: 1,
+            value: 0x12345678,
+            last: 2,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+        let result: Obfuscated = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(result, value);
+    }
+
+    #[test]
+    fn should_write_with_a_write_only_module() {
+        let value = Name {
+            bytes: vec![0x61, 0x62],
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 3);
+        assert_eq!(value.get_size(), 3);
+        assert_eq!(bytes, [0x02, 0x61, 0x62]);
+    }
+}
+
+mod map {
+    use super::*;
+    use macros::EndianRead;
+    use no_std_io::{EndianWrite, ReaderExt};
+
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    impl Kind {
+        fn from_raw(raw: u16) -> Self {
+            match raw {
+                0 => Self::Small,
+                _ => Self::Large,
+            }
+        }
+
+        fn to_raw(&self) -> u16 {
+            match self {
+                Self::Small => 0,
+                Self::Large => 1,
+            }
+        }
+    }
+
+    fn flag_from_raw(raw: u8) -> bool {
+        raw != 0
+    }
+
+    fn flag_to_raw(flag: &bool) -> u8 {
+        *flag as u8
+    }
+
+    #[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+    struct Record {
+        id: u8,
+        #[no_std_io(map_from = "u16", map = "Kind::from_raw", unmap = "Kind::to_raw")]
+        kind: Kind,
+        #[no_std_io(map_from = "u8", map = "flag_from_raw", unmap = "flag_to_raw")]
+        flag: bool,
+    }
+
+    #[test]
+    fn should_write_mapped_fields() {
+        let value = Record {
+            id: 1,
+            kind: Kind::Large,
+            flag: true,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 4);
+        assert_eq!(value.get_size(), 4);
+        assert_eq!(bytes, [0x01, 0x00, 0x01, 0x01]);
+    }
+
+    #[test]
+    fn should_round_trip_mapped_fields() {
+        let value = Record {
+            id: 2,
+            kind: Kind::Small,
+            flag: false,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+        let result: Record = bytes.read_le(0).expect("Read should have worked");
+
+        assert_eq!(bytes, [0x02, 0x00, 0x00, 0x00]);
+        assert_eq!(result, value);
+    }
+}
+
+mod validate {
+    use super::*;
+
+    fn check_length(length: &u16) -> Result<(), Error> {
+        if *length == 0 {
+            return Err(Error::InvalidWrite {
+                message: "Length must be nonzero",
+            });
+        }
+
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Header {
+        kind: u8,
+        #[no_std_io(validate = "check_length")]
+        length: u16,
+    }
+
+    #[test]
+    fn should_write_a_valid_field() {
+        let value = Header { kind: 1, length: 2 };
+        let mut bytes = vec![];
+        let result = bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 3);
+        assert_eq!(bytes, [0x01, 0x02, 0x00]);
+    }
+
+    #[test]
+    fn should_error_if_a_field_is_invalid() {
+        let value = Header { kind: 1, length: 0 };
+        let mut bytes = vec![];
+        let error = bytes
+            .write_le(0, &value)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::Field {
+                struct_name: "Header",
+                field: "length",
+                source: Box::new(Error::InvalidWrite {
+                    message: "Length must be nonzero",
+                }),
+            }
+        );
+    }
+}
+
+mod generic_bounds {
+    use super::*;
+    use core::marker::PhantomData;
+    use no_std_io::EndianWrite;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Entry {
+        kind: u8,
+        value: u16,
+    }
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Wrapper<T> {
+        kind: u8,
+        inner: T,
+    }
+
+    /// A type that can't be written, so parameters it's used for can't have an `EndianWrite` bound.
+    #[derive(Debug, PartialEq)]
+    struct NotWritable;
+
+    #[derive(Debug, PartialEq, EndianWrite)]
+    struct Unwritten<T, M, S> {
+        inner: T,
+        marker: PhantomData<M>,
+        #[no_std_io(skip)]
+        cached: S,
+    }
+
+    #[test]
+    fn should_write_a_primitive_parameter() {
+        let value = Wrapper {
+            kind: 1,
+            inner: 0x11223344u32,
+        };
+        let mut bytes = vec![];
+        let result = bytes.write_be(0, &value).expect("Write should have worked");
+
+        assert_eq!(result, 5);
+        assert_eq!(value.get_size(), 5);
+        assert_eq!(bytes, [0x01, 0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn should_write_a_derived_parameter() {
+        let value = Wrapper {
+            kind: 1,
+            inner: Entry {
+                kind: 2,
+                value: 0x2211,
+            },
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x01, 0x02, 0x11, 0x22]);
+    }
+
+    #[test]
+    fn should_not_bound_phantom_or_skipped_parameters() {
+        let value: Unwritten<u16, NotWritable, NotWritable> = Unwritten {
+            inner: 0x2211,
+            marker: PhantomData,
+            cached: NotWritable,
+        };
+        let mut bytes = vec![];
+        bytes.write_le(0, &value).expect("Write should have worked");
+
+        assert_eq!(bytes, [0x11, 0x22]);
+    }
+}
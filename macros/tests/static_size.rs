@@ -0,0 +1,171 @@
+use core::marker::PhantomData;
+use no_std_io::{EndianRead, EndianWrite, Error, LeIter, StaticSize, StreamContainer};
+
+/// `SizeOf::<T>::SIZE` is only `Some` if `T` implements `StaticSize`.
+struct SizeOf<T>(PhantomData<T>);
+
+trait NotStatic {
+    const SIZE: Option<usize> = None;
+}
+
+impl<T> NotStatic for SizeOf<T> {}
+
+impl<T: StaticSize> SizeOf<T> {
+    const SIZE: Option<usize> = Some(T::SIZE);
+}
+
+#[derive(Debug, PartialEq, EndianRead, EndianWrite, StaticSize)]
+struct Entry {
+    kind: u8,
+    value: u16,
+}
+
+#[derive(Debug, PartialEq, EndianRead, StaticSize)]
+struct Header {
+    #[no_std_io(pad_after = 1)]
+    kind: u8,
+    #[no_std_io(align = 4)]
+    size: u32,
+    array: [u16; 2],
+    entry: Entry,
+    #[no_std_io(skip)]
+    cached: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, EndianWrite, StaticSize)]
+struct Command {
+    #[no_std_io(map_from = "u16", unmap = "Command::kind_to_raw")]
+    kind: u8,
+    #[no_std_io(pad_before = 2)]
+    value: u32,
+}
+
+impl Command {
+    fn kind_to_raw(kind: &u8) -> u16 {
+        *kind as u16
+    }
+}
+
+#[derive(Debug, PartialEq, EndianRead, StaticSize)]
+struct Wrapper<T> {
+    kind: u8,
+    inner: T,
+}
+
+#[derive(Debug, PartialEq, EndianRead)]
+struct Table {
+    count: u8,
+    #[no_std_io(len_from = "count")]
+    entries: Vec<Entry>,
+}
+
+/// A static type whose `get_size` panics, so derived types can show they use `SIZE` instead.
+#[derive(Debug, PartialEq)]
+struct Slot;
+
+impl StaticSize for Slot {
+    const SIZE: usize = 4;
+}
+
+impl EndianWrite for Slot {
+    fn get_size(&self) -> usize {
+        panic!("get_size shouldn't be called for types that implement StaticSize")
+    }
+
+    fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        0u32.try_write_le(dst)
+    }
+
+    fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+        0u32.try_write_be(dst)
+    }
+}
+
+#[derive(Debug, PartialEq, EndianWrite, StaticSize)]
+struct Slots {
+    first: Slot,
+    second: Slot,
+}
+
+#[derive(Debug, PartialEq, EndianWrite)]
+struct DynamicSlots {
+    first: Slot,
+    #[no_std_io(rest)]
+    rest: Vec<u8>,
+}
+
+#[test]
+fn should_add_up_the_size_of_the_fields() {
+    assert_eq!(Entry::SIZE, 3);
+}
+
+#[test]
+fn should_include_padding_and_alignment() {
+    assert_eq!(Header::SIZE, 15);
+}
+
+#[test]
+fn should_use_the_map_from_type() {
+    assert_eq!(Command::SIZE, 8);
+}
+
+#[test]
+fn should_add_bounds_for_generic_fields() {
+    assert_eq!(Wrapper::<u32>::SIZE, 5);
+    assert_eq!(Wrapper::<Entry>::SIZE, 4);
+}
+
+#[test]
+fn should_match_the_bytes_read() {
+    let bytes = vec![0; 15];
+    let result = Header::try_read_le(&bytes).expect("Read should have worked");
+
+    assert_eq!(result.get_read_bytes(), Header::SIZE);
+}
+
+#[test]
+fn should_match_the_bytes_written() {
+    let command = Command { kind: 1, value: 2 };
+    let mut bytes = vec![0; 8];
+    let written = command
+        .try_write_le(&mut bytes)
+        .expect("Write should have succeeded");
+
+    assert_eq!(written, Command::SIZE);
+    assert_eq!(command.get_size(), Command::SIZE);
+}
+
+#[test]
+fn should_return_the_static_size_from_get_size() {
+    let slots = Slots {
+        first: Slot,
+        second: Slot,
+    };
+
+    assert_eq!(slots.get_size(), 8);
+}
+
+#[test]
+#[should_panic(expected = "get_size shouldn't be called")]
+fn should_add_up_the_fields_for_dynamic_types() {
+    let slots = DynamicSlots {
+        first: Slot,
+        rest: vec![],
+    };
+
+    slots.get_size();
+}
+
+#[test]
+fn should_not_implement_static_size_without_the_derive() {
+    assert_eq!(SizeOf::<Entry>::SIZE, Some(3));
+    assert_eq!(SizeOf::<Table>::SIZE, None);
+}
+
+#[test]
+fn should_iterate_with_an_exact_size() {
+    let bytes = vec![0x01, 0x02, 0x00, 0x03, 0x04, 0x00, 0x05];
+    let iter = LeIter::<Entry, _>::new(StreamContainer::new(bytes.as_slice()));
+
+    assert_eq!(iter.len(), 2);
+}
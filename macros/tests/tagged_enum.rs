@@ -0,0 +1,226 @@
+use macros::{EndianRead, EndianWrite};
+use no_std_io::{
+    Cursor, EndianWrite as _, Error, ReaderExt, StreamContainer, StreamReader, Writer,
+};
+
+#[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+struct Payload {
+    kind: u8,
+    value: u32,
+}
+
+#[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+#[no_std_io(tag_type = "u8")]
+enum Packet {
+    #[no_std_io(tag = 1)]
+    Ping,
+    #[no_std_io(tag = 2)]
+    Data { id: u16, payload: Payload },
+    #[no_std_io(tag = 3)]
+    Position(f32, #[no_std_io(pad_before = 2)] f32),
+    #[no_std_io(catch_all)]
+    Unknown(u8, Vec<u8>),
+}
+
+#[derive(Debug, PartialEq, EndianRead, EndianWrite)]
+#[no_std_io(tag_type = "u16")]
+enum Command {
+    #[no_std_io(tag = 0x100)]
+    Reset,
+    #[no_std_io(tag = 0x200)]
+    Move { x: i16, y: i16 },
+}
+
+fn assert_round_trip_le(value: Packet, expected_bytes: &[u8]) {
+    let mut bytes = vec![];
+    let written = bytes.write_le(0, &value).expect("Write should have worked");
+
+    assert_eq!(written, expected_bytes.len());
+    assert_eq!(value.get_size(), expected_bytes.len());
+    assert_eq!(bytes, expected_bytes);
+    assert_eq!(bytes.read_le::<Packet>(0), Ok(value));
+}
+
+fn assert_round_trip_be(value: Packet, expected_bytes: &[u8]) {
+    let mut bytes = vec![];
+    let written = bytes.write_be(0, &value).expect("Write should have worked");
+
+    assert_eq!(written, expected_bytes.len());
+    assert_eq!(value.get_size(), expected_bytes.len());
+    assert_eq!(bytes, expected_bytes);
+    assert_eq!(bytes.read_be::<Packet>(0), Ok(value));
+}
+
+#[test]
+fn should_round_trip_a_unit_variant() {
+    assert_round_trip_le(Packet::Ping, &[0x01]);
+    assert_round_trip_be(Packet::Ping, &[0x01]);
+}
+
+#[test]
+fn should_round_trip_a_nested_struct_payload_le() {
+    let value = Packet::Data {
+        id: 0x2211,
+        payload: Payload {
+            kind: 0xaa,
+            value: 0x66554433,
+        },
+    };
+
+    assert_round_trip_le(value, &[0x02, 0x11, 0x22, 0xaa, 0x33, 0x44, 0x55, 0x66]);
+}
+
+#[test]
+fn should_round_trip_a_nested_struct_payload_be() {
+    let value = Packet::Data {
+        id: 0x1122,
+        payload: Payload {
+            kind: 0xaa,
+            value: 0x33445566,
+        },
+    };
+
+    assert_round_trip_be(value, &[0x02, 0x11, 0x22, 0xaa, 0x33, 0x44, 0x55, 0x66]);
+}
+
+#[test]
+fn should_round_trip_a_padded_tuple_variant() {
+    assert_round_trip_le(
+        Packet::Position(1.0, 2.0),
+        &[
+            0x03, 0x00, 0x00, 0x80, 0x3f, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+        ],
+    );
+    assert_round_trip_be(
+        Packet::Position(1.0, 2.0),
+        &[
+            0x03, 0x3f, 0x80, 0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+        ],
+    );
+}
+
+#[test]
+fn should_round_trip_the_catch_all_variant() {
+    assert_round_trip_le(Packet::Unknown(0x7f, vec![0xaa, 0xbb]), &[0x7f, 0xaa, 0xbb]);
+    assert_round_trip_be(Packet::Unknown(0x80, vec![]), &[0x80]);
+}
+
+#[test]
+fn should_read_the_remaining_bytes_into_the_catch_all_variant() {
+    let bytes = vec![0x00, 0x09, 0x11, 0x22, 0x33];
+    let mut stream = StreamContainer::new_at(bytes.as_slice(), 1);
+    let result: Packet = stream.read_stream_le().expect("Read should have worked");
+
+    assert_eq!(result, Packet::Unknown(0x09, vec![0x11, 0x22, 0x33]));
+    assert_eq!(stream.get_index(), 5);
+}
+
+#[test]
+fn should_get_the_size_of_each_variant() {
+    assert_eq!(Packet::Ping.get_size(), 1);
+    assert_eq!(
+        Packet::Data {
+            id: 0,
+            payload: Payload { kind: 0, value: 0 },
+        }
+        .get_size(),
+        8
+    );
+    assert_eq!(Packet::Position(0.0, 0.0).get_size(), 11);
+    assert_eq!(Packet::Unknown(0, vec![0; 5]).get_size(), 6);
+    assert_eq!(Command::Reset.get_size(), 2);
+    assert_eq!(Command::Move { x: 0, y: 0 }.get_size(), 6);
+}
+
+#[test]
+fn should_round_trip_a_wider_tag() {
+    let value = Command::Move { x: -2, y: 3 };
+    let mut bytes = vec![];
+    bytes.write_be(0, &value).expect("Write should have worked");
+
+    assert_eq!(bytes, [0x02, 0x00, 0xff, 0xfe, 0x00, 0x03]);
+    assert_eq!(bytes.read_be::<Command>(0), Ok(value));
+    assert_eq!(vec![0x00, 0x01].read_le::<Command>(0), Ok(Command::Reset));
+}
+
+#[test]
+fn should_error_on_an_unknown_tag() {
+    let bytes = vec![0x00, 0x00, 0x00, 0x03, 0x00];
+    let mut stream = StreamContainer::new_at(bytes.as_slice(), 2);
+    let error = stream
+        .read_stream_be::<Command>()
+        .expect_err("This should have failed");
+
+    assert_eq!(
+        error,
+        Error::UnknownTag {
+            offset: 2,
+            tag: 0x3,
+        }
+    );
+    assert_eq!(stream.get_index(), 2);
+}
+
+#[test]
+fn should_name_the_variant_of_a_failed_field() {
+    let bytes = vec![0x02, 0x11, 0x22, 0xaa, 0x33];
+    let error = bytes
+        .read_le::<Packet>(0)
+        .expect_err("This should have failed");
+
+    assert_eq!(
+        error,
+        Error::Field {
+            struct_name: "Packet::Data",
+            field: "payload",
+            source: Box::new(Error::Field {
+                struct_name: "Payload",
+                field: "value",
+                source: Box::new(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 4,
+                    data_len: 5,
+                }),
+            }),
+        }
+    );
+}
+
+#[test]
+fn should_name_a_missing_tag() {
+    let bytes = vec![0x01];
+    let error = bytes
+        .read_le::<Command>(0)
+        .expect_err("This should have failed");
+
+    assert_eq!(
+        error,
+        Error::Field {
+            struct_name: "Command",
+            field: "tag",
+            source: Box::new(Error::InvalidSize {
+                wanted_size: 2,
+                offset: 0,
+                data_len: 1,
+            }),
+        }
+    );
+}
+
+#[test]
+fn should_error_if_a_variant_does_not_fit() {
+    let value = Command::Move { x: 1, y: 2 };
+    let mut bytes = [0; 4];
+    let error = bytes
+        .write_le(0, &value)
+        .expect_err("This should have failed");
+
+    assert_eq!(
+        error.root(),
+        &Error::InvalidSize {
+            wanted_size: 2,
+            offset: 4,
+            data_len: 4,
+        }
+    );
+}
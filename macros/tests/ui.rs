@@ -0,0 +1,5 @@
+#[test]
+fn should_report_errors_at_the_offending_item() {
+    let tests = trybuild::TestCases::new();
+    tests.compile_fail("tests/ui/*.rs");
+}
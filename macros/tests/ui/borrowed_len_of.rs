@@ -0,0 +1,11 @@
+use macros::EndianReadBorrowed;
+
+#[derive(EndianReadBorrowed)]
+struct Packet<'a> {
+    #[no_std_io(len_of = "body")]
+    count: u8,
+    #[no_std_io(len_from = "count")]
+    body: &'a [u8],
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use macros::EndianReadBorrowed;
+use no_std_io::ReaderExt;
+
+#[derive(EndianReadBorrowed)]
+struct Record<'a> {
+    #[no_std_io(rest)]
+    body: &'a [u8],
+}
+
+fn main() {
+    let record: Record = {
+        let bytes = vec![0xaa, 0xbb];
+        bytes.read_le_borrowed(0).unwrap()
+    };
+    let _ = record.body;
+}
@@ -0,0 +1,9 @@
+use macros::EndianReadBorrowed;
+
+#[derive(EndianReadBorrowed)]
+struct Packet<'a> {
+    name: &'a str,
+    kind: u8,
+}
+
+fn main() {}
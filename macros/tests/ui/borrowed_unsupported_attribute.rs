@@ -0,0 +1,11 @@
+use macros::EndianReadBorrowed;
+
+#[derive(EndianReadBorrowed)]
+struct Header<'a> {
+    #[no_std_io(magic = 1)]
+    kind: u8,
+    #[no_std_io(rest)]
+    body: &'a [u8],
+}
+
+fn main() {}
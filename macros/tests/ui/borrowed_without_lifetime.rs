@@ -0,0 +1,8 @@
+use macros::EndianReadBorrowed;
+
+#[derive(EndianReadBorrowed)]
+struct Header {
+    kind: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use macros::EndianWrite;
+
+#[derive(EndianWrite)]
+enum Packet {
+    #[no_std_io(tag = 1)]
+    Ping,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use macros::EndianWrite;
+
+#[derive(EndianWrite)]
+struct Header {
+    kind: u8,
+    #[no_std_io(align = 3)]
+    size: u32,
+}
+
+fn main() {}
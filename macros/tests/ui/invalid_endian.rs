@@ -0,0 +1,9 @@
+use macros::EndianRead;
+
+#[derive(EndianRead)]
+#[no_std_io(endian = "middle")]
+struct Header {
+    kind: u16,
+}
+
+fn main() {}
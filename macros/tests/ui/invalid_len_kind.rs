@@ -0,0 +1,11 @@
+use macros::EndianWrite;
+
+#[derive(EndianWrite)]
+struct Table {
+    #[no_std_io(len_of = "entries", len_kind = "words")]
+    count: u8,
+    #[no_std_io(len_from = "count")]
+    entries: Vec<u8>,
+}
+
+fn main() {}
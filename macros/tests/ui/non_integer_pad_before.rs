@@ -0,0 +1,9 @@
+use macros::EndianRead;
+
+#[derive(EndianRead)]
+struct Header {
+    #[no_std_io(pad_before = "two")]
+    kind: u8,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use macros::StaticSize;
+
+#[derive(StaticSize)]
+struct Table {
+    count: u8,
+    #[no_std_io(len_from = "count")]
+    entries: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use macros::StaticSize;
+
+#[derive(StaticSize)]
+enum Kind {
+    First,
+    Second,
+}
+
+fn main() {}
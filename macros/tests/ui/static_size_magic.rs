@@ -0,0 +1,10 @@
+use macros::StaticSize;
+
+#[derive(StaticSize)]
+struct Header {
+    #[no_std_io(magic = 0x4d5a)]
+    magic: u16,
+    size: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use macros::EndianRead;
+
+#[derive(EndianRead)]
+struct Table {
+    #[no_std_io(strict)]
+    count: u8,
+    #[no_std_io(len_from = "count")]
+    entries: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use macros::EndianRead;
+
+#[derive(EndianRead)]
+union Value {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use macros::EndianRead;
+
+#[derive(EndianRead)]
+struct Table {
+    #[no_std_io(len_from = "count")]
+    entries: Vec<u8>,
+    count: u8,
+}
+
+fn main() {}
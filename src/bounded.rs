@@ -0,0 +1,270 @@
+use crate::{Cursor, EndianWrite, Error, Reader, Writer, WriterResult};
+
+/// A [Writer] that refuses to write past a maximum length.
+///
+/// This is useful to keep growable writers, such as vectors, from growing to an unreasonable size
+/// when an offset or length comes from untrusted data.
+/// Writes that would end past the maximum length return an [Error::InvalidSize] with the maximum length as the `data_len`,
+/// and the wrapped writer is left untouched.
+///
+/// [Reader] and [Cursor] are forwarded to the wrapped writer when it implements them.
+pub struct BoundedWriter<W: Writer> {
+    writer: W,
+    max_len: usize,
+}
+
+impl<W: Writer> BoundedWriter<W> {
+    #[inline(always)]
+    pub fn new(writer: W, max_len: usize) -> Self {
+        Self { writer, max_len }
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    /// Returns the maximum length the wrapped writer may be written to.
+    #[inline(always)]
+    pub fn max_len(&self) -> usize {
+        self.max_len
+    }
+
+    #[inline(always)]
+    fn check_bounds(&self, offset: usize, length: usize) -> WriterResult<()> {
+        if offset > self.max_len || length > self.max_len - offset {
+            return Err(Error::InvalidSize {
+                wanted_size: length,
+                offset,
+                data_len: self.max_len,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<W: Writer + Reader> Reader for BoundedWriter<W> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        self.writer.get_slice()
+    }
+}
+
+impl<W: Writer> Writer for BoundedWriter<W> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self.writer.get_mut_slice()
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        self.check_bounds(offset, length)?;
+        self.writer.get_sized_mut_slice(offset, length)
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, offset: usize) -> Option<usize> {
+        let max_len = self.max_len.saturating_sub(offset);
+        let len = self.writer.writable_len(offset).unwrap_or(max_len);
+        Some(core::cmp::min(len, max_len))
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.check_bounds(offset, bytes.len())?;
+        self.writer.write_bytes(offset, bytes)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        self.check_bounds(offset, len)?;
+        self.writer.fill(offset, len, value)
+    }
+
+    #[inline(always)]
+    fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.check_bounds(offset, len)?;
+        self.writer.erase(offset, len)
+    }
+
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        src_offset: usize,
+        dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        self.check_bounds(dst_offset, len)?;
+        self.writer.copy_within(src_offset, dst_offset, len)
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.check_bounds(offset, value.get_size())?;
+        self.writer.write_le(offset, value)
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.check_bounds(offset, value.get_size())?;
+        self.writer.write_be(offset, value)
+    }
+}
+
+impl<W: Writer + Cursor> Cursor for BoundedWriter<W> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.writer.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.writer.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::{StreamContainer, StreamWriter, WriterExt};
+    #[cfg(feature = "alloc")]
+    use alloc::{vec, vec::Vec};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_grow_a_vector_under_the_cap() {
+        let mut writer = BoundedWriter::new(vec![], 8);
+        let written = writer
+            .write_le(4, &0xaabbccddu32)
+            .expect("Write should have succeeded");
+
+        assert_eq!(written, 4);
+        assert_eq!(writer.into_inner(), [0, 0, 0, 0, 0xdd, 0xcc, 0xbb, 0xaa]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_grow_a_vector_past_the_cap() {
+        let mut writer = BoundedWriter::new(vec![1, 2], 8);
+        let error = writer
+            .write_le(6, &0xaabbccddu32)
+            .expect_err("Write should have been too large");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            }
+        );
+        assert_eq!(writer.into_inner(), [1, 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_grow_a_vector_for_large_offsets() {
+        let mut writer = BoundedWriter::new(Vec::new(), 0x100);
+        let error = writer
+            .write_bytes(0x40000000, &[0xaa])
+            .expect_err("Write should have been too large");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 1,
+                offset: 0x40000000,
+                data_len: 0x100,
+            }
+        );
+        assert!(writer.into_inner().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_bound_sized_slices() {
+        let mut writer = BoundedWriter::new(vec![], 4);
+        writer
+            .get_sized_mut_slice(0, 4)
+            .expect("Slice should have been in bounds");
+        let error = writer
+            .get_sized_mut_slice(1, 4)
+            .expect_err("Slice should have been too large");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 1,
+                data_len: 4,
+            }
+        );
+        assert_eq!(writer.into_inner(), [0; 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_bound_array_writes() {
+        let mut writer = BoundedWriter::new(vec![], 4);
+        writer
+            .write_array_be(0, &[0x1122u16, 0x3344, 0x5566])
+            .expect_err("Write should have been too large");
+
+        assert_eq!(writer.into_inner(), [0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_bound_checked_writes() {
+        let mut writer = BoundedWriter::new(vec![], 4);
+        assert_eq!(writer.writable_len(1), Some(3));
+        assert_eq!(writer.checked_write_array_le(2, &[0x1122u16, 0x3344]), 0);
+        assert_eq!(writer.checked_write_array_le(0, &[0x1122u16, 0x3344]), 4);
+        assert_eq!(writer.into_inner(), [0x22, 0x11, 0x44, 0x33]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_truncate_writes_to_the_cap() {
+        let mut writer = BoundedWriter::new(vec![0; 16], 4);
+        let written = writer.write_bytes_truncated(0, &[1; 8]);
+
+        assert_eq!(written, 4);
+        assert_eq!(writer.into_inner()[..8], [1, 1, 1, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn should_return_errors_from_fixed_writers() {
+        let mut writer = BoundedWriter::new([0u8; 4], 8);
+        let error = writer
+            .write_be(2, &0xaabbccddu32)
+            .expect_err("Write should have been too large");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_forward_cursor() {
+        let mut writer = BoundedWriter::new(StreamContainer::new(vec![]), 6);
+        writer
+            .write_stream_be(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.get_index(), 4);
+        writer
+            .write_stream_be(&0xaabbccddu32)
+            .expect_err("Write should have been too large");
+
+        assert_eq!(writer.into_inner().into_raw(), [0xaa, 0xbb, 0xcc, 0xdd]);
+    }
+}
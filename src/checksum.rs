@@ -0,0 +1,95 @@
+const CRC16_CCITT_TABLE: [u16; 256] = create_crc16_ccitt_table();
+const CRC32_TABLE: [u32; 256] = create_crc32_table();
+
+const fn create_crc16_ccitt_table() -> [u16; 256] {
+    let mut table = [0u16; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = (index as u16) << 8;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+const fn create_crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xedb88320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+}
+
+/// CRC-16/CCITT-FALSE: polynomial 0x1021, initial value 0xffff, no reflection, no final xor.
+#[inline(always)]
+pub(crate) fn crc16_ccitt(bytes: impl IntoIterator<Item = u8>) -> u16 {
+    bytes.into_iter().fold(0xffff, |crc, byte| {
+        let index = ((crc >> 8) as u8 ^ byte) as usize;
+        (crc << 8) ^ CRC16_CCITT_TABLE[index]
+    })
+}
+
+/// CRC-32 (ISO-HDLC): reflected polynomial 0xedb88320, initial value and final xor 0xffffffff.
+#[inline(always)]
+pub(crate) fn crc32(bytes: impl IntoIterator<Item = u8>) -> u32 {
+    let crc = bytes.into_iter().fold(0xffffffff, |crc, byte| {
+        let index = (crc as u8 ^ byte) as usize;
+        (crc >> 8) ^ CRC32_TABLE[index]
+    });
+
+    !crc
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_calculate_crc16_ccitt() {
+        assert_eq!(crc16_ccitt(b"123456789".iter().copied()), 0x29b1);
+    }
+
+    #[test]
+    fn should_calculate_crc16_ccitt_for_empty_data() {
+        assert_eq!(crc16_ccitt([]), 0xffff);
+    }
+
+    #[test]
+    fn should_calculate_crc32() {
+        assert_eq!(crc32(b"123456789".iter().copied()), 0xcbf43926);
+    }
+
+    #[test]
+    fn should_calculate_crc32_for_empty_data() {
+        assert_eq!(crc32([]), 0);
+    }
+}
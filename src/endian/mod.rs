@@ -1,5 +1,8 @@
 mod read;
 pub use read::*;
 
+mod read_borrowed;
+pub use read_borrowed::*;
+
 mod write;
 pub use write::*;
@@ -41,6 +41,12 @@ impl<T: Sized> ReadOutput<T> {
 ///
 /// This should only be used when handling an external data source, such as a remote API or file.
 /// Usually you'll want code to be endian agnostic.
+///
+/// Error offsets should be relative to the start of `bytes`.
+/// Readers such as [ReaderExt::read_le](crate::ReaderExt::read_le) and [StreamReader](crate::StreamReader)
+/// rebase them onto the data being read, so errors from nested reads, such as a derived struct
+/// inside another derived struct, report the absolute offset from the start of the outermost data.
+/// Impls that read from a subslice themselves should rebase errors with [ResultExt::context_offset](crate::ResultExt::context_offset).
 pub trait EndianRead: Sized {
     /// Tries to read the value from its little endian representation.
     fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error>;
@@ -94,6 +100,37 @@ macro_rules! impl_endian_read {
 
 impl_endian_read!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64);
 
+/// Types that always take the same number of bytes to read and write, regardless of their value,
+/// which lets sizes be known without a value, such as for buffers sized at compile time.
+///
+/// [LeIter](crate::LeIter) and [BeIter](crate::BeIter) only iterate types that implement this,
+/// and use `SIZE` for [Iterator::size_hint] and [ExactSizeIterator], so every successful read should consume exactly `SIZE` bytes.
+/// If `SIZE` is zero, the iterators never run out of items and report a length of `usize::MAX`.
+/// Every read should also succeed once `SIZE` bytes are available, since the iterators count on it for their length.
+///
+/// Structs can derive this with `#[derive(StaticSize)]` when all of their fields implement it,
+/// and derived [EndianWrite](crate::EndianWrite) types return `SIZE` from `get_size` when they implement it.
+pub trait StaticSize {
+    /// The number of bytes the type takes to read and write.
+    const SIZE: usize;
+}
+
+macro_rules! impl_static_size {
+    ($($i:ty),*) => {
+        $(
+            impl StaticSize for $i {
+                const SIZE: usize = mem::size_of::<$i>();
+            }
+        )*
+    };
+}
+
+impl_static_size!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64, bool);
+
+impl<T: StaticSize, const SIZE: usize> StaticSize for [T; SIZE] {
+    const SIZE: usize = T::SIZE * SIZE;
+}
+
 impl EndianRead for bool {
     #[inline(always)]
     fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
@@ -160,7 +197,7 @@ impl EndianRead for () {
     }
 }
 
-impl<T: EndianRead> EndianRead for PhantomData<T> {
+impl<T> EndianRead for PhantomData<T> {
     #[inline(always)]
     fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
         Ok(ReadOutput::new(PhantomData, 0))
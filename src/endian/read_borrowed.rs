@@ -0,0 +1,121 @@
+use crate::{EndianRead, Error, ReadOutput};
+
+/// Defines a shared interface to read data that borrows from the bytes it was read from,
+/// such as a struct with `&'a [u8]` or `&'a str` fields, so payloads can be parsed without copying them.
+///
+/// Every [EndianRead] type implements this, so borrowed types can contain owned fields.
+/// `&[u8]` and `&str` read all of the bytes they're given, so types that contain them should
+/// pass them a slice of the right length.
+///
+/// The methods are suffixed with `_borrowed` so they don't conflict with [EndianRead] when both traits are in scope.
+/// Like [EndianRead], error offsets should be relative to the start of `bytes`.
+pub trait EndianReadBorrowed<'a>: Sized {
+    /// Tries to read the value from its little endian representation.
+    fn try_read_le_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error>;
+    /// Tries to read the value from its big endian representation.
+    fn try_read_be_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error>;
+}
+
+impl<'a, T: EndianRead> EndianReadBorrowed<'a> for T {
+    #[inline(always)]
+    fn try_read_le_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error> {
+        T::try_read_le(bytes)
+    }
+
+    #[inline(always)]
+    fn try_read_be_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error> {
+        T::try_read_be(bytes)
+    }
+}
+
+impl<'a> EndianReadBorrowed<'a> for &'a [u8] {
+    #[inline(always)]
+    fn try_read_le_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error> {
+        Ok(ReadOutput::new(bytes, bytes.len()))
+    }
+
+    #[inline(always)]
+    fn try_read_be_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error> {
+        Ok(ReadOutput::new(bytes, bytes.len()))
+    }
+}
+
+/// Errors with [Error::InvalidRead] if the bytes aren't valid UTF-8.
+impl<'a> EndianReadBorrowed<'a> for &'a str {
+    #[inline(always)]
+    fn try_read_le_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error> {
+        read_str(bytes)
+    }
+
+    #[inline(always)]
+    fn try_read_be_borrowed(bytes: &'a [u8]) -> Result<ReadOutput<Self>, Error> {
+        read_str(bytes)
+    }
+}
+
+#[inline(always)]
+fn read_str(bytes: &[u8]) -> Result<ReadOutput<&str>, Error> {
+    let string = core::str::from_utf8(bytes).map_err(|_| Error::InvalidRead {
+        message: "Invalid UTF-8 string",
+    })?;
+
+    Ok(ReadOutput::new(string, bytes.len()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    mod slice {
+        use super::*;
+
+        #[test]
+        fn should_borrow_all_of_the_bytes() {
+            let bytes = [0x11, 0x22, 0x33];
+            let result = <&[u8]>::try_read_le_borrowed(&bytes).expect("Read should have worked");
+
+            assert_eq!(result.get_read_bytes(), 3);
+            assert_eq!(result.into_data().as_ptr(), bytes.as_ptr());
+        }
+    }
+
+    mod str {
+        use super::*;
+
+        #[test]
+        fn should_borrow_all_of_the_bytes() {
+            let bytes = b"name";
+            let result = <&str>::try_read_be_borrowed(bytes).expect("Read should have worked");
+
+            assert_eq!(result.get_read_bytes(), 4);
+            let data = result.into_data();
+            assert_eq!(data, "name");
+            assert_eq!(data.as_ptr(), bytes.as_ptr());
+        }
+
+        #[test]
+        fn should_return_error_if_the_bytes_are_not_utf8() {
+            let bytes = [0x61, 0xff];
+            let error = <&str>::try_read_le_borrowed(&bytes).expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid UTF-8 string",
+                }
+            );
+        }
+    }
+
+    mod endian_read {
+        use super::*;
+
+        #[test]
+        fn should_read_endian_read_types() {
+            let bytes = [0x11, 0x22];
+            let result = u16::try_read_be_borrowed(&bytes).expect("Read should have worked");
+
+            assert_eq!(result, ReadOutput::new(0x1122, 2));
+        }
+    }
+}
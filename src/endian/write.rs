@@ -1,4 +1,4 @@
-use crate::Error;
+use crate::{Error, StaticSize};
 use core::{marker::PhantomData, mem};
 
 /// Defines a shared interface to write data to a source that is endian specific.
@@ -22,7 +22,7 @@ macro_rules! impl_endian_write {
             impl EndianWrite for $i {
                 #[inline(always)]
                 fn get_size(&self) -> usize {
-                    mem::size_of::<$i>()
+                    Self::SIZE
                 }
 
                 #[inline(always)]
@@ -68,7 +68,7 @@ impl_endian_write!(u8, i8, u16, i16, u32, i32, u64, i64, usize, isize, f32, f64)
 impl EndianWrite for bool {
     #[inline(always)]
     fn get_size(&self) -> usize {
-        mem::size_of::<Self>()
+        Self::SIZE
     }
 
     #[inline(always)]
@@ -158,7 +158,7 @@ impl EndianWrite for () {
     }
 }
 
-impl<T: EndianWrite> EndianWrite for PhantomData<T> {
+impl<T> EndianWrite for PhantomData<T> {
     #[inline(always)]
     fn get_size(&self) -> usize {
         0
@@ -174,3 +174,35 @@ impl<T: EndianWrite> EndianWrite for PhantomData<T> {
         Ok(0)
     }
 }
+
+/// Used by derived [EndianWrite::get_size] implementations to return [StaticSize::SIZE] when the type implements [StaticSize],
+/// and to add up the size of each field when it doesn't.
+///
+/// Method lookup tries `&&SizeOf<T>` before `&SizeOf<T>`,
+/// so [StaticGetSize] is used whenever `T: StaticSize` holds, and [FieldsGetSize] otherwise.
+#[doc(hidden)]
+pub struct SizeOf<T: ?Sized>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait StaticGetSize {
+    fn get_size(&self, fields_size: impl FnOnce() -> usize) -> usize;
+}
+
+impl<T: StaticSize + ?Sized> StaticGetSize for &SizeOf<T> {
+    #[inline(always)]
+    fn get_size(&self, _fields_size: impl FnOnce() -> usize) -> usize {
+        T::SIZE
+    }
+}
+
+#[doc(hidden)]
+pub trait FieldsGetSize {
+    fn get_size(&self, fields_size: impl FnOnce() -> usize) -> usize;
+}
+
+impl<T: ?Sized> FieldsGetSize for SizeOf<T> {
+    #[inline(always)]
+    fn get_size(&self, fields_size: impl FnOnce() -> usize) -> usize {
+        fields_size()
+    }
+}
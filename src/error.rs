@@ -1,35 +1,342 @@
-use snafu::Snafu;
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::String};
+use core::fmt;
 
-#[derive(Debug, PartialEq, Eq, Snafu)]
+/// The errors returned when reading and writing.
+///
+/// New variants may be added in future releases, so matches outside of this crate
+/// need a wildcard arm, such as `_ => ...`, to handle them.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Error {
-    #[snafu(display(
-        "Invalid size: wanted 0x{:x} at offset offset: 0x{:x}, but data length is 0x{:x} ",
-        wanted_size,
-        offset,
-        data_len
-    ))]
     InvalidSize {
         wanted_size: usize,
         offset: usize,
         data_len: usize,
     },
-    #[snafu(display(
-        "Invalid alignment: wanted size: {}, source size: {}, source offset: {}",
-        wanted_size,
-        source_size,
-        source_offset
-    ))]
     InvalidAlignment {
         wanted_size: usize,
         source_size: usize,
         source_offset: usize,
     },
+    /// A byte of the data did not match the expected bytes, such as a byte string signature.
+    /// Values that are checked as a whole, such as integer magic numbers, return [Error::InvalidData] instead.
+    UnexpectedByte {
+        offset: usize,
+        expected: u8,
+        found: u8,
+    },
+    /// Writing values from an iterator failed partway through.
+    ///
+    /// With the `alloc` feature, `source` is the error the failing value returned.
+    IncompleteIterWrite {
+        offset: usize,
+        items_written: usize,
+        #[cfg(feature = "alloc")]
+        source: Box<Error>,
+    },
+    /// Reading a counted sequence of values failed partway through.
+    IncompleteCountRead { offset: usize, items_read: usize },
+    /// The source of a stream copy didn't have enough data.
+    CopySourceTooShort {
+        wanted_size: usize,
+        offset: usize,
+        data_len: usize,
+    },
+    /// The destination of a stream copy couldn't be written.
+    CopyDestinationWriteFailed { wanted_size: usize, offset: usize },
+    /// The data was read, but its value wasn't valid, such as a bad magic number or a count over its maximum.
+    ///
+    /// The description replaces "Invalid data" at the start of the display message if it's provided.
+    InvalidData {
+        offset: usize,
+        expected: u64,
+        found: u64,
+        description: Option<&'static str>,
+    },
+    /// The tag of a tagged enum didn't match any of its variants.
+    UnknownTag { offset: usize, tag: u64 },
+    /// A stream read started at or past the end of the data, so the input simply ended.
+    ///
+    /// Stream reads return this instead of [Error::InvalidSize] when no data was left to read,
+    /// while [Error::InvalidSize] means there was data, but not enough of it, such as a length that points past the end.
+    EndOfData { offset: usize, requested: usize },
+    /// Moving an offset or index forward would have overflowed.
+    OffsetOverflow { offset: usize, size: usize },
     /// Generic read error message to describe a custom read error by the implementor.
-    #[snafu(display("Invalid read: {}", message))]
     InvalidRead { message: &'static str },
     /// Generic write error message to describe a custom write error by the implementor.
-    #[snafu(display("Invalid write: {}", message))]
     InvalidWrite { message: &'static str },
+    /// Same as [Error::InvalidRead], but the message can include runtime details, such as the offending value.
+    ///
+    /// Use [Error::invalid_read_fmt] to create this from a format string.
+    #[cfg(feature = "alloc")]
+    InvalidReadOwned { message: String },
+    /// Same as [Error::InvalidWrite], but the message can include runtime details, such as the offending value.
+    ///
+    /// Use [Error::invalid_write_fmt] to create this from a format string.
+    #[cfg(feature = "alloc")]
+    InvalidWriteOwned { message: String },
+    /// Reading or writing a field of a struct failed, such as a field of a derived [EndianRead](crate::EndianRead) type.
+    ///
+    /// Padding and alignment errors belong to the field they surround.
+    /// Nested fields are displayed as a path, such as `Header.entries.count`.
+    /// Use [Error::root] to get the error that caused the failure.
+    #[cfg(feature = "alloc")]
+    Field {
+        struct_name: &'static str,
+        field: &'static str,
+        source: Box<Error>,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidSize {
+                wanted_size,
+                offset,
+                data_len,
+            } => write!(
+                f,
+                "Invalid size: wanted 0x{:x} at offset offset: 0x{:x}, but data length is 0x{:x} ",
+                wanted_size, offset, data_len
+            ),
+            Error::InvalidAlignment {
+                wanted_size,
+                source_size,
+                source_offset,
+            } => write!(
+                f,
+                "Invalid alignment: wanted size: {}, source size: {}, source offset: {}",
+                wanted_size, source_size, source_offset
+            ),
+            Error::UnexpectedByte {
+                offset,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Unexpected byte at offset 0x{:x}: expected 0x{:x}, found 0x{:x}",
+                offset, expected, found
+            ),
+            #[cfg(feature = "alloc")]
+            Error::IncompleteIterWrite {
+                offset,
+                items_written,
+                source,
+            } => write!(
+                f,
+                "Iterator write failed at offset 0x{:x} after writing {} items: {}",
+                offset, items_written, source
+            ),
+            #[cfg(not(feature = "alloc"))]
+            Error::IncompleteIterWrite {
+                offset,
+                items_written,
+            } => write!(
+                f,
+                "Iterator write failed at offset 0x{:x} after writing {} items",
+                offset, items_written
+            ),
+            Error::IncompleteCountRead { offset, items_read } => write!(
+                f,
+                "Counted read failed at offset 0x{:x} after reading {} items",
+                offset, items_read
+            ),
+            Error::CopySourceTooShort {
+                wanted_size,
+                offset,
+                data_len,
+            } => write!(
+                f,
+                "Copy source is too short: wanted 0x{:x} bytes at offset 0x{:x}, but data length is 0x{:x}",
+                wanted_size, offset, data_len
+            ),
+            Error::CopyDestinationWriteFailed {
+                wanted_size,
+                offset,
+            } => write!(
+                f,
+                "Copy destination could not write 0x{:x} bytes at offset 0x{:x}",
+                wanted_size, offset
+            ),
+            Error::InvalidData {
+                offset,
+                expected,
+                found,
+                description,
+            } => write!(
+                f,
+                "{} at offset 0x{:x}: expected 0x{:x}, found 0x{:x}",
+                description.unwrap_or("Invalid data"),
+                offset,
+                expected,
+                found
+            ),
+            Error::UnknownTag { offset, tag } => {
+                write!(f, "Unknown tag 0x{:x} at offset 0x{:x}", tag, offset)
+            }
+            Error::EndOfData { offset, requested } => write!(
+                f,
+                "End of data at offset 0x{:x}: wanted 0x{:x} bytes",
+                offset, requested
+            ),
+            Error::OffsetOverflow { offset, size } => write!(
+                f,
+                "Offset 0x{:x} overflowed when adding 0x{:x}",
+                offset, size
+            ),
+            Error::InvalidRead { message } => write!(f, "Invalid read: {}", message),
+            Error::InvalidWrite { message } => write!(f, "Invalid write: {}", message),
+            #[cfg(feature = "alloc")]
+            Error::InvalidReadOwned { message } => write!(f, "Invalid read: {}", message),
+            #[cfg(feature = "alloc")]
+            Error::InvalidWriteOwned { message } => write!(f, "Invalid write: {}", message),
+            #[cfg(feature = "alloc")]
+            Error::Field {
+                struct_name,
+                field,
+                source,
+            } => write!(
+                f,
+                "Invalid field {}",
+                FieldPath {
+                    struct_name,
+                    field,
+                    source
+                }
+            ),
+        }
+    }
+}
+
+/// Field and iterator write errors return the error that caused them from [std::error::Error::source].
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Field { source, .. } | Error::IncompleteIterWrite { source, .. } => {
+                Some(source.as_ref())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Error {
+    /// Adds the struct and field name of a failed read or write to the error.
+    ///
+    /// Without the `alloc` feature, the error is returned unchanged.
+    #[inline(always)]
+    pub fn in_field(self, struct_name: &'static str, field: &'static str) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            Error::Field {
+                struct_name,
+                field,
+                source: Box::new(self),
+            }
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = (struct_name, field);
+            self
+        }
+    }
+
+    /// Returns the error that caused the failure, skipping any [Error::Field] context.
+    #[inline(always)]
+    pub fn root(&self) -> &Error {
+        match self {
+            #[cfg(feature = "alloc")]
+            Error::Field { source, .. } => source.root(),
+            _ => self,
+        }
+    }
+}
+
+/// Displays the path of a field error, such as `Header.entries.count`, followed by the root error.
+#[cfg(feature = "alloc")]
+struct FieldPath<'a> {
+    struct_name: &'a str,
+    field: &'a str,
+    source: &'a Error,
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for FieldPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.struct_name, self.field)?;
+
+        let mut source = self.source;
+        while let Error::Field {
+            field,
+            source: next,
+            ..
+        } = source
+        {
+            write!(f, ".{}", field)?;
+            source = next;
+        }
+
+        write!(f, ": {}", source)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Error {
+    /// Creates an [Error::InvalidReadOwned] from format arguments,
+    /// such as `Error::invalid_read_fmt(format_args!("Unknown kind: {}", kind))`.
+    #[inline(always)]
+    pub fn invalid_read_fmt(args: fmt::Arguments<'_>) -> Self {
+        Error::InvalidReadOwned {
+            message: alloc::fmt::format(args),
+        }
+    }
+
+    /// Creates an [Error::InvalidWriteOwned] from format arguments,
+    /// such as `Error::invalid_write_fmt(format_args!("Value {} is too large", value))`.
+    #[inline(always)]
+    pub fn invalid_write_fmt(args: fmt::Arguments<'_>) -> Self {
+        Error::InvalidWriteOwned {
+            message: alloc::fmt::format(args),
+        }
+    }
+}
+
+/// Wraps the error a value returned when writing an iterator failed partway through.
+#[inline(always)]
+pub(crate) fn incomplete_iter_write(source: Error, offset: usize, items_written: usize) -> Error {
+    #[cfg(not(feature = "alloc"))]
+    let _ = source;
+
+    Error::IncompleteIterWrite {
+        offset,
+        items_written,
+        #[cfg(feature = "alloc")]
+        source: Box::new(source),
+    }
+}
+
+/// Returns the end of `size` bytes starting at `offset`,
+/// or an [Error::OffsetOverflow] if the end would overflow.
+#[inline(always)]
+pub(crate) fn checked_offset_end(offset: usize, size: usize) -> Result<usize, Error> {
+    offset
+        .checked_add(size)
+        .ok_or(Error::OffsetOverflow { offset, size })
+}
+
+/// Returns the size of `count` values of `size` bytes each that start at `offset`,
+/// or an [Error::OffsetOverflow] if the size would overflow.
+#[inline(always)]
+pub(crate) fn checked_total_size(offset: usize, size: usize, count: usize) -> Result<usize, Error> {
+    size.checked_mul(count).ok_or(Error::OffsetOverflow {
+        offset,
+        size: usize::MAX,
+    })
 }
 
 #[inline(always)]
@@ -38,7 +345,70 @@ pub(crate) fn add_error_context<T>(
     offset: usize,
     data_len: usize,
 ) -> Result<T, Error> {
-    error.map_err(|error| match error {
+    error.context_offset(offset, data_len)
+}
+
+impl Error {
+    /// Rebases the offset of an error from a nested read or write onto the data it was read from or written to.
+    ///
+    /// Errors from [EndianRead](crate::EndianRead) and [EndianWrite](crate::EndianWrite) impls have offsets
+    /// relative to the slice they were given. Custom containers that pass a subslice starting at `base_offset`
+    /// should call this so the error points at the same place in the full data, the same way the built-in
+    /// slice and Vec impls do. `data_len` replaces the data length of [Error::InvalidSize] errors.
+    ///
+    /// Errors without an offset are returned unchanged.
+    #[inline(always)]
+    pub fn with_offset(self, base_offset: usize, data_len: usize) -> Self {
+        add_offset_context(self, base_offset, data_len)
+    }
+}
+
+/// Extension methods for results that return an [Error].
+pub trait ResultExt {
+    /// Rebases the offset of an error with [Error::with_offset], leaving successful results unchanged.
+    ///
+    /// ```
+    /// use no_std_io::{EndianRead, Error, ReadOutput, ResultExt};
+    ///
+    /// struct Header {
+    ///     size: u32,
+    /// }
+    ///
+    /// impl EndianRead for Header {
+    ///     fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+    ///         let body = bytes.get(4..).unwrap_or_default();
+    ///         let size = u32::try_read_le(body).context_offset(4, bytes.len())?;
+    ///         Ok(ReadOutput::new(Header { size: size.into_data() }, 8))
+    ///     }
+    ///
+    ///     fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+    ///         unimplemented!()
+    ///     }
+    /// }
+    ///
+    /// let error = Header::try_read_le(&[0; 6]).err();
+    /// assert_eq!(
+    ///     error,
+    ///     Some(Error::InvalidSize {
+    ///         wanted_size: 4,
+    ///         offset: 4,
+    ///         data_len: 6,
+    ///     })
+    /// );
+    /// ```
+    fn context_offset(self, offset: usize, data_len: usize) -> Self;
+}
+
+impl<T> ResultExt for Result<T, Error> {
+    #[inline(always)]
+    fn context_offset(self, offset: usize, data_len: usize) -> Self {
+        self.map_err(|error| error.with_offset(offset, data_len))
+    }
+}
+
+#[inline(always)]
+fn add_offset_context(error: Error, offset: usize, data_len: usize) -> Error {
+    match error {
         Error::InvalidSize {
             wanted_size,
             offset: error_offset,
@@ -48,6 +418,814 @@ pub(crate) fn add_error_context<T>(
             offset: offset + error_offset,
             data_len,
         },
+        Error::UnexpectedByte {
+            offset: error_offset,
+            expected,
+            found,
+        } => Error::UnexpectedByte {
+            offset: offset + error_offset,
+            expected,
+            found,
+        },
+        Error::InvalidData {
+            offset: error_offset,
+            expected,
+            found,
+            description,
+        } => Error::InvalidData {
+            offset: offset + error_offset,
+            expected,
+            found,
+            description,
+        },
+        Error::UnknownTag {
+            offset: error_offset,
+            tag,
+        } => Error::UnknownTag {
+            offset: offset + error_offset,
+            tag,
+        },
+        Error::EndOfData {
+            offset: error_offset,
+            requested,
+        } => Error::EndOfData {
+            offset: offset + error_offset,
+            requested,
+        },
+        #[cfg(feature = "alloc")]
+        Error::IncompleteIterWrite {
+            offset: error_offset,
+            items_written,
+            source,
+        } => Error::IncompleteIterWrite {
+            offset: offset + error_offset,
+            items_written,
+            source: Box::new(add_offset_context(*source, offset, data_len)),
+        },
+        #[cfg(not(feature = "alloc"))]
+        Error::IncompleteIterWrite {
+            offset: error_offset,
+            items_written,
+        } => Error::IncompleteIterWrite {
+            offset: offset + error_offset,
+            items_written,
+        },
+        Error::IncompleteCountRead {
+            offset: error_offset,
+            items_read,
+        } => Error::IncompleteCountRead {
+            offset: offset + error_offset,
+            items_read,
+        },
+        Error::OffsetOverflow {
+            offset: error_offset,
+            size,
+        } => Error::OffsetOverflow {
+            offset: offset.saturating_add(error_offset),
+            size,
+        },
+        #[cfg(feature = "alloc")]
+        Error::Field {
+            struct_name,
+            field,
+            source,
+        } => Error::Field {
+            struct_name,
+            field,
+            source: Box::new(add_offset_context(*source, offset, data_len)),
+        },
         _ => error,
-    })
+    }
+}
+
+/// Returns the closest [std::io::ErrorKind] for an error.
+#[cfg(feature = "std")]
+fn io_error_kind(error: &Error) -> std::io::ErrorKind {
+    use std::io::ErrorKind;
+
+    match error {
+        Error::InvalidSize { .. } | Error::EndOfData { .. } | Error::CopySourceTooShort { .. } => {
+            ErrorKind::UnexpectedEof
+        }
+        Error::InvalidAlignment { .. }
+        | Error::UnexpectedByte { .. }
+        | Error::InvalidData { .. }
+        | Error::UnknownTag { .. }
+        | Error::IncompleteCountRead { .. } => ErrorKind::InvalidData,
+        Error::IncompleteIterWrite { .. } | Error::CopyDestinationWriteFailed { .. } => {
+            ErrorKind::WriteZero
+        }
+        Error::OffsetOverflow { .. } => ErrorKind::InvalidInput,
+        Error::InvalidRead { .. }
+        | Error::InvalidWrite { .. }
+        | Error::InvalidReadOwned { .. }
+        | Error::InvalidWriteOwned { .. } => ErrorKind::Other,
+        Error::Field { source, .. } => io_error_kind(source),
+    }
+}
+
+/// Converts to an [std::io::Error] with the closest [std::io::ErrorKind],
+/// keeping the original error as the inner error.
+///
+/// Running out of data maps to [std::io::ErrorKind::UnexpectedEof], data that doesn't match what was expected
+/// maps to [std::io::ErrorKind::InvalidData], and custom read and write errors map to [std::io::ErrorKind::Other].
+/// Field errors use the kind of the error that caused them.
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    fn from(error: Error) -> Self {
+        std::io::Error::new(io_error_kind(&error), error)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "alloc")]
+    mod display {
+        use super::*;
+        use alloc::string::ToString;
+
+        #[test]
+        fn should_display_invalid_size() {
+            let error = Error::InvalidSize {
+                wanted_size: 4,
+                offset: 0x10,
+                data_len: 0x12,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Invalid size: wanted 0x4 at offset offset: 0x10, but data length is 0x12 "
+            );
+        }
+
+        #[test]
+        fn should_display_invalid_alignment() {
+            let error = Error::InvalidAlignment {
+                wanted_size: 4,
+                source_size: 10,
+                source_offset: 3,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Invalid alignment: wanted size: 4, source size: 10, source offset: 3"
+            );
+        }
+
+        #[test]
+        fn should_display_unexpected_byte() {
+            let error = Error::UnexpectedByte {
+                offset: 0x20,
+                expected: 0xaa,
+                found: 0xbb,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Unexpected byte at offset 0x20: expected 0xaa, found 0xbb"
+            );
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        #[test]
+        fn should_display_incomplete_iter_write() {
+            let error = Error::IncompleteIterWrite {
+                offset: 0x8,
+                items_written: 2,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Iterator write failed at offset 0x8 after writing 2 items"
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_display_incomplete_iter_write_with_its_source() {
+            let error = Error::IncompleteIterWrite {
+                offset: 0x8,
+                items_written: 2,
+                source: Box::new(Error::InvalidWrite {
+                    message: "Out of space",
+                }),
+            };
+            assert_eq!(
+                error.to_string(),
+                "Iterator write failed at offset 0x8 after writing 2 items: Invalid write: Out of space"
+            );
+        }
+
+        #[test]
+        fn should_display_incomplete_count_read() {
+            let error = Error::IncompleteCountRead {
+                offset: 0xc,
+                items_read: 3,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Counted read failed at offset 0xc after reading 3 items"
+            );
+        }
+
+        #[test]
+        fn should_display_copy_source_too_short() {
+            let error = Error::CopySourceTooShort {
+                wanted_size: 0x10,
+                offset: 2,
+                data_len: 8,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Copy source is too short: wanted 0x10 bytes at offset 0x2, but data length is 0x8"
+            );
+        }
+
+        #[test]
+        fn should_display_copy_destination_write_failed() {
+            let error = Error::CopyDestinationWriteFailed {
+                wanted_size: 0x10,
+                offset: 4,
+            };
+            assert_eq!(
+                error.to_string(),
+                "Copy destination could not write 0x10 bytes at offset 0x4"
+            );
+        }
+
+        #[test]
+        fn should_display_end_of_data() {
+            let error = Error::EndOfData {
+                offset: 0x40,
+                requested: 2,
+            };
+            assert_eq!(
+                error.to_string(),
+                "End of data at offset 0x40: wanted 0x2 bytes"
+            );
+        }
+
+        #[test]
+        fn should_display_unknown_tag() {
+            let error = Error::UnknownTag {
+                offset: 0x10,
+                tag: 0xff,
+            };
+            assert_eq!(error.to_string(), "Unknown tag 0xff at offset 0x10");
+        }
+
+        #[test]
+        fn should_display_offset_overflow() {
+            let error = Error::OffsetOverflow {
+                offset: usize::MAX,
+                size: 1,
+            };
+            assert_eq!(
+                error.to_string(),
+                alloc::format!("Offset 0x{:x} overflowed when adding 0x1", usize::MAX)
+            );
+        }
+
+        #[test]
+        fn should_display_invalid_read() {
+            let error = Error::InvalidRead {
+                message: "Bad read",
+            };
+            assert_eq!(error.to_string(), "Invalid read: Bad read");
+        }
+
+        #[test]
+        fn should_display_invalid_write() {
+            let error = Error::InvalidWrite {
+                message: "Bad write",
+            };
+            assert_eq!(error.to_string(), "Invalid write: Bad write");
+        }
+
+        #[test]
+        fn should_display_invalid_read_owned() {
+            let error = Error::InvalidReadOwned {
+                message: "Bad read".to_string(),
+            };
+            assert_eq!(error.to_string(), "Invalid read: Bad read");
+        }
+
+        #[test]
+        fn should_display_invalid_write_owned() {
+            let error = Error::InvalidWriteOwned {
+                message: "Bad write".to_string(),
+            };
+            assert_eq!(error.to_string(), "Invalid write: Bad write");
+        }
+
+        #[test]
+        fn should_display_field() {
+            let error = Error::InvalidRead {
+                message: "Bad read",
+            }
+            .in_field("Entries", "count")
+            .in_field("Header", "entries");
+            assert_eq!(
+                error.to_string(),
+                "Invalid field Header.entries.count: Invalid read: Bad read"
+            );
+        }
+    }
+
+    mod with_offset {
+        use super::*;
+
+        #[test]
+        fn should_rebase_invalid_size_offsets() {
+            let error = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 1,
+                data_len: 0,
+            };
+
+            assert_eq!(
+                error.with_offset(2, 6),
+                Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 3,
+                    data_len: 6,
+                }
+            );
+        }
+
+        #[test]
+        fn should_pass_through_errors_without_offsets() {
+            let error = Error::InvalidRead {
+                message: "Custom error!",
+            };
+
+            assert_eq!(
+                error.with_offset(2, 6),
+                Error::InvalidRead {
+                    message: "Custom error!",
+                }
+            );
+        }
+    }
+
+    mod unknown_tag {
+        use super::*;
+
+        #[test]
+        fn should_add_offset_context() {
+            let result: Result<(), Error> = Err(Error::UnknownTag { offset: 1, tag: 9 });
+
+            assert_eq!(
+                add_error_context(result, 4, 8),
+                Err(Error::UnknownTag { offset: 5, tag: 9 })
+            );
+        }
+    }
+
+    mod context_offset {
+        use super::*;
+        use crate::{EndianRead, ReadOutput, ReaderExt};
+
+        #[derive(Debug)]
+        struct OffsetErrorTest;
+
+        impl EndianRead for OffsetErrorTest {
+            fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 1,
+                    data_len: 0,
+                })
+            }
+
+            fn try_read_be(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[derive(Debug)]
+        struct NestedOffsetErrorTest;
+
+        impl EndianRead for NestedOffsetErrorTest {
+            fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                let inner = bytes.get(2..).unwrap_or_default();
+                OffsetErrorTest::try_read_le(inner).context_offset(2, bytes.len())?;
+                Ok(ReadOutput::new(NestedOffsetErrorTest, 2))
+            }
+
+            fn try_read_be(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[test]
+        fn should_leave_ok_results_unchanged() {
+            let result: Result<u8, Error> = Ok(1);
+            assert_eq!(result.context_offset(2, 6), Ok(1));
+        }
+
+        #[test]
+        fn should_bubble_up_custom_errors() {
+            let result: Result<u8, Error> = Err(Error::InvalidRead {
+                message: "Custom error!",
+            });
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result.context_offset(2, 6), Err(expected));
+        }
+
+        #[test]
+        fn should_bubble_up_error_offsets() {
+            let result = NestedOffsetErrorTest::try_read_le(&[0; 4]).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 4,
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_error_offsets_through_a_reader() {
+            let bytes: &[u8] = &[0; 8];
+            let result = bytes.read_le::<NestedOffsetErrorTest>(2).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 5,
+                data_len: 8,
+            };
+            assert_eq!(result, expected)
+        }
+    }
+
+    mod invalid_data {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::string::ToString;
+
+        #[test]
+        fn should_match_the_found_value() {
+            let error = Error::InvalidData {
+                offset: 2,
+                expected: 0x1234,
+                found: 0x5678,
+                description: None,
+            };
+
+            match error {
+                Error::InvalidData { found, .. } => assert_eq!(found, 0x5678),
+                _ => panic!("Error should have been invalid data"),
+            }
+        }
+
+        #[test]
+        fn should_add_offset_context() {
+            let result: Result<(), Error> = Err(Error::InvalidData {
+                offset: 2,
+                expected: 1,
+                found: 0,
+                description: Some("Bad flag"),
+            });
+
+            assert_eq!(
+                add_error_context(result, 4, 8),
+                Err(Error::InvalidData {
+                    offset: 6,
+                    expected: 1,
+                    found: 0,
+                    description: Some("Bad flag"),
+                })
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_display_without_a_description() {
+            let error = Error::InvalidData {
+                offset: 0x10,
+                expected: 0x1234,
+                found: 0x5678,
+                description: None,
+            };
+
+            assert_eq!(
+                error.to_string(),
+                "Invalid data at offset 0x10: expected 0x1234, found 0x5678"
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_display_with_a_description() {
+            let error = Error::InvalidData {
+                offset: 0,
+                expected: 0x46464952,
+                found: 0,
+                description: Some("Bad magic"),
+            };
+
+            assert_eq!(
+                error.to_string(),
+                "Bad magic at offset 0x0: expected 0x46464952, found 0x0"
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod owned_messages {
+        use super::*;
+        use alloc::string::ToString;
+
+        #[test]
+        fn should_format_a_read_message_with_a_runtime_value() {
+            let kind = 7;
+            let error = Error::invalid_read_fmt(format_args!("Unknown kind: {}", kind));
+
+            assert_eq!(
+                error,
+                Error::InvalidReadOwned {
+                    message: "Unknown kind: 7".to_string(),
+                }
+            );
+            assert_eq!(error.to_string(), "Invalid read: Unknown kind: 7");
+        }
+
+        #[test]
+        fn should_format_a_write_message_with_a_runtime_value() {
+            let value = 300;
+            let error = Error::invalid_write_fmt(format_args!("Value {} is too large", value));
+
+            assert_eq!(
+                error,
+                Error::InvalidWriteOwned {
+                    message: "Value 300 is too large".to_string(),
+                }
+            );
+            assert_eq!(error.to_string(), "Invalid write: Value 300 is too large");
+        }
+
+        #[test]
+        fn should_display_the_same_as_static_messages() {
+            let owned = Error::invalid_read_fmt(format_args!("Bad read"));
+            let borrowed = Error::InvalidRead {
+                message: "Bad read",
+            };
+
+            assert_eq!(owned.to_string(), borrowed.to_string());
+        }
+
+        #[test]
+        fn should_keep_the_message_through_error_context() {
+            let field = "header";
+            let result: Result<(), Error> = Err(Error::invalid_read_fmt(format_args!(
+                "Bad field: {}",
+                field
+            )));
+
+            assert_eq!(
+                add_error_context(result, 4, 8),
+                Err(Error::InvalidReadOwned {
+                    message: "Bad field: header".to_string(),
+                })
+            );
+        }
+    }
+
+    mod in_field {
+        use super::*;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_add_the_field_names() {
+            let error = Error::InvalidRead {
+                message: "Bad read",
+            }
+            .in_field("Header", "magic");
+
+            assert_eq!(
+                error,
+                Error::Field {
+                    struct_name: "Header",
+                    field: "magic",
+                    source: Box::new(Error::InvalidRead {
+                        message: "Bad read",
+                    }),
+                }
+            );
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        #[test]
+        fn should_return_the_error_unchanged_without_alloc() {
+            let error = Error::InvalidRead {
+                message: "Bad read",
+            }
+            .in_field("Header", "magic");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Bad read",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_the_root_error() {
+            let error = Error::InvalidRead {
+                message: "Bad read",
+            }
+            .in_field("Entries", "count")
+            .in_field("Header", "entries");
+
+            assert_eq!(
+                error.root(),
+                &Error::InvalidRead {
+                    message: "Bad read",
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_add_offset_context_to_the_root_error() {
+            let result: Result<(), Error> = Err(Error::InvalidSize {
+                wanted_size: 4,
+                offset: 1,
+                data_len: 2,
+            }
+            .in_field("Header", "count"));
+
+            assert_eq!(
+                add_error_context(result, 4, 8),
+                Err(Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 5,
+                    data_len: 8,
+                }
+                .in_field("Header", "count"))
+            );
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod std_error {
+        use super::*;
+        use std::error::Error as _;
+
+        #[test]
+        fn should_return_the_source_of_a_field_error() {
+            let error = Error::InvalidRead { message: "Inner" }.in_field("Header", "magic");
+            let source = error.source().expect("Field errors should have a source");
+
+            assert_eq!(
+                source.downcast_ref::<Error>(),
+                Some(&Error::InvalidRead { message: "Inner" })
+            );
+        }
+
+        #[test]
+        fn should_return_the_source_of_an_incomplete_iter_write() {
+            let error = Error::IncompleteIterWrite {
+                offset: 4,
+                items_written: 1,
+                source: Box::new(Error::InvalidWrite { message: "Inner" }),
+            };
+            let source = error
+                .source()
+                .expect("Incomplete iterator writes should have a source");
+
+            assert_eq!(
+                source.downcast_ref::<Error>(),
+                Some(&Error::InvalidWrite { message: "Inner" })
+            );
+        }
+
+        #[test]
+        fn should_not_have_a_source_for_other_errors() {
+            let error = Error::EndOfData {
+                offset: 0,
+                requested: 4,
+            };
+
+            assert!(error.source().is_none());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    mod io_error {
+        use super::*;
+        use std::{io, string::ToString};
+
+        #[test]
+        fn should_map_invalid_size_to_unexpected_eof() {
+            let error: io::Error = Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+            .into();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        }
+
+        #[test]
+        fn should_map_invalid_alignment_to_invalid_data() {
+            let error: io::Error = Error::InvalidAlignment {
+                wanted_size: 4,
+                source_size: 3,
+                source_offset: 1,
+            }
+            .into();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+
+        #[test]
+        fn should_map_unexpected_byte_to_invalid_data() {
+            let error: io::Error = Error::UnexpectedByte {
+                offset: 0,
+                expected: 0xaa,
+                found: 0xbb,
+            }
+            .into();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+
+        #[test]
+        fn should_map_invalid_data_to_invalid_data() {
+            let error: io::Error = Error::InvalidData {
+                offset: 0,
+                expected: 1,
+                found: 2,
+                description: None,
+            }
+            .into();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+
+        #[test]
+        fn should_map_unknown_tags_to_invalid_data() {
+            let error: io::Error = Error::UnknownTag { offset: 0, tag: 7 }.into();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+
+        #[test]
+        fn should_map_field_errors_to_the_root_kind() {
+            let error: io::Error = Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+            .in_field("Header", "count")
+            .into();
+            assert_eq!(error.kind(), io::ErrorKind::UnexpectedEof);
+        }
+
+        #[test]
+        fn should_map_custom_errors_to_other() {
+            let read_error: io::Error = Error::InvalidRead {
+                message: "Bad read",
+            }
+            .into();
+            let write_error: io::Error = Error::InvalidWrite {
+                message: "Bad write",
+            }
+            .into();
+
+            assert_eq!(read_error.kind(), io::ErrorKind::Other);
+            assert_eq!(write_error.kind(), io::ErrorKind::Other);
+        }
+
+        #[test]
+        fn should_keep_the_display_text() {
+            let error = Error::InvalidRead {
+                message: "Bad read",
+            };
+            let expected = error.to_string();
+            let io_error: io::Error = error.into();
+
+            assert_eq!(io_error.to_string(), expected);
+            assert_eq!(io_error.to_string(), "Invalid read: Bad read");
+        }
+
+        #[test]
+        fn should_keep_the_original_error() {
+            let io_error: io::Error = Error::OffsetOverflow {
+                offset: usize::MAX,
+                size: 1,
+            }
+            .into();
+            let inner = io_error
+                .into_inner()
+                .expect("Error should have had an inner error")
+                .downcast::<Error>()
+                .expect("Inner error should have been an Error");
+
+            assert_eq!(
+                *inner,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 1,
+                }
+            );
+        }
+    }
 }
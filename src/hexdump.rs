@@ -0,0 +1,97 @@
+use core::fmt;
+
+const BYTES_PER_LINE: usize = 16;
+
+/// Formats bytes in the classic hexdump layout with an offset, hex, and ASCII column per 16 bytes.
+///
+/// This is intended for debugging, and is returned by [Reader::hexdump](crate::Reader::hexdump).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HexDump<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> HexDump<'a> {
+    /// Creates a hexdump of `bytes`, labeling the first byte with `offset`.
+    #[inline(always)]
+    pub fn new(bytes: &'a [u8], offset: usize) -> Self {
+        Self { bytes, offset }
+    }
+}
+
+impl fmt::Display for HexDump<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line_index, line) in self.bytes.chunks(BYTES_PER_LINE).enumerate() {
+            write!(f, "{:08x}  ", self.offset + line_index * BYTES_PER_LINE)?;
+
+            for index in 0..BYTES_PER_LINE {
+                match line.get(index) {
+                    Some(byte) => write!(f, "{:02x} ", byte)?,
+                    None => write!(f, "   ")?,
+                }
+
+                if index == BYTES_PER_LINE / 2 - 1 {
+                    write!(f, " ")?;
+                }
+            }
+
+            write!(f, " |")?;
+
+            for byte in line {
+                let character = if byte.is_ascii_graphic() || *byte == b' ' {
+                    *byte as char
+                } else {
+                    '.'
+                };
+                write!(f, "{}", character)?;
+            }
+
+            writeln!(f, "|")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn should_format_a_full_line() {
+        let bytes = *b"RIFF\x00\x01\x02\x03ABCDEFGH";
+        let result = HexDump::new(&bytes, 0).to_string();
+        assert_eq!(
+            result,
+            "00000000  52 49 46 46 00 01 02 03  41 42 43 44 45 46 47 48  |RIFF....ABCDEFGH|\n"
+        );
+    }
+
+    #[test]
+    fn should_format_a_partial_line() {
+        let bytes = [0x7f, 0x20, 0x7e, 0xff, 0x0a];
+        let result = HexDump::new(&bytes, 0x10).to_string();
+        assert_eq!(
+            result,
+            "00000010  7f 20 7e ff 0a                                    |. ~..|\n"
+        );
+    }
+
+    #[test]
+    fn should_format_multiple_lines() {
+        let bytes: [u8; 18] = core::array::from_fn(|index| index as u8 + 0x30);
+        let result = HexDump::new(&bytes, 0x20).to_string();
+        assert_eq!(
+            result,
+            "00000020  30 31 32 33 34 35 36 37  38 39 3a 3b 3c 3d 3e 3f  |0123456789:;<=>?|\n\
+             00000030  40 41                                             |@A|\n"
+        );
+    }
+
+    #[test]
+    fn should_format_nothing_if_empty() {
+        let result = HexDump::new(&[], 0).to_string();
+        assert_eq!(result, "");
+    }
+}
@@ -3,6 +3,13 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+extern crate std;
+
+// Lets the derives, which refer to `::no_std_io`, be used in this crate's tests.
+#[cfg(test)]
+extern crate self as no_std_io;
+
 mod reader;
 pub use reader::*;
 
@@ -15,7 +22,35 @@ pub use error::*;
 mod endian;
 pub use endian::*;
 
+mod checksum;
+
+mod hexdump;
+pub use hexdump::*;
+
 mod stream;
 pub use stream::*;
 
+mod volatile;
+pub use volatile::*;
+
+mod bounded;
+pub use bounded::*;
+
+mod sink;
+pub use sink::*;
+
+mod size_calc;
+pub use size_calc::*;
+
+mod transform;
+pub use transform::*;
+
+mod tee;
+pub use tee::*;
+
+#[cfg(feature = "alloc")]
+mod padded_vec;
+#[cfg(feature = "alloc")]
+pub use padded_vec::*;
+
 pub use macros::*;
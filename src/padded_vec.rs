@@ -0,0 +1,186 @@
+use crate::{
+    add_error_context, checked_offset_end, EndianWrite, Error, GrowableWriter, Reader, Writer,
+    WriterResult,
+};
+use alloc::vec::Vec;
+
+/// A growable [Reader] and [Writer] that fills gaps with a configurable byte instead of zeroes.
+///
+/// Like a vector, the data grows when a write lands past the end.
+/// The bytes between the old end and the write are set to the gap fill byte,
+/// which is useful for formats such as flash images that expect erased space to be `0xff`.
+pub struct PaddedVec {
+    bytes: Vec<u8>,
+    gap_fill: u8,
+}
+
+impl PaddedVec {
+    #[inline(always)]
+    pub fn new(bytes: Vec<u8>, gap_fill: u8) -> Self {
+        Self { bytes, gap_fill }
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the byte used to fill gaps when the data grows.
+    #[inline(always)]
+    pub fn gap_fill(&self) -> u8 {
+        self.gap_fill
+    }
+
+    /// Sets the byte used to fill gaps when the data grows.
+    /// Gaps that were already filled are not changed.
+    #[inline(always)]
+    pub fn set_gap_fill(&mut self, gap_fill: u8) {
+        self.gap_fill = gap_fill;
+    }
+
+    #[inline(always)]
+    fn grow_to(&mut self, len: usize) {
+        if len > self.bytes.len() {
+            self.bytes.resize(len, self.gap_fill);
+        }
+    }
+}
+
+impl From<PaddedVec> for Vec<u8> {
+    #[inline(always)]
+    fn from(padded: PaddedVec) -> Self {
+        padded.into_inner()
+    }
+}
+
+impl Reader for PaddedVec {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        &self.bytes
+    }
+}
+
+impl Writer for PaddedVec {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.bytes
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        let offset_end = checked_offset_end(offset, length)?;
+        self.grow_to(offset_end);
+        Ok(&mut self.bytes[offset..offset_end])
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, _offset: usize) -> Option<usize> {
+        None
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let slice = self.get_sized_mut_slice(offset, value.get_size())?;
+        let slice_len = slice.len();
+        add_error_context(value.try_write_le(slice), offset, offset + slice_len)
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let slice = self.get_sized_mut_slice(offset, value.get_size())?;
+        let slice_len = slice.len();
+        add_error_context(value.try_write_be(slice), offset, offset + slice_len)
+    }
+}
+
+/// If the offset of an insert is past the end of the data, the gap is filled with the gap fill byte.
+impl GrowableWriter for PaddedVec {
+    #[inline(always)]
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.grow_to(offset);
+        self.bytes.splice(offset..offset, bytes.iter().copied());
+        Ok(bytes.len())
+    }
+
+    #[inline(always)]
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        let data_len = self.bytes.len();
+        let offset_end = checked_offset_end(offset, len)?;
+
+        if offset_end > data_len {
+            return Err(Error::InvalidSize {
+                wanted_size: len,
+                offset,
+                data_len,
+            });
+        }
+
+        self.bytes.drain(offset..offset_end);
+        Ok(len)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Cursor, StreamContainer, StreamWriter};
+    use alloc::vec;
+
+    #[test]
+    fn should_fill_gaps_with_the_gap_fill_byte() {
+        let mut writer = PaddedVec::new(vec![1, 2], 0xff);
+        let written = writer
+            .write_le(6, &0xaabbu16)
+            .expect("Write should have succeeded");
+
+        assert_eq!(written, 2);
+        assert_eq!(
+            writer.into_inner(),
+            [1, 2, 0xff, 0xff, 0xff, 0xff, 0xbb, 0xaa]
+        );
+    }
+
+    #[test]
+    fn should_fill_gaps_left_by_byte_writes() {
+        let mut writer = PaddedVec::new(vec![], 0x20);
+        writer
+            .write_bytes(3, b"hi")
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.into_inner(), b"   hi");
+    }
+
+    #[test]
+    fn should_fill_gaps_left_by_stream_writes() {
+        let mut writer = StreamContainer::new(PaddedVec::new(vec![], 0xff));
+        writer.set_index(2);
+        writer
+            .write_array_stream_be(&[0x1122u16, 0x3344])
+            .expect("Write should have succeeded");
+
+        let bytes: Vec<u8> = writer.into_raw().into();
+        assert_eq!(bytes, [0xff, 0xff, 0x11, 0x22, 0x33, 0x44]);
+    }
+
+    #[test]
+    fn should_use_the_new_gap_fill_byte() {
+        let mut writer = PaddedVec::new(vec![], 0xff);
+        writer.set_gap_fill(0);
+        writer
+            .write_bytes(2, &[1])
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.gap_fill(), 0);
+        assert_eq!(writer.into_inner(), [0, 0, 1]);
+    }
+
+    #[test]
+    fn should_fill_gaps_left_by_inserts() {
+        let mut writer = PaddedVec::new(vec![1], 0xff);
+        writer
+            .insert_bytes(3, &[2])
+            .expect("Insert should have succeeded");
+
+        assert_eq!(writer.into_inner(), [1, 0xff, 0xff, 2]);
+    }
+}
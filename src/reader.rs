@@ -1,13 +1,20 @@
 #[cfg(feature = "alloc")]
-use alloc::{vec, vec::Vec};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
 
-use super::{add_error_context, EndianRead, Error, ReadOutput};
+use super::{
+    add_error_context, checked_offset_end, checksum, EndianRead, EndianReadBorrowed, Error,
+    HexDump, ReadOutput,
+};
 use core::mem;
 use safe_transmute::{transmute_many_permissive, TriviallyTransmutable};
 
 pub type ReaderResult<T> = Result<T, Error>;
 
 /// An interface to safely read values from a source.
+///
+/// The trait is object safe, so it can be used as a trait object such as `&dyn Reader`.
+/// Methods that readers may override with generic parameters, such as [Reader::read_le_with_output], require `Self: Sized`,
+/// and the other generic methods are in [ReaderExt].
 pub trait Reader {
     /// Returns the data to be read from.
     fn get_slice(&self) -> &[u8];
@@ -29,10 +36,11 @@ pub trait Reader {
     ///
     /// An error should be returned if the size is invalid (e.g. `offset + size` exceeds the available data)
     /// or if the alignment is incorrect.
+    /// An [Error::OffsetOverflow] is returned if `offset + size` overflows.
     #[inline(always)]
     fn get_slice_of_size(&self, offset: usize, size: usize) -> ReaderResult<&[u8]> {
         let data = self.get_slice();
-        let offset_end = offset + size;
+        let offset_end = checked_offset_end(offset, size)?;
 
         if data.len() < offset_end {
             return Err(Error::InvalidSize {
@@ -45,12 +53,220 @@ pub trait Reader {
         Ok(&data[offset..offset_end])
     }
 
+    /// Checks that the bytes at an offset match `expected`, such as a magic number or signature.
+    ///
+    /// An error is returned if there isn't enough data, or if the bytes differ.
+    /// The error for differing bytes contains the offset of the first byte that didn't match.
+    #[inline(always)]
+    fn expect_bytes(&self, offset: usize, expected: &[u8]) -> ReaderResult<()> {
+        let bytes = self.get_slice_of_size(offset, expected.len())?;
+
+        let mismatch = bytes.iter().zip(expected).position(|(a, b)| a != b);
+
+        match mismatch {
+            Some(index) => Err(Error::UnexpectedByte {
+                offset: offset + index,
+                expected: expected[index],
+                found: bytes[index],
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Same as [Reader::expect_bytes], but returns a bool instead of an error.
+    #[inline(always)]
+    fn matches_bytes(&self, offset: usize, expected: &[u8]) -> bool {
+        self.expect_bytes(offset, expected).is_ok()
+    }
+
+    /// Returns the sum of `len` bytes from an offset, wrapping on overflow.
+    #[inline(always)]
+    fn checksum_sum8(&self, offset: usize, len: usize) -> ReaderResult<u8> {
+        let bytes = self.get_slice_of_size(offset, len)?;
+        Ok(bytes.iter().fold(0, |sum, byte| sum.wrapping_add(*byte)))
+    }
+
+    /// Same as [Reader::checksum_sum8], but sums into a [u16].
+    #[inline(always)]
+    fn checksum_sum16(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+        let bytes = self.get_slice_of_size(offset, len)?;
+        Ok(bytes
+            .iter()
+            .fold(0, |sum: u16, byte| sum.wrapping_add(*byte as u16)))
+    }
+
+    /// Same as [Reader::checksum_sum8], but sums into a [u32].
+    #[inline(always)]
+    fn checksum_sum32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+        let bytes = self.get_slice_of_size(offset, len)?;
+        Ok(bytes
+            .iter()
+            .fold(0, |sum: u32, byte| sum.wrapping_add(*byte as u32)))
+    }
+
+    /// Returns the CRC-16/CCITT-FALSE of `len` bytes from an offset.
+    ///
+    /// This uses the polynomial 0x1021 with an initial value of 0xffff, no reflection, and no final xor.
+    #[inline(always)]
+    fn crc16_ccitt(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+        let bytes = self.get_slice_of_size(offset, len)?;
+        Ok(checksum::crc16_ccitt(bytes.iter().copied()))
+    }
+
+    /// Returns the CRC-32 of `len` bytes from an offset.
+    ///
+    /// This is the common CRC-32 used by zip, png, and ethernet.
+    #[inline(always)]
+    fn crc32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+        let bytes = self.get_slice_of_size(offset, len)?;
+        Ok(checksum::crc32(bytes.iter().copied()))
+    }
+
+    /// Returns a [HexDump] of up to `len` bytes from an offset, which can be displayed for debugging.
+    ///
+    /// The region is clamped to the available data.
+    #[inline(always)]
+    fn hexdump(&self, offset: usize, len: usize) -> HexDump<'_> {
+        let bytes = self.get_slice_at_offset(offset);
+        let len = core::cmp::min(len, bytes.len());
+        HexDump::new(&bytes[..len], offset)
+    }
+
+    /// Reads `bit_len` bits starting at `bit_offset` bits into the byte at `byte_offset`,
+    /// with the most significant bit of each byte first.
+    ///
+    /// Bit fields may span multiple bytes.  An error is returned if `bit_len` is greater than 64
+    /// or if the bits exceed the available data.
+    #[inline(always)]
+    fn read_bits_msb(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+    ) -> ReaderResult<u64> {
+        if bit_len > 64 {
+            return Err(Error::InvalidRead {
+                message: "Bit length must be 64 or less",
+            });
+        }
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let bytes = self.get_slice_of_size(byte_offset, bit_end.div_ceil(8))?;
+        let mut value = 0u64;
+
+        for bit in bit_offset..bit_end {
+            let bit_value = (bytes[bit / 8] >> (7 - bit % 8)) & 1;
+            value = (value << 1) | bit_value as u64;
+        }
+
+        Ok(value)
+    }
+
+    /// Same as [Reader::read_bits_msb], but reads the least significant bit of each byte first.
+    /// The first bit read is the least significant bit of the result.
+    #[inline(always)]
+    fn read_bits_lsb(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+    ) -> ReaderResult<u64> {
+        if bit_len > 64 {
+            return Err(Error::InvalidRead {
+                message: "Bit length must be 64 or less",
+            });
+        }
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let bytes = self.get_slice_of_size(byte_offset, bit_end.div_ceil(8))?;
+        let mut value = 0u64;
+
+        for (index, bit) in (bit_offset..bit_end).enumerate() {
+            let bit_value = (bytes[bit / 8] >> (bit % 8)) & 1;
+            value |= (bit_value as u64) << index;
+        }
+
+        Ok(value)
+    }
+
+    /// Same as [Reader::get_slice_of_size], but converts the result to a vector.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_byte_vec(&self, offset: usize, size: usize) -> ReaderResult<Vec<u8>> {
+        Ok(self.get_slice_of_size(offset, size)?.to_vec())
+    }
+
+    /// Same as [Reader::read_byte_vec], but returns a zeroed
+    /// out vector of the correct size if the read is invalid.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn default_read_byte_vec(&self, offset: usize, size: usize) -> Vec<u8> {
+        self.read_byte_vec(offset, size)
+            .unwrap_or_else(|_| vec![0; size])
+    }
+
+    /// Reads a UTF-16 string from its little endian representation that ends with a `0x0000` code unit.
+    ///
+    /// The read bytes include the terminator.  An error is returned if the terminator is missing
+    /// or if the string is not valid UTF-16.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_nt_utf16_le_string(&self, offset: usize) -> ReaderResult<ReadOutput<String>> {
+        let bytes = self.get_slice_at_offset(offset);
+        read_nt_utf16_string(bytes, u16::from_le_bytes)
+    }
+
+    /// Same as [Reader::read_nt_utf16_le_string], but reads the big endian representation.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_nt_utf16_be_string(&self, offset: usize) -> ReaderResult<ReadOutput<String>> {
+        let bytes = self.get_slice_at_offset(offset);
+        read_nt_utf16_string(bytes, u16::from_be_bytes)
+    }
+
+    /// Reads a value from its little endian representation.
+    ///
+    /// Prefer endian agnostic methods when possible.
+    /// This should only be used when reading data from a format or protocol
+    /// that explicitly defines little endian.
+    ///
+    /// Every endian read on [ReaderExt] and [StreamReader](crate::StreamReader) goes through this,
+    /// so readers that can't lend out their data, such as [VolatileSlice](crate::VolatileSlice), can override it.
+    #[inline(always)]
+    fn read_le_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>>
+    where
+        Self: Sized,
+    {
+        let bytes = self.get_slice_at_offset(offset);
+        add_error_context(T::try_read_le(bytes), offset, self.get_slice().len())
+    }
+
+    /// Reads a value from its big endian representation.
+    ///
+    /// Prefer endian agnostic methods when possible.
+    /// This should only be used when reading data from a format or protocol
+    /// that explicitly defines big endian.
+    #[inline(always)]
+    fn read_be_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>>
+    where
+        Self: Sized,
+    {
+        let bytes = self.get_slice_at_offset(offset);
+        add_error_context(T::try_read_be(bytes), offset, self.get_slice().len())
+    }
+}
+
+/// Generic methods for every [Reader].
+///
+/// These are kept out of [Reader] so it stays object safe.
+/// The trait is implemented for every sized reader, including references to trait objects such as `&dyn Reader`.
+pub trait ReaderExt: Reader + Sized {
     /// Same as [Reader::get_slice_of_size], but uses `T.len()` for the size.
     #[inline(always)]
     fn get_sized_slice<T: Sized>(&self, offset: usize) -> ReaderResult<&[u8]> {
         let data = self.get_slice();
         let result_size = mem::size_of::<T>();
-        let offset_end = offset + result_size;
+        let offset_end = checked_offset_end(offset, result_size)?;
 
         if data.len() < offset_end {
             return Err(Error::InvalidSize {
@@ -84,29 +300,18 @@ pub trait Reader {
         Ok(read_value.first().unwrap())
     }
 
-    /// Same as [Reader::get_transmutable], but copies the reference to be an owned value.
+    /// Same as [ReaderExt::get_transmutable], but copies the reference to be an owned value.
     #[inline(always)]
     fn read<T: TriviallyTransmutable>(&self, offset: usize) -> ReaderResult<T> {
         Ok(*self.get_transmutable(offset)?)
     }
 
-    /// Same as [Reader::read], but returns a default value if the read is invalid.
+    /// Same as [ReaderExt::read], but returns a default value if the read is invalid.
     #[inline(always)]
     fn default_read<T: TriviallyTransmutable + Default>(&self, offset: usize) -> T {
         self.read(offset).unwrap_or_default()
     }
 
-    /// Reads a value from its little endian representation.
-    ///
-    /// Prefer endian agnostic methods when possible.
-    /// This should only be used when reading data from a format or protocol
-    /// that explicitly defines little endian.
-    #[inline(always)]
-    fn read_le_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>> {
-        let bytes = self.get_slice_at_offset(offset);
-        add_error_context(T::try_read_le(bytes), offset, self.get_slice().len())
-    }
-
     /// Same as [Reader::read_le_with_output], but only returns the read data.
     ///
     /// Prefer endian agnostic methods when possible.
@@ -118,7 +323,7 @@ pub trait Reader {
         Ok(result.into_data())
     }
 
-    /// Same as [Reader::read_le], but returns a default value if the read is invalid.
+    /// Same as [ReaderExt::read_le], but returns a default value if the read is invalid.
     ///
     /// Prefer endian agnostic methods when possible.
     /// This should only be used when reading data from a format or protocol
@@ -128,17 +333,6 @@ pub trait Reader {
         self.read_le(offset).unwrap_or_default()
     }
 
-    /// Reads a value from its big endian representation.
-    ///
-    /// Prefer endian agnostic methods when possible.
-    /// This should only be used when reading data from a format or protocol
-    /// that explicitly defines big endian.
-    #[inline(always)]
-    fn read_be_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>> {
-        let bytes = self.get_slice_at_offset(offset);
-        add_error_context(T::try_read_be(bytes), offset, self.get_slice().len())
-    }
-
     /// Same as [Reader::read_be_with_output], but only returns the read data.
     ///
     /// Prefer endian agnostic methods when possible.
@@ -150,7 +344,7 @@ pub trait Reader {
         Ok(result.into_data())
     }
 
-    /// Same as [Reader::read_be], but returns a default value if the read is invalid.
+    /// Same as [ReaderExt::read_be], but returns a default value if the read is invalid.
     ///
     /// Prefer endian agnostic methods when possible.
     /// This should only be used when reading data from a format or protocol
@@ -160,20 +354,61 @@ pub trait Reader {
         self.read_be(offset).unwrap_or_default()
     }
 
-    /// Same as [Reader::get_slice_of_size], but converts the result to a vector.
-    #[cfg(feature = "alloc")]
+    /// Reads a value from its native endian representation.
+    ///
+    /// This should only be used for data that was produced by the same machine,
+    /// such as shared memory or in-memory IPC.
     #[inline(always)]
-    fn read_byte_vec(&self, offset: usize, size: usize) -> ReaderResult<Vec<u8>> {
-        Ok(self.get_slice_of_size(offset, size)?.to_vec())
+    fn read_ne<T: EndianRead>(&self, offset: usize) -> ReaderResult<T> {
+        if cfg!(target_endian = "little") {
+            self.read_le(offset)
+        } else {
+            self.read_be(offset)
+        }
     }
 
-    /// Same as [Reader::read_byte_vec], but returns a zeroed
-    /// out vector of the correct size if the read is invalid.
-    #[cfg(feature = "alloc")]
+    /// Same as [ReaderExt::read_ne], but returns a default value if the read is invalid.
+    ///
+    /// This should only be used for data that was produced by the same machine,
+    /// such as shared memory or in-memory IPC.
     #[inline(always)]
-    fn default_read_byte_vec(&self, offset: usize, size: usize) -> Vec<u8> {
-        self.read_byte_vec(offset, size)
-            .unwrap_or_else(|_| vec![0; size])
+    fn default_read_ne<T: EndianRead + Default>(&self, offset: usize) -> T {
+        self.read_ne(offset).unwrap_or_default()
+    }
+
+    /// Reads a value that borrows from the data, such as a struct with `&[u8]` or `&str` fields,
+    /// from its little endian representation.
+    ///
+    /// The value borrows the reader, so the data can't be dropped or changed while the value is used.
+    ///
+    /// Prefer endian agnostic methods when possible.
+    /// This should only be used when reading data from a format or protocol
+    /// that explicitly defines little endian.
+    #[inline(always)]
+    fn read_le_borrowed<'a, T: EndianReadBorrowed<'a>>(&'a self, offset: usize) -> ReaderResult<T> {
+        let bytes = self.get_slice_at_offset(offset);
+        let result = add_error_context(
+            T::try_read_le_borrowed(bytes),
+            offset,
+            self.get_slice().len(),
+        )?;
+        Ok(result.into_data())
+    }
+
+    /// Same as [ReaderExt::read_le_borrowed], but reads the big endian representation.
+    ///
+    /// Prefer endian agnostic methods when possible.
+    /// This should only be used when reading data from a format or protocol
+    /// that explicitly defines big endian.
+    #[inline(always)]
+    fn read_be_borrowed<'a, T: EndianReadBorrowed<'a>>(&'a self, offset: usize) -> ReaderResult<T> {
+        let bytes = self.get_slice_at_offset(offset);
+        let result = add_error_context(
+            T::try_read_be_borrowed(bytes),
+            offset,
+            self.get_slice().len(),
+        )?;
+        Ok(result.into_data())
     }
 
     /// Reads a array from its little endian representation.
@@ -198,7 +433,7 @@ pub trait Reader {
         Ok(data.map(|elem| unsafe { elem.unwrap_unchecked() }))
     }
 
-    /// Same as [Reader::read_array_le], but returns a default
+    /// Same as [ReaderExt::read_array_le], but returns a default
     /// array if the read is invalid.
     fn default_read_array_le<const SIZE: usize, T: EndianRead + Default>(
         &self,
@@ -231,7 +466,7 @@ pub trait Reader {
         Ok(data.map(|elem| unsafe { elem.unwrap_unchecked() }))
     }
 
-    /// Same as [Reader::read_array_be], but returns a default
+    /// Same as [ReaderExt::read_array_be], but returns a default
     /// array if the read is invalid.
     fn default_read_array_be<const SIZE: usize, T: EndianRead + Default>(
         &self,
@@ -243,6 +478,32 @@ pub trait Reader {
     }
 }
 
+impl<T: Reader> ReaderExt for T {}
+
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn read_nt_utf16_string(
+    bytes: &[u8],
+    from_bytes: fn([u8; 2]) -> u16,
+) -> ReaderResult<ReadOutput<String>> {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| from_bytes([unit[0], unit[1]]));
+    let len = units
+        .clone()
+        .position(|unit| unit == 0)
+        .ok_or(Error::InvalidRead {
+            message: "Missing null terminator",
+        })?;
+    let string = char::decode_utf16(units.take(len))
+        .collect::<Result<String, _>>()
+        .map_err(|_| Error::InvalidRead {
+            message: "Invalid UTF-16 string",
+        })?;
+
+    Ok(ReadOutput::new(string, (len + 1) * 2))
+}
+
 impl<const SIZE: usize> Reader for [u8; SIZE] {
     #[inline(always)]
     fn get_slice(&self) -> &[u8] {
@@ -264,6 +525,175 @@ impl Reader for &mut [u8] {
     }
 }
 
+/// Forwards the object safe [Reader] methods through a pointer, or to a field with `this => this.field`,
+/// so readers that customize them behave the same behind the pointer or in a wrapper.
+macro_rules! forward_reader_methods {
+    () => {
+        forward_reader_methods!(this => **this);
+    };
+    ($this:ident => $target:expr) => {
+        #[inline(always)]
+        fn get_slice(&self) -> &[u8] {
+            let $this = self;
+            $target.get_slice()
+        }
+
+        #[inline(always)]
+        fn get_slice_at_offset(&self, offset: usize) -> &[u8] {
+            let $this = self;
+            $target.get_slice_at_offset(offset)
+        }
+
+        #[inline(always)]
+        fn get_slice_of_size(&self, offset: usize, size: usize) -> ReaderResult<&[u8]> {
+            let $this = self;
+            $target.get_slice_of_size(offset, size)
+        }
+
+        #[inline(always)]
+        fn expect_bytes(&self, offset: usize, expected: &[u8]) -> ReaderResult<()> {
+            let $this = self;
+            $target.expect_bytes(offset, expected)
+        }
+
+        #[inline(always)]
+        fn matches_bytes(&self, offset: usize, expected: &[u8]) -> bool {
+            let $this = self;
+            $target.matches_bytes(offset, expected)
+        }
+
+        #[inline(always)]
+        fn checksum_sum8(&self, offset: usize, len: usize) -> ReaderResult<u8> {
+            let $this = self;
+            $target.checksum_sum8(offset, len)
+        }
+
+        #[inline(always)]
+        fn checksum_sum16(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+            let $this = self;
+            $target.checksum_sum16(offset, len)
+        }
+
+        #[inline(always)]
+        fn checksum_sum32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+            let $this = self;
+            $target.checksum_sum32(offset, len)
+        }
+
+        #[inline(always)]
+        fn crc16_ccitt(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+            let $this = self;
+            $target.crc16_ccitt(offset, len)
+        }
+
+        #[inline(always)]
+        fn crc32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+            let $this = self;
+            $target.crc32(offset, len)
+        }
+
+        #[inline(always)]
+        fn hexdump(&self, offset: usize, len: usize) -> HexDump<'_> {
+            let $this = self;
+            $target.hexdump(offset, len)
+        }
+
+        #[inline(always)]
+        fn read_bits_msb(
+            &self,
+            byte_offset: usize,
+            bit_offset: usize,
+            bit_len: usize,
+        ) -> ReaderResult<u64> {
+            let $this = self;
+            $target.read_bits_msb(byte_offset, bit_offset, bit_len)
+        }
+
+        #[inline(always)]
+        fn read_bits_lsb(
+            &self,
+            byte_offset: usize,
+            bit_offset: usize,
+            bit_len: usize,
+        ) -> ReaderResult<u64> {
+            let $this = self;
+            $target.read_bits_lsb(byte_offset, bit_offset, bit_len)
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        fn read_byte_vec(&self, offset: usize, size: usize) -> ReaderResult<Vec<u8>> {
+            let $this = self;
+            $target.read_byte_vec(offset, size)
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        fn default_read_byte_vec(&self, offset: usize, size: usize) -> Vec<u8> {
+            let $this = self;
+            $target.default_read_byte_vec(offset, size)
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        fn read_nt_utf16_le_string(&self, offset: usize) -> ReaderResult<ReadOutput<String>> {
+            let $this = self;
+            $target.read_nt_utf16_le_string(offset)
+        }
+
+        #[cfg(feature = "alloc")]
+        #[inline(always)]
+        fn read_nt_utf16_be_string(&self, offset: usize) -> ReaderResult<ReadOutput<String>> {
+            let $this = self;
+            $target.read_nt_utf16_be_string(offset)
+        }
+    };
+}
+pub(crate) use forward_reader_methods;
+
+/// Forwards the [Reader] methods that require `Self: Sized` through a pointer, or to a field with `this => this.field`,
+/// so readers that customize them, such as volatile memory, behave the same behind the pointer.
+macro_rules! forward_sized_reader_methods {
+    () => {
+        forward_sized_reader_methods!(this => **this);
+    };
+    ($this:ident => $target:expr) => {
+        #[inline(always)]
+        fn read_le_with_output<U: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<U>> {
+            let $this = self;
+            $target.read_le_with_output(offset)
+        }
+
+        #[inline(always)]
+        fn read_be_with_output<U: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<U>> {
+            let $this = self;
+            $target.read_be_with_output(offset)
+        }
+    };
+}
+pub(crate) use forward_sized_reader_methods;
+
+impl<T: Reader> Reader for &T {
+    forward_reader_methods!();
+    forward_sized_reader_methods!();
+}
+
+/// Trait objects can't forward the methods that require `Self: Sized`,
+/// so those read from [Reader::get_slice] instead.
+impl<'a> Reader for &(dyn Reader + 'a) {
+    forward_reader_methods!();
+}
+
+impl<T: Reader> Reader for &mut T {
+    forward_reader_methods!();
+    forward_sized_reader_methods!();
+}
+
+/// Same as the `&dyn Reader` implementation, the methods that require `Self: Sized` aren't forwarded.
+impl<'a> Reader for &mut (dyn Reader + 'a) {
+    forward_reader_methods!();
+}
+
 #[cfg(feature = "alloc")]
 impl Reader for Vec<u8> {
     #[inline(always)]
@@ -272,7 +702,28 @@ impl Reader for Vec<u8> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Reader for Box<[u8]> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Reader> Reader for Box<T> {
+    forward_reader_methods!();
+    forward_sized_reader_methods!();
+}
+
+/// Same as the `&dyn Reader` implementation, the methods that require `Self: Sized` aren't forwarded.
+#[cfg(feature = "alloc")]
+impl<'a> Reader for Box<dyn Reader + 'a> {
+    forward_reader_methods!();
+}
+
 #[cfg(test)]
+#[allow(dead_code)]
 mod test {
     use super::*;
 
@@ -491,9 +942,11 @@ mod test {
             );
         }
 
+        #[cfg(feature = "alloc")]
         #[derive(Debug)]
         struct CustomErrorTest;
 
+        #[cfg(feature = "alloc")]
         impl EndianRead for CustomErrorTest {
             fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
                 Err(Error::InvalidRead {
@@ -506,6 +959,7 @@ mod test {
             }
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_bubble_up_custom_errors() {
             let result = vec![].read_le::<CustomErrorTest>(0).unwrap_err();
@@ -515,9 +969,11 @@ mod test {
             assert_eq!(result, expected)
         }
 
+        #[cfg(feature = "alloc")]
         #[derive(Debug)]
         struct OffsetErrorTest(u32);
 
+        #[cfg(feature = "alloc")]
         impl EndianRead for OffsetErrorTest {
             fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
                 Err(Error::InvalidSize {
@@ -532,6 +988,7 @@ mod test {
             }
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_bubble_up_error_offsets() {
             let bytes = vec![];
@@ -624,9 +1081,11 @@ mod test {
             );
         }
 
+        #[cfg(feature = "alloc")]
         #[derive(Debug)]
         struct CustomErrorTest;
 
+        #[cfg(feature = "alloc")]
         impl EndianRead for CustomErrorTest {
             fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
                 unimplemented!()
@@ -639,6 +1098,7 @@ mod test {
             }
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_bubble_up_custom_errors() {
             let result = vec![].read_be::<CustomErrorTest>(0).unwrap_err();
@@ -648,9 +1108,11 @@ mod test {
             assert_eq!(result, expected)
         }
 
+        #[cfg(feature = "alloc")]
         #[derive(Debug)]
         struct OffsetErrorTest(u32);
 
+        #[cfg(feature = "alloc")]
         impl EndianRead for OffsetErrorTest {
             fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
                 unimplemented!()
@@ -665,6 +1127,7 @@ mod test {
             }
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_bubble_up_error_offsets() {
             let bytes = vec![];
@@ -727,24 +1190,27 @@ mod test {
         }
     }
 
-    mod read_byte_vec {
+    mod read_ne {
         use super::*;
 
         #[test]
         fn should_return_a_value() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             let value = reader
-                .read_byte_vec(4, 3)
+                .read_ne::<u32>(4)
                 .expect("Read should have been successful.");
 
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            #[cfg(target_endian = "little")]
+            assert_eq!(value, 0xddccbbaa);
+            #[cfg(target_endian = "big")]
+            assert_eq!(value, 0xaabbccdd);
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             let error = reader
-                .read_byte_vec(6, 4)
+                .read_ne::<u32>(6)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
@@ -758,61 +1224,572 @@ mod test {
         }
     }
 
-    mod default_read_byte_vec {
+    mod default_read_ne {
         use super::*;
 
         #[test]
         fn should_return_a_value() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            let value = reader.default_read_byte_vec(4, 3);
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            let value = reader.default_read_ne::<u32>(4);
+            assert_eq!(value, u32::from_ne_bytes([0xaa, 0xbb, 0xcc, 0xdd]));
         }
 
         #[test]
         fn should_return_default_if_size_is_too_large_for_offset() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            let value = reader.default_read_byte_vec(6, 4);
-            assert_eq!(value, vec![0, 0, 0, 0]);
+            let value = reader.default_read_ne::<u32>(6);
+            assert_eq!(value, u32::default());
         }
     }
 
-    mod read_array_le {
+    mod read_le_borrowed {
         use super::*;
 
         #[test]
-        fn should_return_a_value() {
+        fn should_borrow_from_the_reader() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            let value: [u16; 4] = reader
-                .read_array_le(0)
-                .expect("Should have been successful");
-            assert_eq!(value, [0x2211, 0x4433, 0xbbaa, 0xddcc]);
+            let value: &[u8] = reader.read_le_borrowed(4).expect("Read should have worked");
+            assert_eq!(value, [0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(value.as_ptr(), reader.bytes[4..].as_ptr());
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_read_endian_read_types() {
             let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            let value = reader
-                .read_array_le::<4, u16>(6)
-                .expect_err("Length should have been too large");
+            let value: u32 = reader.read_le_borrowed(4).expect("Read should have worked");
+            assert_eq!(value, 0xddccbbaa);
+        }
+
+        #[test]
+        fn should_add_the_offset_to_errors() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_le_borrowed::<u32>(6)
+                .expect_err("This should have failed");
             assert_eq!(
-                value,
+                error,
                 Error::InvalidSize {
-                    wanted_size: 2,
-                    offset: 8,
+                    wanted_size: 4,
+                    offset: 6,
                     data_len: 8,
                 }
             );
         }
     }
 
-    mod default_read_array_le {
+    mod read_be_borrowed {
         use super::*;
 
         #[test]
-        fn should_return_a_value() {
-            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            let value: [u16; 4] = reader.default_read_array_le(0);
-            assert_eq!(value, [0x2211, 0x4433, 0xbbaa, 0xddcc]);
+        fn should_borrow_from_the_reader() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x61, 0x62, 0x63, 0x64]);
+            let value: &str = reader.read_be_borrowed(4).expect("Read should have worked");
+            assert_eq!(value, "abcd");
+            assert_eq!(value.as_ptr(), reader.bytes[4..].as_ptr());
+        }
+
+        #[test]
+        fn should_read_endian_read_types() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value: u32 = reader.read_be_borrowed(4).expect("Read should have worked");
+            assert_eq!(value, 0xaabbccdd);
+        }
+    }
+
+    mod expect_bytes {
+        use super::*;
+
+        #[test]
+        fn should_succeed_if_bytes_match() {
+            let reader = MockReader::new(*b"RIFF\x11\x22\x33\x44");
+            reader
+                .expect_bytes(0, b"RIFF")
+                .expect("Bytes should have matched");
+        }
+
+        #[test]
+        fn should_return_error_with_the_first_mismatched_byte() {
+            let reader = MockReader::new(*b"RIFX\x11\x22\x33\x44");
+            let error = reader
+                .expect_bytes(0, b"RIFF")
+                .expect_err("Bytes should not have matched");
+
+            assert_eq!(
+                error,
+                Error::UnexpectedByte {
+                    offset: 3,
+                    expected: b'F',
+                    found: b'X',
+                }
+            );
+        }
+
+        #[test]
+        fn should_include_the_offset_in_the_error() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .expect_bytes(4, &[0xaa, 0xbc])
+                .expect_err("Bytes should not have matched");
+
+            assert_eq!(
+                error,
+                Error::UnexpectedByte {
+                    offset: 5,
+                    expected: 0xbc,
+                    found: 0xbb,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new(*b"RIFF\x11\x22\x33\x44");
+            let error = reader
+                .expect_bytes(6, b"RIFF")
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod matches_bytes {
+        use super::*;
+
+        #[test]
+        fn should_return_true_if_bytes_match() {
+            let reader = MockReader::new(*b"RIFF\x11\x22\x33\x44");
+            assert!(reader.matches_bytes(0, b"RIFF"));
+        }
+
+        #[test]
+        fn should_return_false_if_bytes_differ() {
+            let reader = MockReader::new(*b"RIFX\x11\x22\x33\x44");
+            assert!(!reader.matches_bytes(0, b"RIFF"));
+        }
+
+        #[test]
+        fn should_return_false_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new(*b"RIFF\x11\x22\x33\x44");
+            assert!(!reader.matches_bytes(6, b"RIFF"));
+        }
+    }
+
+    mod checksums {
+        use super::*;
+
+        #[test]
+        fn should_return_sum8() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .checksum_sum8(2, 4)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0xdc);
+        }
+
+        #[test]
+        fn should_return_sum16() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .checksum_sum16(0, 8)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0x03b8);
+        }
+
+        #[test]
+        fn should_return_sum32() {
+            let reader = MockReader::new([0xff; 8]);
+            let value = reader
+                .checksum_sum32(0, 8)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0x07f8);
+        }
+
+        #[test]
+        fn should_return_crc16_ccitt() {
+            let reader = *b"_123456789";
+            let value = reader
+                .crc16_ccitt(1, 9)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0x29b1);
+        }
+
+        #[test]
+        fn should_return_crc32() {
+            let reader = *b"_123456789";
+            let value = reader
+                .crc32(1, 9)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0xcbf43926);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let expected = || Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            };
+
+            assert_eq!(reader.checksum_sum8(6, 4), Err(expected()));
+            assert_eq!(reader.checksum_sum16(6, 4), Err(expected()));
+            assert_eq!(reader.checksum_sum32(6, 4), Err(expected()));
+            assert_eq!(reader.crc16_ccitt(6, 4), Err(expected()));
+            assert_eq!(reader.crc32(6, 4), Err(expected()));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod hexdump {
+        use super::*;
+        use alloc::string::ToString;
+
+        #[test]
+        fn should_format_the_region() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x41, 0x42, 0x00, 0xdd]);
+            let result = reader.hexdump(2, 4).to_string();
+            assert_eq!(
+                result,
+                "00000002  33 44 41 42                                       |3DAB|\n"
+            );
+        }
+
+        #[test]
+        fn should_clamp_to_the_available_data() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x41, 0x42, 0x00, 0xdd]);
+            let result = reader.hexdump(6, 100).to_string();
+            assert_eq!(
+                result,
+                "00000006  00 dd                                             |..|\n"
+            );
+        }
+    }
+
+    mod read_bits_msb {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0b1010_0000, 0, 0, 0, 0, 0, 0, 0]);
+            let value = reader
+                .read_bits_msb(0, 0, 3)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0b101);
+        }
+
+        #[test]
+        fn should_read_across_bytes() {
+            let reader = MockReader::new([0b0001_1111, 0b1111_1111, 0, 0, 0, 0, 0, 0]);
+            let value = reader
+                .read_bits_msb(0, 3, 13)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0x1fff);
+        }
+
+        #[test]
+        fn should_read_byte_aligned_fields() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_bits_msb(4, 0, 16)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0xaabb);
+        }
+
+        #[test]
+        fn should_read_64_bits() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_bits_msb(0, 0, 64)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0x11223344aabbccdd);
+        }
+
+        #[test]
+        fn should_return_error_if_bits_are_out_of_range() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_bits_msb(7, 4, 8)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_bit_length_is_too_large() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_bits_msb(0, 0, 65)
+                .expect_err("Bit length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Bit length must be 64 or less",
+                }
+            );
+        }
+    }
+
+    mod read_bits_lsb {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0b0000_0101, 0, 0, 0, 0, 0, 0, 0]);
+            let value = reader
+                .read_bits_lsb(0, 0, 3)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0b101);
+        }
+
+        #[test]
+        fn should_read_across_bytes() {
+            let reader = MockReader::new([0b1111_1000, 0b1111_1111, 0, 0, 0, 0, 0, 0]);
+            let value = reader
+                .read_bits_lsb(0, 3, 13)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0x1fff);
+        }
+
+        #[test]
+        fn should_read_byte_aligned_fields() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_bits_lsb(4, 0, 16)
+                .expect("Read should have been successful.");
+            assert_eq!(value, 0xbbaa);
+        }
+
+        #[test]
+        fn should_return_error_if_bits_are_out_of_range() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_bits_lsb(7, 4, 8)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_bit_length_is_too_large() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_bits_lsb(0, 0, 65)
+                .expect_err("Bit length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Bit length must be 64 or less",
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_byte_vec {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_byte_vec(4, 3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_byte_vec(6, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod default_read_byte_vec {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.default_read_byte_vec(4, 3);
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_return_default_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader.default_read_byte_vec(6, 4);
+            assert_eq!(value, vec![0, 0, 0, 0]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_nt_utf16_le_string {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x48, 0x00, 0x69, 0x00, 0x00, 0x00, 0xaa, 0xbb]);
+            let value = reader
+                .read_nt_utf16_le_string(0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new(String::from("Hi"), 6));
+        }
+
+        #[test]
+        fn should_not_stop_at_unaligned_null_bytes() {
+            let reader = MockReader::new([0xaa, 0x00, 0x00, 0x01, 0x00, 0x00, 0xff, 0xff]);
+            let value = reader
+                .read_nt_utf16_le_string(0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new(String::from("\u{aa}\u{100}"), 6));
+        }
+
+        #[test]
+        fn should_decode_surrogate_pairs() {
+            let reader = MockReader::new([0x3d, 0xd8, 0x00, 0xde, 0x00, 0x00, 0xaa, 0xbb]);
+            let value = reader
+                .read_nt_utf16_le_string(0)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new(String::from("\u{1f600}"), 6));
+        }
+
+        #[test]
+        fn should_return_error_if_surrogates_are_invalid() {
+            let reader = MockReader::new([0x3d, 0xd8, 0x41, 0x00, 0x00, 0x00, 0xaa, 0xbb]);
+            let error = reader
+                .read_nt_utf16_le_string(0)
+                .expect_err("String should have been invalid");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid UTF-16 string",
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_terminator_is_missing() {
+            let reader = MockReader::new([0x48, 0x00, 0x69, 0x00, 0x01, 0x00, 0x41, 0x00]);
+            let error = reader
+                .read_nt_utf16_le_string(1)
+                .expect_err("Terminator should have been missing");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Missing null terminator",
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_nt_utf16_be_string {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0xaa, 0x00, 0x48, 0x00, 0x69, 0x00, 0x00, 0xbb]);
+            let value = reader
+                .read_nt_utf16_be_string(1)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, ReadOutput::new(String::from("Hi"), 6));
+        }
+
+        #[test]
+        fn should_return_error_if_terminator_is_missing() {
+            let reader = MockReader::new([0x00, 0x48, 0x00, 0x69, 0x00, 0x01, 0x00, 0x02]);
+            let error = reader
+                .read_nt_utf16_be_string(0)
+                .expect_err("Terminator should have been missing");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Missing null terminator",
+                }
+            );
+        }
+    }
+
+    mod read_array_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value: [u16; 4] = reader
+                .read_array_le(0)
+                .expect("Should have been successful");
+            assert_eq!(value, [0x2211, 0x4433, 0xbbaa, 0xddcc]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value = reader
+                .read_array_le::<4, u16>(6)
+                .expect_err("Length should have been too large");
+            assert_eq!(
+                value,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod default_read_array_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let value: [u16; 4] = reader.default_read_array_le(0);
+            assert_eq!(value, [0x2211, 0x4433, 0xbbaa, 0xddcc]);
         }
 
         #[test]
@@ -871,4 +1848,173 @@ mod test {
             assert_eq!(value, [0, 0, 0, 0]);
         }
     }
+
+    mod pointer_readers {
+        use super::*;
+
+        /// A reader that customizes its checks and checksums.
+        struct CustomReader;
+
+        impl Reader for CustomReader {
+            fn get_slice(&self) -> &[u8] {
+                &[0x11, 0x22]
+            }
+
+            fn expect_bytes(&self, _offset: usize, _expected: &[u8]) -> ReaderResult<()> {
+                Ok(())
+            }
+
+            fn checksum_sum8(&self, _offset: usize, _len: usize) -> ReaderResult<u8> {
+                Ok(0xaa)
+            }
+
+            fn read_bits_msb(
+                &self,
+                _byte_offset: usize,
+                _bit_offset: usize,
+                _bit_len: usize,
+            ) -> ReaderResult<u64> {
+                Ok(0xbb)
+            }
+        }
+
+        fn assert_custom<R: Reader>(reader: R) {
+            assert_eq!(reader.expect_bytes(0, &[0xff]), Ok(()));
+            assert_eq!(reader.checksum_sum8(0, 2), Ok(0xaa));
+            assert_eq!(reader.read_bits_msb(0, 0, 4), Ok(0xbb));
+        }
+
+        #[test]
+        fn should_forward_overrides_through_references() {
+            let mut reader = CustomReader;
+            assert_custom(&reader);
+            assert_custom(&mut reader);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_forward_overrides_through_boxes() {
+            assert_custom(Box::new(CustomReader));
+        }
+    }
+
+    mod dyn_reader {
+        use super::*;
+
+        #[test]
+        fn should_be_usable_as_a_trait_object() {
+            let bytes = [0x11, 0x22, 0x33, 0x44];
+            let reader: &dyn Reader = &bytes;
+            let slice = reader
+                .get_slice_of_size(1, 2)
+                .expect("Read should have succeeded");
+
+            assert_eq!(slice, [0x22, 0x33]);
+        }
+
+        #[test]
+        fn should_call_extension_methods_on_a_trait_object() {
+            let bytes = [0x11, 0x22, 0x33, 0x44];
+            let reader: &dyn Reader = &bytes;
+            let value = reader
+                .read_le::<u16>(2)
+                .expect("Read should have succeeded");
+
+            assert_eq!(value, 0x4433);
+        }
+
+        #[test]
+        fn should_call_extension_methods_on_concrete_types() {
+            let reader = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+            let value = reader
+                .read_be::<u32>(4)
+                .expect("Read should have succeeded");
+
+            assert_eq!(value, 0x55667788);
+        }
+
+        #[test]
+        fn should_store_different_readers_together() {
+            let array = [0x11, 0x22, 0x33, 0x44];
+            let slice: &[u8] = &[0x11, 0x22];
+            let mock = MockReader::new([0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88]);
+            let readers: [&dyn Reader; 3] = [&array, &slice, &mock];
+
+            for reader in readers {
+                let value = reader
+                    .read_le::<u16>(0)
+                    .expect("Read should have succeeded");
+                assert_eq!(value, 0x2211);
+            }
+        }
+    }
+
+    mod offset_overflow {
+        use super::*;
+
+        #[test]
+        fn should_return_error_if_slice_end_overflows() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .get_slice_of_size(usize::MAX, 2)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 2,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_sized_slice_end_overflows() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .get_sized_slice::<u32>(usize::MAX - 1)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX - 1,
+                    size: 4,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_bit_range_overflows() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .read_bits_msb(0, usize::MAX, 4)
+                .expect_err("Bit offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 4,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_size_for_values_near_the_end_of_the_address_space() {
+            let reader = MockReader::new([0; 8]);
+            let error = reader
+                .read_le::<u32>(usize::MAX)
+                .expect_err("Offset should have been past the end");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: usize::MAX,
+                    data_len: 8,
+                }
+            );
+        }
+    }
 }
@@ -0,0 +1,282 @@
+use crate::{add_error_context, checked_total_size, Cursor, EndianWrite, Writer, WriterResult};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(not(feature = "alloc"))]
+use crate::Error;
+
+/// The largest single write a [SinkWriter] accepts when the `alloc` feature is disabled.
+pub const SINK_CHUNK_SIZE: usize = 256;
+
+/// A [Writer] that accepts writes at any offset and discards the data.
+///
+/// Data written to this writer is not retained.
+/// [EndianWrite] implementations are still run against a scratch buffer,
+/// so their errors are returned the same way they would be for any other writer.
+/// This is useful for benchmarking and dry runs.
+///
+/// With the `alloc` feature, the scratch buffer grows to fit the largest single write.
+/// Without it, writes that need a scratch buffer larger than [SINK_CHUNK_SIZE] return an [Error::InvalidSize](crate::Error::InvalidSize).
+#[derive(Debug)]
+pub struct SinkWriter {
+    cursor: usize,
+    #[cfg(feature = "alloc")]
+    scratch: Vec<u8>,
+    #[cfg(not(feature = "alloc"))]
+    scratch: [u8; SINK_CHUNK_SIZE],
+}
+
+impl SinkWriter {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self {
+            cursor: 0,
+            #[cfg(feature = "alloc")]
+            scratch: Vec::new(),
+            #[cfg(not(feature = "alloc"))]
+            scratch: [0; SINK_CHUNK_SIZE],
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn get_scratch(&mut self, _offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        if self.scratch.len() < length {
+            self.scratch.resize(length, 0);
+        }
+
+        Ok(&mut self.scratch[..length])
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[inline(always)]
+    fn get_scratch(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        if length > SINK_CHUNK_SIZE {
+            return Err(Error::InvalidSize {
+                wanted_size: length,
+                offset,
+                data_len: offset.saturating_add(SINK_CHUNK_SIZE),
+            });
+        }
+
+        Ok(&mut self.scratch[..length])
+    }
+}
+
+impl Default for SinkWriter {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Writer for SinkWriter {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.scratch
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        self.get_scratch(offset, length)
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, _offset: usize) -> Option<usize> {
+        None
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, _offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        Ok(bytes.len())
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, _offset: usize, len: usize, _value: u8) -> WriterResult<usize> {
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn erase(&mut self, _offset: usize, len: usize) -> WriterResult<usize> {
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        _src_offset: usize,
+        _dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let scratch = self.get_scratch(offset, value.get_size())?;
+        let scratch_len = scratch.len();
+        add_error_context(
+            value.try_write_le(scratch),
+            offset,
+            offset.saturating_add(scratch_len),
+        )
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let scratch = self.get_scratch(offset, value.get_size())?;
+        let scratch_len = scratch.len();
+        add_error_context(
+            value.try_write_be(scratch),
+            offset,
+            offset.saturating_add(scratch_len),
+        )
+    }
+
+    #[inline(always)]
+    fn write_repeated_le<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let size = self.write_le(offset, value)?;
+        checked_total_size(offset, size, count)
+    }
+
+    #[inline(always)]
+    fn write_repeated_be<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        if count == 0 {
+            return Ok(0);
+        }
+
+        let size = self.write_be(offset, value)?;
+        checked_total_size(offset, size, count)
+    }
+}
+
+impl Cursor for SinkWriter {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.cursor
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.cursor = index;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Error, StreamWriter};
+
+    #[derive(Debug)]
+    struct CustomErrorTest;
+
+    impl EndianWrite for CustomErrorTest {
+        fn get_size(&self) -> usize {
+            2
+        }
+        fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+            Err(Error::InvalidWrite {
+                message: "Custom error!",
+            })
+        }
+        fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+            unimplemented!()
+        }
+    }
+
+    #[test]
+    fn should_accept_writes_at_any_offset() {
+        let mut writer = SinkWriter::new();
+        let written = writer
+            .write_le(0x40000000, &0xaabbccddu32)
+            .expect("Write should have succeeded");
+        assert_eq!(written, 4);
+
+        let written = writer
+            .write_bytes(usize::MAX, &[1, 2, 3])
+            .expect("Write should have succeeded");
+        assert_eq!(written, 3);
+
+        let written = writer
+            .write_array_be(0x1234, &[0x1122u16, 0x3344])
+            .expect("Write should have succeeded");
+        assert_eq!(written, 4);
+    }
+
+    #[test]
+    fn should_advance_the_cursor() {
+        let mut writer = SinkWriter::new();
+        writer
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        writer
+            .fill_stream(0x1000, 0)
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.get_index(), 0x1004);
+    }
+
+    #[test]
+    fn should_return_error_if_the_repeated_size_overflows() {
+        let mut writer = SinkWriter::new();
+        let error = writer
+            .write_repeated_le(1, &0u16, usize::MAX)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::OffsetOverflow {
+                offset: 1,
+                size: usize::MAX,
+            }
+        );
+    }
+
+    #[test]
+    fn should_bubble_up_custom_errors() {
+        let mut writer = SinkWriter::new();
+        let error = writer
+            .write_le(0x10, &CustomErrorTest)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::InvalidWrite {
+                message: "Custom error!",
+            }
+        );
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    #[test]
+    fn should_return_error_if_write_is_larger_than_the_chunk_at_the_last_offset() {
+        let mut writer = SinkWriter::new();
+        let error = writer
+            .write_le(usize::MAX, &[0u8; SINK_CHUNK_SIZE + 1])
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: SINK_CHUNK_SIZE + 1,
+                offset: usize::MAX,
+                data_len: usize::MAX,
+            }
+        );
+    }
+}
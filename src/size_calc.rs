@@ -0,0 +1,293 @@
+use crate::{checked_offset_end, Cursor, EndianWrite, SinkWriter, Writer, WriterResult};
+
+/// A [Writer] that discards written data and only measures how large the written data would be.
+///
+/// Running existing write code against this writer yields the exact number of bytes it writes,
+/// which is useful to preallocate buffers or write size headers.
+/// The size is the end of the furthest write, so gaps left between writes are counted.
+///
+/// Values are written to a [SinkWriter], so [EndianWrite] errors are still returned
+/// and the same scratch buffer limits apply.
+#[derive(Debug, Default)]
+pub struct SizeCalcWriter {
+    size: usize,
+    sink: SinkWriter,
+}
+
+impl SizeCalcWriter {
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of bytes needed to hold everything written so far.
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    #[inline(always)]
+    fn record_write(&mut self, offset: usize, length: usize) -> WriterResult<()> {
+        self.size = core::cmp::max(self.size, checked_offset_end(offset, length)?);
+        Ok(())
+    }
+}
+
+impl Writer for SizeCalcWriter {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self.sink.get_mut_slice()
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        let offset_end = checked_offset_end(offset, length)?;
+        let slice = self.sink.get_sized_mut_slice(offset, length)?;
+        self.size = core::cmp::max(self.size, offset_end);
+        Ok(slice)
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, offset: usize) -> Option<usize> {
+        self.sink.writable_len(offset)
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        let written = self.sink.write_bytes(offset, bytes)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        let written = self.sink.fill(offset, len, value)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        let written = self.sink.erase(offset, len)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        src_offset: usize,
+        dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        let written = self.sink.copy_within(src_offset, dst_offset, len)?;
+        self.record_write(dst_offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let written = self.sink.write_le(offset, value)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let written = self.sink.write_be(offset, value)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn write_repeated_le<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        let written = self.sink.write_repeated_le(offset, value, count)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn write_repeated_be<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        let written = self.sink.write_repeated_be(offset, value, count)?;
+        self.record_write(offset, written)?;
+        Ok(written)
+    }
+}
+
+impl Cursor for SizeCalcWriter {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.sink.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.sink.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::StreamWriter;
+
+    #[test]
+    fn should_measure_writes() {
+        let mut writer = SizeCalcWriter::new();
+        writer
+            .write_le(0, &0xaabbccddu32)
+            .expect("Write should have succeeded");
+        writer
+            .write_bytes(4, &[1, 2, 3])
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.size(), 7);
+    }
+
+    #[test]
+    fn should_measure_the_furthest_write() {
+        let mut writer = SizeCalcWriter::new();
+        writer
+            .write_be(8, &0xaabbu16)
+            .expect("Write should have succeeded");
+        writer
+            .write_be(0, &0xaabbccddu32)
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.size(), 10);
+    }
+
+    #[test]
+    fn should_measure_stream_writes() {
+        let mut writer = SizeCalcWriter::new();
+        writer
+            .write_stream_le(&0xaau8)
+            .expect("Write should have succeeded");
+        writer
+            .write_array_stream_be(&[0x1122u16, 0x3344])
+            .expect("Write should have succeeded");
+        writer
+            .fill_stream(3, 0)
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.get_index(), 8);
+        assert_eq!(writer.size(), 8);
+    }
+
+    #[test]
+    fn should_measure_repeated_writes() {
+        let mut writer = SizeCalcWriter::new();
+        writer
+            .write_repeated_le(2, &0xffffffffu32, 0x100)
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.size(), 0x402);
+    }
+
+    #[test]
+    fn should_be_empty_if_nothing_was_written() {
+        let writer = SizeCalcWriter::new();
+        assert_eq!(writer.size(), 0);
+    }
+
+    #[cfg(feature = "alloc")]
+    mod derived {
+        use super::*;
+        use crate::EndianWrite;
+        use alloc::{vec, vec::Vec};
+
+        #[derive(EndianWrite)]
+        struct Entry {
+            kind: u8,
+            value: u16,
+        }
+
+        #[derive(EndianWrite)]
+        struct Table {
+            #[no_std_io(len_of = "entries")]
+            count: u16,
+            #[no_std_io(len_from = "count")]
+            entries: Vec<Entry>,
+            last: u8,
+        }
+
+        #[derive(EndianWrite)]
+        struct Packet {
+            #[no_std_io(len_of = "payload", len_kind = "bytes")]
+            size: u8,
+            #[no_std_io(pad_before = 3)]
+            table: Table,
+            #[no_std_io(rest)]
+            payload: Vec<Entry>,
+        }
+
+        fn create_table(count: u16) -> Table {
+            Table {
+                count: 0,
+                entries: (0..count)
+                    .map(|index| Entry {
+                        kind: index as u8,
+                        value: index,
+                    })
+                    .collect(),
+                last: 0xaa,
+            }
+        }
+
+        #[test]
+        fn should_match_a_vector_write_of_a_len_from_field() {
+            for count in [0, 1, 5] {
+                let table = create_table(count);
+                let mut bytes = vec![];
+                bytes
+                    .write_le(0, &table)
+                    .expect("Write should have succeeded");
+                let mut writer = SizeCalcWriter::new();
+                writer
+                    .write_le(0, &table)
+                    .expect("Write should have succeeded");
+
+                assert_eq!(writer.size(), bytes.len());
+            }
+        }
+
+        #[test]
+        fn should_match_a_vector_write_of_nested_structs() {
+            let packet = Packet {
+                size: 0,
+                table: create_table(3),
+                payload: vec![
+                    Entry {
+                        kind: 1,
+                        value: 0x1122,
+                    },
+                    Entry {
+                        kind: 2,
+                        value: 0x3344,
+                    },
+                ],
+            };
+            let mut bytes = vec![];
+            bytes
+                .write_be(2, &packet)
+                .expect("Write should have succeeded");
+            let mut writer = SizeCalcWriter::new();
+            writer
+                .write_be(2, &packet)
+                .expect("Write should have succeeded");
+
+            assert_eq!(writer.size(), bytes.len());
+            assert_eq!(writer.size(), 2 + 1 + 3 + 12 + 6);
+        }
+    }
+}
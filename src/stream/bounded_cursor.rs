@@ -0,0 +1,271 @@
+use super::cursor::Cursor;
+use crate::{EndianWrite, Error, Reader, Writer, WriterResult};
+
+/// A [Cursor] that refuses to read, write, or increment past a limit.
+///
+/// This is useful when a length or offset comes from untrusted data,
+/// so a corrupt value can't move the index somewhere unexpected.
+/// [Cursor::checked_increment_by] returns an [Error::OffsetOverflow] without moving the index
+/// if the new index would be past the limit.
+///
+/// [Reader] and [Writer] are forwarded to the wrapped cursor when it implements them,
+/// but only the data before the limit can be read or written.
+/// Like moving the index past the end of the data, [Cursor::set_index] can move the index past the limit,
+/// and reads and writes from there fail.
+pub struct BoundedCursor<T: Cursor> {
+    cursor: T,
+    limit: usize,
+}
+
+impl<T: Cursor> BoundedCursor<T> {
+    #[inline(always)]
+    pub fn new(cursor: T, limit: usize) -> Self {
+        Self { cursor, limit }
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> T {
+        self.cursor
+    }
+
+    /// Returns the largest index the cursor may move to.
+    #[inline(always)]
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    #[inline(always)]
+    fn check_bounds(&self, offset: usize, length: usize) -> WriterResult<()> {
+        if offset > self.limit || length > self.limit - offset {
+            return Err(Error::InvalidSize {
+                wanted_size: length,
+                offset,
+                data_len: self.limit,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Cursor> Cursor for BoundedCursor<T> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.cursor.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.cursor.set_index(index);
+    }
+
+    #[inline(always)]
+    fn checked_increment_by(&mut self, count: usize) -> Result<(), Error> {
+        let index = self.get_index();
+
+        match index.checked_add(count) {
+            Some(new_index) if new_index <= self.limit => {
+                self.cursor.set_index(new_index);
+                Ok(())
+            }
+            _ => Err(Error::OffsetOverflow {
+                offset: index,
+                size: count,
+            }),
+        }
+    }
+}
+
+impl<T: Cursor + Reader> Reader for BoundedCursor<T> {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        let slice = self.cursor.get_slice();
+        &slice[..core::cmp::min(self.limit, slice.len())]
+    }
+}
+
+impl<T: Cursor + Writer> Writer for BoundedCursor<T> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        let limit = self.limit;
+        let slice = self.cursor.get_mut_slice();
+        let len = core::cmp::min(limit, slice.len());
+        &mut slice[..len]
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        self.check_bounds(offset, length)?;
+        self.cursor.get_sized_mut_slice(offset, length)
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, offset: usize) -> Option<usize> {
+        let max_len = self.limit.saturating_sub(offset);
+        let len = self.cursor.writable_len(offset).unwrap_or(max_len);
+        Some(core::cmp::min(len, max_len))
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.check_bounds(offset, bytes.len())?;
+        self.cursor.write_bytes(offset, bytes)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        self.check_bounds(offset, len)?;
+        self.cursor.fill(offset, len, value)
+    }
+
+    #[inline(always)]
+    fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.check_bounds(offset, len)?;
+        self.cursor.erase(offset, len)
+    }
+
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        src_offset: usize,
+        dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        self.check_bounds(src_offset, len)?;
+        self.check_bounds(dst_offset, len)?;
+        self.cursor.copy_within(src_offset, dst_offset, len)
+    }
+
+    #[inline(always)]
+    fn write_le<V: EndianWrite>(&mut self, offset: usize, value: &V) -> WriterResult<usize> {
+        self.check_bounds(offset, value.get_size())?;
+        self.cursor.write_le(offset, value)
+    }
+
+    #[inline(always)]
+    fn write_be<V: EndianWrite>(&mut self, offset: usize, value: &V) -> WriterResult<usize> {
+        self.check_bounds(offset, value.get_size())?;
+        self.cursor.write_be(offset, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Seek, SeekFrom, StreamContainer, StreamReader, StreamWriter};
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    #[test]
+    fn should_not_read_across_the_limit() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new([1, 2, 3, 4, 5, 6, 7, 8]), 2);
+        let error = cursor
+            .read_stream_le::<u32>()
+            .expect_err("Read should have been past the limit");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 0,
+                data_len: 2,
+            }
+        );
+        assert_eq!(cursor.get_index(), 0);
+
+        let value: u16 = cursor.read_stream_le().expect("Read should have succeeded");
+        assert_eq!(value, 0x0201);
+        cursor
+            .read_stream_le::<u8>()
+            .expect_err("Read should have been past the limit");
+    }
+
+    #[test]
+    fn should_not_read_after_moving_past_the_limit() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new([1, 2, 3, 4, 5, 6, 7, 8]), 6);
+        cursor.set_index(usize::MAX);
+        assert_eq!(cursor.get_index(), usize::MAX);
+
+        cursor.set_index(4);
+        cursor.increment_by(4);
+        assert_eq!(cursor.get_index(), 8);
+        cursor
+            .read_stream_le::<u8>()
+            .expect_err("Read should have been past the limit");
+    }
+
+    #[test]
+    fn should_seek_to_the_limit() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new([0u8; 8]), 6);
+        assert_eq!(cursor.seek(SeekFrom::End(0)), Ok(6));
+        assert_eq!(cursor.get_index(), 6);
+        assert_eq!(cursor.seek(SeekFrom::Current(-2)), Ok(4));
+    }
+
+    #[test]
+    fn should_not_write_across_the_limit() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new([0u8; 8]), 6);
+        cursor.set_index(4);
+        let error = cursor
+            .write_stream_le(&0xaabbccddu32)
+            .expect_err("Write should have been past the limit");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 4,
+                data_len: 6,
+            }
+        );
+        assert_eq!(cursor.get_index(), 4);
+        assert_eq!(cursor.into_inner().into_raw(), [0; 8]);
+    }
+
+    #[test]
+    fn should_return_error_if_increment_is_past_the_limit() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new([0u8; 8]), 6);
+        cursor.set_index(4);
+        cursor
+            .checked_increment_by(2)
+            .expect("Increment should have succeeded");
+
+        let error = cursor
+            .checked_increment_by(1)
+            .expect_err("Increment should have been past the limit");
+        assert_eq!(error, Error::OffsetOverflow { offset: 6, size: 1 });
+        assert_eq!(cursor.get_index(), 6);
+    }
+
+    #[test]
+    fn should_return_error_instead_of_wrapping() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new([0u8; 8]), usize::MAX);
+        cursor.set_index(usize::MAX - 1);
+
+        let error = cursor
+            .checked_increment_by(usize::MAX)
+            .expect_err("Increment should have overflowed");
+        assert_eq!(
+            error,
+            Error::OffsetOverflow {
+                offset: usize::MAX - 1,
+                size: usize::MAX,
+            }
+        );
+        assert_eq!(cursor.get_index(), usize::MAX - 1);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_forward_reads_and_writes() {
+        let mut cursor = BoundedCursor::new(StreamContainer::new(vec![]), 8);
+        cursor
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        cursor.set_index(0);
+
+        let value: u32 = cursor.read_stream_le().expect("Read should have succeeded");
+        assert_eq!(value, 0xaabbccdd);
+        assert_eq!(cursor.into_inner().into_raw(), [0xdd, 0xcc, 0xbb, 0xaa]);
+    }
+}
@@ -1,7 +1,22 @@
-use crate::{Cursor, EndianWrite, Reader, Writer, WriterResult};
+use crate::{
+    reader::{forward_reader_methods, forward_sized_reader_methods},
+    writer::{forward_sized_writer_methods, forward_writer_methods},
+    Cursor, EndianRead, EndianWrite, Error, GrowableWriter, HexDump, ReadOutput, Reader,
+    ReaderResult, Writer, WriterResult,
+};
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{boxed::Box, string::String, vec::Vec};
+use core::fmt;
+
+/// The number of bytes shown on each side of the cursor when debug formatting a [StreamContainer].
+const DEBUG_CONTEXT_BYTES: usize = 4;
+
+/// The two halves returned by [StreamContainer::split_at].
+pub type SplitStreams<'a> = (StreamContainer<&'a [u8]>, StreamContainer<&'a [u8]>);
+
+/// The two halves returned by [StreamContainer::split_at_mut].
+pub type SplitStreamsMut<'a> = (StreamContainer<&'a mut [u8]>, StreamContainer<&'a mut [u8]>);
 
 /// A convenience container that allows streaming anything that implements [Reader].
 /// The container can also write to anything that implements [Writer], but only [Reader] is needed
@@ -9,6 +24,10 @@ use alloc::vec::Vec;
 ///
 /// To forward streamed [Writer] methods to containers with vectors, implement both [StreamContainer::get_mut_slice]
 /// and [StreamContainer::get_sized_mut_slice] instead of only [StreamContainer::get_mut_slice].
+///
+/// Debug formatting shows the cursor index, the data length, and the bytes around the cursor,
+/// such as `StreamContainer { index: 12, len: 64, around: [.. aa bb | cc dd ..] }`.
+#[derive(Clone, PartialEq, Eq)]
 pub struct StreamContainer<T: Reader> {
     raw: T,
     cursor: usize,
@@ -20,38 +39,189 @@ impl<T: Reader> StreamContainer<T> {
         Self { raw, cursor: 0 }
     }
 
+    /// Creates a container with the cursor starting at the provided offset.
+    ///
+    /// Like [Cursor::set_index], an offset past the end of the data is allowed,
+    /// but reads will return an error until the cursor is moved back.
+    #[inline(always)]
+    pub fn new_at(raw: T, offset: usize) -> Self {
+        Self {
+            raw,
+            cursor: offset,
+        }
+    }
+
+    /// Moves the cursor to the provided index and returns the container for chaining.
+    #[inline(always)]
+    pub fn with_index(mut self, index: usize) -> Self {
+        self.cursor = index;
+        self
+    }
+
     #[inline(always)]
     pub fn into_raw(self) -> T {
         self.raw
     }
+
+    /// Consumes the container and returns a copy of the bytes from the cursor to the end of the data.
+    /// Returns an empty vector if the cursor is at or past the end of the data.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    pub fn into_remaining_vec(self) -> Vec<u8> {
+        self.raw.get_slice_at_offset(self.cursor).to_vec()
+    }
+
+    /// Returns a sub-stream over the next `len` bytes and moves the cursor past them.
+    ///
+    /// The sub-stream can't read past its window, and its offsets are relative to the start of the window.
+    /// Errors without moving the cursor if there aren't `len` bytes left.
+    #[inline(always)]
+    pub fn take(&mut self, len: usize) -> ReaderResult<StreamContainer<&[u8]>> {
+        let index = self.cursor;
+        self.raw.get_slice_of_size(index, len)?;
+        self.cursor += len;
+        Ok(StreamContainer::new(
+            &self.raw.get_slice()[index..index + len],
+        ))
+    }
+
+    /// Same as [StreamContainer::take], but doesn't move the cursor.
+    #[inline(always)]
+    pub fn window(&self, len: usize) -> ReaderResult<StreamContainer<&[u8]>> {
+        let bytes = self.raw.get_slice_of_size(self.cursor, len)?;
+        Ok(StreamContainer::new(bytes))
+    }
+
+    /// Splits the data at an absolute offset into two sub-streams, each with its cursor at the start.
+    ///
+    /// The first sub-stream has the bytes before the offset, and the second has the bytes from the offset to the end.
+    /// Errors if the offset is past the end of the data.
+    #[inline(always)]
+    pub fn split_at(&self, offset: usize) -> ReaderResult<SplitStreams<'_>> {
+        self.raw.get_slice_of_size(0, offset)?;
+        let (left, right) = self.raw.get_slice().split_at(offset);
+        Ok((StreamContainer::new(left), StreamContainer::new(right)))
+    }
+
+    /// Same as [StreamContainer::split_at], but splits at the cursor.
+    #[inline(always)]
+    pub fn split_at_cursor(&self) -> ReaderResult<SplitStreams<'_>> {
+        self.split_at(self.cursor)
+    }
+
+    /// Returns a [HexDump] of up to `context_bytes` before and after the cursor for debugging.
+    #[inline(always)]
+    pub fn hexdump_around_cursor(&self, context_bytes: usize) -> HexDump<'_> {
+        let start = self.cursor.saturating_sub(context_bytes);
+        let end = core::cmp::min(
+            self.cursor.saturating_add(context_bytes),
+            self.raw.get_slice().len(),
+        );
+        self.hexdump(start, end.saturating_sub(start))
+    }
 }
 
-impl<T: Reader> Reader for StreamContainer<T> {
+impl<T: Reader + Writer> StreamContainer<T> {
+    /// Same as [StreamContainer::split_at], but the sub-streams can write to their halves of the data.
+    ///
+    /// Growable writers, such as vectors, are not grown to fit the offset.
     #[inline(always)]
-    fn get_slice(&self) -> &[u8] {
-        self.raw.get_slice()
+    pub fn split_at_mut(&mut self, offset: usize) -> WriterResult<SplitStreamsMut<'_>> {
+        let data = self.raw.get_mut_slice();
+        let data_len = data.len();
+
+        if offset > data_len {
+            return Err(Error::InvalidSize {
+                wanted_size: offset,
+                offset: 0,
+                data_len,
+            });
+        }
+
+        let (left, right) = data.split_at_mut(offset);
+        Ok((StreamContainer::new(left), StreamContainer::new(right)))
     }
 }
 
+/// Every method is forwarded to the inner reader, so readers that customize them, such as volatile memory, behave the same in a container.
+impl<T: Reader> Reader for StreamContainer<T> {
+    forward_reader_methods!(this => this.raw);
+    forward_sized_reader_methods!(this => this.raw);
+}
+
+/// Every method is forwarded to the inner writer, so writers that customize them, such as transforms, behave the same in a container.
 impl<T: Reader + Writer> Writer for StreamContainer<T> {
+    forward_writer_methods!(this => this.raw);
+    forward_sized_writer_methods!(this => this.raw);
+}
+
+/// Inserting and removing bytes does not move the cursor.
+impl<T: Reader + GrowableWriter> GrowableWriter for StreamContainer<T> {
     #[inline(always)]
-    fn get_mut_slice(&mut self) -> &mut [u8] {
-        self.raw.get_mut_slice()
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.raw.insert_bytes(offset, bytes)
     }
 
     #[inline(always)]
-    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
-        self.raw.get_sized_mut_slice(offset, length)
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.raw.remove_bytes(offset, len)
     }
+}
 
-    #[inline(always)]
-    fn write_le<U: EndianWrite>(&mut self, offset: usize, value: &U) -> WriterResult<usize> {
-        self.raw.write_le(offset, value)
+impl<T: Reader> fmt::Debug for StreamContainer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.raw.get_slice();
+        let index = self.cursor.min(bytes.len());
+        let start = index.saturating_sub(DEBUG_CONTEXT_BYTES);
+        let end = bytes.len().min(index + DEBUG_CONTEXT_BYTES);
+
+        f.debug_struct("StreamContainer")
+            .field("index", &self.cursor)
+            .field("len", &bytes.len())
+            .field(
+                "around",
+                &CursorWindow {
+                    before: &bytes[start..index],
+                    after: &bytes[index..end],
+                    has_start: start == 0,
+                    has_end: end == bytes.len(),
+                },
+            )
+            .finish()
     }
+}
 
-    #[inline(always)]
-    fn write_be<U: EndianWrite>(&mut self, offset: usize, value: &U) -> WriterResult<usize> {
-        self.raw.write_be(offset, value)
+/// The bytes around a cursor, with the cursor marked by `|` and `..` marking omitted bytes.
+struct CursorWindow<'a> {
+    before: &'a [u8],
+    after: &'a [u8],
+    has_start: bool,
+    has_end: bool,
+}
+
+impl fmt::Debug for CursorWindow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[")?;
+
+        if !self.has_start {
+            write!(f, ".. ")?;
+        }
+
+        for byte in self.before {
+            write!(f, "{:02x} ", byte)?;
+        }
+
+        write!(f, "|")?;
+
+        for byte in self.after {
+            write!(f, " {:02x}", byte)?;
+        }
+
+        if !self.has_end {
+            write!(f, " ..")?;
+        }
+
+        write!(f, "]")
     }
 }
 
@@ -96,12 +266,22 @@ impl From<StreamContainer<Vec<u8>>> for Vec<u8> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl From<StreamContainer<Box<[u8]>>> for Box<[u8]> {
+    #[inline(always)]
+    fn from(stream: StreamContainer<Box<[u8]>>) -> Self {
+        stream.into_raw()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{StreamReader, StreamWriter};
-    use alloc::vec;
+    use crate::{ReaderExt, StreamReader, StreamRemaining, StreamWriter};
+    #[cfg(feature = "alloc")]
+    use alloc::{string::ToString, vec};
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn should_work_with_vectors() {
         let data = vec![0xaa, 0xbb, 0xcc, 0xdd];
@@ -120,6 +300,169 @@ mod test {
         StreamContainer::new(data.as_mut_slice()).default_read_stream::<u32>();
     }
 
+    #[test]
+    fn should_stream_reads_from_slices_without_alloc() {
+        let data: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+        let mut stream = StreamContainer::new(data.as_slice());
+
+        let first: u32 = stream.read_stream_le().expect("Read should have succeeded");
+        let second: u16 = stream.read_stream_be().expect("Read should have succeeded");
+        let mut bytes = [0u8; 2];
+        stream
+            .read_stream_exact(&mut bytes)
+            .expect("Read should have succeeded");
+
+        assert_eq!(first, 0xddccbbaa);
+        assert_eq!(second, 0x1122);
+        assert_eq!(bytes, [0x33, 0x44]);
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_stream_writes_to_arrays_without_alloc() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        stream
+            .write_stream_be(&0x1122u16)
+            .expect("Write should have succeeded");
+
+        let error = stream
+            .write_stream_le(&0u32)
+            .expect_err("Write should have failed");
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            }
+        );
+        assert_eq!(stream.get_index(), 6);
+
+        stream.set_index(0);
+        let value: u32 = stream.read_stream_le().expect("Read should have succeeded");
+        assert_eq!(value, 0xaabbccdd);
+        assert_eq!(
+            stream.into_raw(),
+            [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22, 0, 0]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_return_the_remaining_bytes() {
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(1);
+        assert_eq!(stream.into_remaining_vec(), [2, 3, 4]);
+
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(4);
+        assert!(stream.into_remaining_vec().is_empty());
+
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(6);
+        assert!(stream.into_remaining_vec().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_work_with_boxed_slices() {
+        let data: Box<[u8]> = vec![0; 4].into_boxed_slice();
+        let mut stream = StreamContainer::new(data);
+        stream
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        stream.set_index(0);
+
+        let result = stream
+            .read_stream_le::<u32>()
+            .expect("Read should have succeeded");
+        assert_eq!(result, 0xaabbccddu32);
+
+        let raw: Box<[u8]> = stream.into();
+        assert_eq!(*raw, [0xdd, 0xcc, 0xbb, 0xaa]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_grow_a_boxed_slice() {
+        let data: Box<[u8]> = vec![0; 4].into_boxed_slice();
+        let mut stream = StreamContainer::new(data);
+        stream.set_index(2);
+        stream
+            .write_stream_le(&0xaabbccddu32)
+            .expect_err("Length should have been too large");
+        assert_eq!(*stream.into_raw(), [0; 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_insert_and_remove_bytes_without_moving_the_cursor() {
+        let mut stream = StreamContainer::new(vec![1, 2, 3, 4]);
+        stream.set_index(2);
+        stream
+            .insert_bytes(1, &[0xaa, 0xbb])
+            .expect("Insert should have succeeded");
+        stream
+            .remove_bytes(4, 2)
+            .expect("Remove should have succeeded");
+
+        assert_eq!(stream.get_index(), 2);
+        assert_eq!(stream.into_raw(), [1, 0xaa, 0xbb, 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_hexdump_around_the_cursor() {
+        let data: [u8; 8] = [0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd];
+        let mut stream = StreamContainer::new(data);
+        stream.set_index(4);
+        let result = stream.hexdump_around_cursor(2).to_string();
+        assert_eq!(
+            result,
+            "00000002  33 44 aa bb                                       |3D..|\n"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_hexdump_around_the_cursor_near_the_start() {
+        let data: [u8; 8] = [0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd];
+        let mut stream = StreamContainer::new(data);
+        stream.set_index(1);
+        let result = stream.hexdump_around_cursor(2).to_string();
+        assert_eq!(
+            result,
+            "00000000  11 22 33                                          |.\"3|\n"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_hexdump_everything_with_large_context() {
+        let data: [u8; 4] = [0x11, 0x22, 0x33, 0x44];
+        let mut stream = StreamContainer::new(data);
+        stream.set_index(2);
+        let result = stream.hexdump_around_cursor(usize::MAX).to_string();
+        assert_eq!(
+            result,
+            "00000000  11 22 33 44                                       |.\"3D|\n"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_hexdump_nothing_if_the_cursor_is_far_past_the_end() {
+        let data: [u8; 4] = [0x11, 0x22, 0x33, 0x44];
+        let mut stream = StreamContainer::new(data);
+        stream.set_index(usize::MAX);
+        let result = stream.hexdump_around_cursor(2).to_string();
+        assert_eq!(result, "");
+    }
+
+    #[cfg(feature = "alloc")]
     #[test]
     fn should_grow_a_vector_if_needed() {
         let data = vec![];
@@ -128,6 +471,7 @@ mod test {
         assert_eq!(stream.into_raw(), [0xaa, 0xbb, 0xcc, 0xdd]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn should_grow_a_vector_if_needed_with_le() {
         let data = vec![];
@@ -136,6 +480,7 @@ mod test {
         assert_eq!(stream.into_raw(), [0xdd, 0xcc, 0xbb, 0xaa]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn should_grow_a_vector_if_needed_with_be() {
         let data = vec![];
@@ -144,6 +489,7 @@ mod test {
         assert_eq!(stream.into_raw(), [0xaa, 0xbb, 0xcc, 0xdd]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn should_not_grow_a_vector_if_not_needed() {
         let data = vec![0; 4];
@@ -152,6 +498,7 @@ mod test {
         assert_eq!(stream.into_raw(), [0xaa, 0xbb, 0xcc, 0xdd]);
     }
 
+    #[cfg(feature = "alloc")]
     #[test]
     fn should_grow_a_vector_if_needed_and_written_to_twice() {
         let data = vec![0; 4];
@@ -163,4 +510,620 @@ mod test {
             [0xaa, 0xbb, 0xcc, 0xdd, 0xaa, 0xbb, 0xcc, 0xdd]
         );
     }
+
+    #[test]
+    fn should_rewind_a_failed_speculative_read() {
+        let mut stream = StreamContainer::new([0x01u8, 0x02, 0x03, 0x04]);
+
+        let magic = {
+            let mut checkpoint = stream.checkpoint();
+            let magic: u32 = checkpoint
+                .read_stream_be()
+                .expect("Read should have succeeded");
+            magic
+        };
+        assert_eq!(magic, 0x01020304);
+        assert_eq!(stream.get_index(), 0);
+
+        let value: u16 = stream.with_position(2, |stream| {
+            stream.read_stream_le().expect("Read should have succeeded")
+        });
+        assert_eq!(value, 0x0403);
+        assert_eq!(stream.get_index(), 0);
+    }
+
+    #[test]
+    fn should_start_at_an_offset() {
+        let mut stream = StreamContainer::new_at([0x01u8, 0x02, 0x03, 0x04], 2);
+        assert_eq!(stream.get_index(), 2);
+
+        let value: u16 = stream.read_stream_be().expect("Read should have succeeded");
+        assert_eq!(value, 0x0304);
+    }
+
+    #[test]
+    fn should_return_error_if_started_past_the_end() {
+        let mut stream = StreamContainer::new_at([0x01u8, 0x02, 0x03, 0x04], 6);
+
+        let error = stream
+            .read_stream_le::<u8>()
+            .expect_err("Read should have failed");
+        assert_eq!(
+            error,
+            Error::EndOfData {
+                offset: 6,
+                requested: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn should_set_the_index_with_a_builder() {
+        let stream = StreamContainer::new([0u8; 8]).with_index(5);
+        assert_eq!(stream.get_index(), 5);
+    }
+
+    #[test]
+    fn should_take_a_sub_stream() {
+        let mut stream = StreamContainer::new([0x01u8, 0x02, 0x03, 0x04, 0x05, 0x06]);
+        stream.set_index(1);
+
+        let mut block = stream.take(3).expect("Take should have succeeded");
+        let value: u16 = block.read_stream_be().expect("Read should have succeeded");
+        assert_eq!(value, 0x0203);
+
+        let error = block
+            .read_stream_be::<u16>()
+            .expect_err("Read should have been past the window");
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 2,
+                offset: 2,
+                data_len: 3,
+            }
+        );
+
+        assert_eq!(stream.get_index(), 4);
+        let value: u8 = stream.read_stream_le().expect("Read should have succeeded");
+        assert_eq!(value, 0x05);
+    }
+
+    #[test]
+    fn should_return_error_if_take_is_past_the_end() {
+        let mut stream = StreamContainer::new([0x01u8, 0x02, 0x03, 0x04]);
+        stream.set_index(2);
+
+        let error = stream
+            .take(3)
+            .expect_err("Take should have been past the end");
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 3,
+                offset: 2,
+                data_len: 4,
+            }
+        );
+        assert_eq!(stream.get_index(), 2);
+    }
+
+    #[test]
+    fn should_view_a_window_without_moving_the_cursor() {
+        let mut stream = StreamContainer::new([0x01u8, 0x02, 0x03, 0x04]);
+        stream.set_index(2);
+
+        let mut window = stream.window(2).expect("Window should have succeeded");
+        let value: u16 = window.read_stream_le().expect("Read should have succeeded");
+        assert_eq!(value, 0x0403);
+        assert!(window.read_stream_le::<u8>().is_err());
+
+        assert_eq!(stream.get_index(), 2);
+        assert!(stream.window(3).is_err());
+    }
+
+    fn write_header<W: StreamWriter>(mut writer: W) -> WriterResult<usize> {
+        writer.write_stream_le(&0xaabbccddu32)?;
+        writer.write_array_stream_be(&[0x1122u16])
+    }
+
+    fn read_header<R: StreamReader>(mut reader: R) -> ReaderResult<u32> {
+        reader.read_stream_le()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_grow_a_vector_through_a_mutable_reference() {
+        let mut stream = StreamContainer::new(vec![]);
+        write_header(&mut stream).expect("Write should have succeeded");
+        write_header(&mut stream).expect("Write should have succeeded");
+
+        assert_eq!(stream.get_index(), 12);
+        assert_eq!(
+            stream.into_raw(),
+            [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22, 0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_grow_a_vector_through_a_box() {
+        let mut stream = Box::new(StreamContainer::new(vec![]));
+        write_header(&mut stream).expect("Write should have succeeded");
+        stream.set_index(0);
+
+        let value = read_header(stream).expect("Read should have succeeded");
+        assert_eq!(value, 0xaabbccdd);
+    }
+
+    #[test]
+    fn should_write_through_a_mutable_reference() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        write_header(&mut stream).expect("Write should have succeeded");
+        write_header(&mut stream).expect_err("Write should have been past the end");
+
+        assert_eq!(stream.get_index(), 6);
+        assert_eq!(
+            stream.into_raw(),
+            [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22, 0, 0]
+        );
+    }
+
+    #[test]
+    fn should_read_through_a_mutable_reference() {
+        let mut stream = StreamContainer::new([0xaau8, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44]);
+        let first = read_header(&mut stream).expect("Read should have succeeded");
+        let second = read_header(&mut stream).expect("Read should have succeeded");
+
+        assert_eq!(first, 0xddccbbaa);
+        assert_eq!(second, 0x44332211);
+        assert_eq!(stream.get_index(), 8);
+    }
+
+    #[test]
+    fn should_read_through_a_shared_reference() {
+        fn first_value<R: Reader>(reader: R) -> ReaderResult<u32> {
+            reader.read_le(0)
+        }
+
+        let stream = StreamContainer::new([0xaau8, 0xbb, 0xcc, 0xdd]);
+        let value = first_value(&stream).expect("Read should have succeeded");
+        assert_eq!(value, 0xddccbbaa);
+    }
+
+    #[cfg(feature = "alloc")]
+    mod debug {
+        use super::*;
+        use alloc::format;
+
+        const DATA: [u8; 12] = [
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb,
+        ];
+
+        #[test]
+        fn should_format_the_start_of_the_data() {
+            let stream = StreamContainer::new(DATA);
+            assert_eq!(
+                format!("{:?}", stream),
+                "StreamContainer { index: 0, len: 12, around: [| 00 11 22 33 ..] }"
+            );
+        }
+
+        #[test]
+        fn should_format_the_middle_of_the_data() {
+            let stream = StreamContainer::new_at(DATA, 6);
+            assert_eq!(
+                format!("{:?}", stream),
+                "StreamContainer { index: 6, len: 12, around: [.. 22 33 44 55 | 66 77 88 99 ..] }"
+            );
+        }
+
+        #[test]
+        fn should_format_the_end_of_the_data() {
+            let stream = StreamContainer::new_at(DATA, 12);
+            assert_eq!(
+                format!("{:?}", stream),
+                "StreamContainer { index: 12, len: 12, around: [.. 88 99 aa bb |] }"
+            );
+        }
+
+        #[test]
+        fn should_format_a_cursor_past_the_end_of_the_data() {
+            let stream = StreamContainer::new_at(DATA, 20);
+            assert_eq!(
+                format!("{:?}", stream),
+                "StreamContainer { index: 20, len: 12, around: [.. 88 99 aa bb |] }"
+            );
+        }
+
+        #[test]
+        fn should_format_data_shorter_than_the_window() {
+            let stream = StreamContainer::new_at([0xaa, 0xbb, 0xcc], 1);
+            assert_eq!(
+                format!("{:?}", stream),
+                "StreamContainer { index: 1, len: 3, around: [aa | bb cc] }"
+            );
+        }
+
+        #[test]
+        fn should_format_empty_data() {
+            let stream = StreamContainer::new([0u8; 0]);
+            assert_eq!(
+                format!("{:?}", stream),
+                "StreamContainer { index: 0, len: 0, around: [|] }"
+            );
+        }
+    }
+
+    mod clone {
+        use super::*;
+
+        #[test]
+        fn should_clone_the_data_and_cursor() {
+            let stream = StreamContainer::new_at([0xaa, 0xbb, 0xcc, 0xdd], 2);
+            let mut clone = stream.clone();
+            clone.set_index(0);
+
+            assert_eq!(stream.get_index(), 2);
+            assert_eq!(clone.get_index(), 0);
+            assert_eq!(clone.into_raw(), [0xaa, 0xbb, 0xcc, 0xdd]);
+        }
+    }
+
+    mod eq {
+        use super::*;
+
+        #[test]
+        fn should_be_equal_if_data_and_cursor_match() {
+            let left = StreamContainer::new_at([0xaa, 0xbb], 1);
+            let right = StreamContainer::new_at([0xaa, 0xbb], 1);
+            assert_eq!(left, right);
+        }
+
+        #[test]
+        fn should_not_be_equal_if_cursors_differ() {
+            let left = StreamContainer::new_at([0xaa, 0xbb], 0);
+            let right = StreamContainer::new_at([0xaa, 0xbb], 1);
+            assert_ne!(left, right);
+        }
+
+        #[test]
+        fn should_not_be_equal_if_data_differs() {
+            let left = StreamContainer::new([0xaa, 0xbb]);
+            let right = StreamContainer::new([0xaa, 0xcc]);
+            assert_ne!(left, right);
+        }
+    }
+
+    mod split_at {
+        use super::*;
+
+        #[test]
+        fn should_split_at_an_offset() {
+            let stream = StreamContainer::new_at([0xaa, 0xbb, 0xcc, 0xdd], 3);
+            let (left, right) = stream.split_at(1).expect("Split should have succeeded");
+
+            assert_eq!(left.get_index(), 0);
+            assert_eq!(left.get_slice(), [0xaa]);
+            assert_eq!(right.get_index(), 0);
+            assert_eq!(right.get_slice(), [0xbb, 0xcc, 0xdd]);
+            assert_eq!(stream.get_index(), 3);
+        }
+
+        #[test]
+        fn should_move_the_cursors_independently() {
+            let stream = StreamContainer::new([0xaa, 0xbb, 0xcc, 0xdd]);
+            let (mut left, mut right) = stream.split_at(2).expect("Split should have succeeded");
+
+            let left_value = left
+                .read_stream_le::<u8>()
+                .expect("Read should have succeeded");
+            let right_value = right
+                .read_stream_be::<u16>()
+                .expect("Read should have succeeded");
+
+            assert_eq!(left_value, 0xaa);
+            assert_eq!(right_value, 0xccdd);
+            assert_eq!(left.get_index(), 1);
+            assert_eq!(right.get_index(), 2);
+        }
+
+        #[test]
+        fn should_not_read_past_each_half() {
+            let stream = StreamContainer::new([0xaa, 0xbb, 0xcc, 0xdd]);
+            let (mut left, _) = stream.split_at(2).expect("Split should have succeeded");
+
+            let error = left
+                .read_stream_le::<u32>()
+                .expect_err("Read should have been past the end");
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 0,
+                    data_len: 2,
+                }
+            );
+        }
+
+        #[test]
+        fn should_split_at_the_start_and_end() {
+            let stream = StreamContainer::new([0xaa, 0xbb]);
+
+            let (left, right) = stream.split_at(0).expect("Split should have succeeded");
+            assert_eq!(left.get_slice(), []);
+            assert_eq!(right.get_slice(), [0xaa, 0xbb]);
+
+            let (left, right) = stream.split_at(2).expect("Split should have succeeded");
+            assert_eq!(left.get_slice(), [0xaa, 0xbb]);
+            assert_eq!(right.get_slice(), []);
+        }
+
+        #[test]
+        fn should_return_error_if_offset_is_past_the_end() {
+            let stream = StreamContainer::new([0xaa, 0xbb]);
+            let error = stream
+                .split_at(3)
+                .expect_err("Split should have been past the end");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 0,
+                    data_len: 2,
+                }
+            );
+        }
+    }
+
+    mod split_at_cursor {
+        use super::*;
+
+        #[test]
+        fn should_split_at_the_cursor() {
+            let stream = StreamContainer::new_at([0xaa, 0xbb, 0xcc, 0xdd], 3);
+            let (left, right) = stream
+                .split_at_cursor()
+                .expect("Split should have succeeded");
+
+            assert_eq!(left.get_slice(), [0xaa, 0xbb, 0xcc]);
+            assert_eq!(right.get_slice(), [0xdd]);
+        }
+
+        #[test]
+        fn should_return_error_if_cursor_is_past_the_end() {
+            let stream = StreamContainer::new_at([0xaa, 0xbb], 4);
+            let error = stream
+                .split_at_cursor()
+                .expect_err("Split should have been past the end");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 0,
+                    data_len: 2,
+                }
+            );
+        }
+    }
+
+    mod split_at_mut {
+        use super::*;
+
+        #[test]
+        fn should_write_to_disjoint_halves() {
+            let mut stream = StreamContainer::new([0u8; 4]);
+            let (mut left, mut right) =
+                stream.split_at_mut(2).expect("Split should have succeeded");
+
+            left.write_stream_le(&0xbbaau16)
+                .expect("Write should have succeeded");
+            right
+                .write_stream_be(&0xccu8)
+                .expect("Write should have succeeded");
+
+            assert_eq!(left.get_index(), 2);
+            assert_eq!(right.get_index(), 1);
+            assert_eq!(stream.into_raw(), [0xaa, 0xbb, 0xcc, 0]);
+        }
+
+        #[test]
+        fn should_not_write_past_each_half() {
+            let mut stream = StreamContainer::new([0u8; 4]);
+            let (mut left, _) = stream.split_at_mut(1).expect("Split should have succeeded");
+
+            let error = left
+                .write_stream_le(&0xbbaau16)
+                .expect_err("Write should have been past the end");
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 0,
+                    data_len: 1,
+                }
+            );
+            assert_eq!(stream.into_raw(), [0; 4]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector() {
+            let mut stream = StreamContainer::new(vec![0xaa, 0xbb]);
+            let error = stream
+                .split_at_mut(3)
+                .expect_err("Split should have been past the end");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 0,
+                    data_len: 2,
+                }
+            );
+            assert_eq!(stream.into_raw(), [0xaa, 0xbb]);
+        }
+    }
+
+    mod forwarding {
+        use super::*;
+
+        /// A stream that customizes its checks, bit fields, and endian writes.
+        #[derive(Default)]
+        struct CustomStream {
+            bytes: [u8; 4],
+            bits_written: bool,
+            values_written: usize,
+        }
+
+        impl Reader for CustomStream {
+            fn get_slice(&self) -> &[u8] {
+                &self.bytes
+            }
+
+            fn expect_bytes(&self, _offset: usize, _expected: &[u8]) -> ReaderResult<()> {
+                Ok(())
+            }
+
+            fn checksum_sum8(&self, _offset: usize, _len: usize) -> ReaderResult<u8> {
+                Ok(0xaa)
+            }
+
+            fn read_bits_msb(
+                &self,
+                _byte_offset: usize,
+                _bit_offset: usize,
+                _bit_len: usize,
+            ) -> ReaderResult<u64> {
+                Ok(0xbb)
+            }
+        }
+
+        impl Writer for CustomStream {
+            fn get_mut_slice(&mut self) -> &mut [u8] {
+                &mut self.bytes
+            }
+
+            fn write_bits_msb(
+                &mut self,
+                _byte_offset: usize,
+                _bit_offset: usize,
+                _bit_len: usize,
+                _value: u64,
+            ) -> WriterResult<()> {
+                self.bits_written = true;
+                Ok(())
+            }
+
+            fn write_le<U: EndianWrite>(
+                &mut self,
+                _offset: usize,
+                value: &U,
+            ) -> WriterResult<usize> {
+                self.values_written += 1;
+                Ok(value.get_size())
+            }
+        }
+
+        #[test]
+        fn should_forward_reader_overrides() {
+            let stream = StreamContainer::new(CustomStream::default());
+
+            assert_eq!(stream.expect_bytes(0, &[0xff]), Ok(()));
+            assert_eq!(stream.checksum_sum8(0, 4), Ok(0xaa));
+            assert_eq!(stream.read_bits_msb(0, 0, 4), Ok(0xbb));
+        }
+
+        #[test]
+        fn should_forward_writer_overrides() {
+            let mut stream = StreamContainer::new(CustomStream::default());
+            stream
+                .write_bits_msb(0, 0, 4, 1)
+                .expect("Write should have succeeded");
+            stream
+                .write_stream_le(&1u16)
+                .expect("Write should have succeeded");
+
+            let raw = stream.into_raw();
+            assert!(raw.bits_written);
+            assert_eq!(raw.values_written, 1);
+            assert_eq!(raw.bytes, [0; 4]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod vector_writes {
+        use super::*;
+
+        #[test]
+        fn should_grow_when_streaming_arrays() {
+            let mut stream = StreamContainer::new(vec![]);
+            let written_length = stream
+                .write_array_stream_le(&[1u32, 2, 3])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 12);
+            assert_eq!(stream.get_index(), 12);
+            assert_eq!(stream.into_raw(), [1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_grow_when_writing_arrays() {
+            let mut stream = StreamContainer::new(vec![]);
+
+            stream
+                .write_array_le(0, &[1u16, 2])
+                .expect("Write should have succeeded");
+            stream
+                .write_array_be(4, &[3u16])
+                .expect("Write should have succeeded");
+
+            assert_eq!(stream.into_raw(), [1, 0, 2, 0, 0, 3]);
+        }
+
+        #[test]
+        fn should_grow_when_writing_slices() {
+            let mut stream = StreamContainer::new(vec![]);
+
+            stream
+                .write_slice_le(0, &[1u16, 2])
+                .expect("Write should have succeeded");
+            stream
+                .write_slice_be(4, &[3u16])
+                .expect("Write should have succeeded");
+
+            assert_eq!(stream.into_raw(), [1, 0, 2, 0, 0, 3]);
+        }
+
+        #[test]
+        fn should_grow_when_writing_repeated_values() {
+            let mut stream = StreamContainer::new(vec![]);
+
+            stream
+                .write_repeated_le(0, &1u16, 2)
+                .expect("Write should have succeeded");
+            stream
+                .write_repeated_be(4, &2u16, 1)
+                .expect("Write should have succeeded");
+
+            assert_eq!(stream.into_raw(), [1, 0, 1, 0, 0, 2]);
+        }
+
+        #[test]
+        fn should_grow_when_writing_bytes() {
+            let mut stream = StreamContainer::new(vec![]);
+
+            stream
+                .write_bytes(0, &[1, 2])
+                .expect("Write should have succeeded");
+            stream
+                .write_all_slices(2, &[&[3], &[4, 5]])
+                .expect("Write should have succeeded");
+
+            assert_eq!(stream.into_raw(), [1, 2, 3, 4, 5]);
+        }
+    }
 }
@@ -1,4 +1,10 @@
-use core::mem::size_of;
+use crate::Error;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::{
+    mem::size_of,
+    ops::{Deref, DerefMut},
+};
 
 /// An interface for working with cursors by getting and setting an index.
 pub trait Cursor {
@@ -6,12 +12,37 @@ pub trait Cursor {
     fn set_index(&mut self, index: usize);
 
     /// Increments the index by the given amount.
+    ///
+    /// The index saturates at `usize::MAX` instead of wrapping around.
+    /// Use [Cursor::checked_increment_by] to get an error instead.
     #[inline(always)]
     fn increment_by(&mut self, count: usize) {
-        self.set_index(self.get_index() + count);
+        self.set_index(self.get_index().saturating_add(count));
+    }
+
+    /// Same as [Cursor::increment_by], but returns an [Error::OffsetOverflow] without moving the index
+    /// if the index would overflow.
+    #[inline(always)]
+    fn checked_increment_by(&mut self, count: usize) -> Result<(), Error> {
+        let index = self.get_index();
+        let new_index = index.checked_add(count).ok_or(Error::OffsetOverflow {
+            offset: index,
+            size: count,
+        })?;
+
+        self.set_index(new_index);
+        Ok(())
+    }
+
+    /// Moves the index back to the start.
+    #[inline(always)]
+    fn rewind(&mut self) {
+        self.set_index(0);
     }
 
     /// Returns the current index and replaces it with the provided size.
+    ///
+    /// The index saturates at `usize::MAX` instead of wrapping around.
     #[inline(always)]
     fn swap_incremented_index(&mut self, size: usize) -> usize {
         let index = self.get_index();
@@ -26,6 +57,117 @@ pub trait Cursor {
         let size = size_of::<T>();
         self.swap_incremented_index(size)
     }
+
+    /// Saves the current index and returns a guard that restores it when dropped.
+    ///
+    /// This is useful for speculative parsing, since early returns restore the index automatically.
+    /// Call [Checkpoint::commit] to keep the new index instead.
+    #[inline(always)]
+    fn checkpoint(&mut self) -> Checkpoint<'_, Self> {
+        Checkpoint::new(self)
+    }
+
+    /// Runs a closure with the index temporarily set to the provided index,
+    /// then restores the previous index.
+    #[inline(always)]
+    fn with_position<R, F: FnOnce(&mut Self) -> R>(&mut self, index: usize, f: F) -> R {
+        let mut checkpoint = self.checkpoint();
+        checkpoint.set_index(index);
+        f(&mut checkpoint)
+    }
+}
+
+impl<T: Cursor + ?Sized> Cursor for &mut T {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        (**self).get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        (**self).set_index(index)
+    }
+
+    #[inline(always)]
+    fn checked_increment_by(&mut self, count: usize) -> Result<(), Error> {
+        (**self).checked_increment_by(count)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Cursor + ?Sized> Cursor for Box<T> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        (**self).get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        (**self).set_index(index)
+    }
+
+    #[inline(always)]
+    fn checked_increment_by(&mut self, count: usize) -> Result<(), Error> {
+        (**self).checked_increment_by(count)
+    }
+}
+
+/// A guard that restores the index of a [Cursor] when dropped, unless [Checkpoint::commit] is called.
+///
+/// The cursor can be used through the guard while the checkpoint is alive.
+pub struct Checkpoint<'a, T: Cursor + ?Sized> {
+    cursor: &'a mut T,
+    index: usize,
+    committed: bool,
+}
+
+impl<'a, T: Cursor + ?Sized> Checkpoint<'a, T> {
+    #[inline(always)]
+    fn new(cursor: &'a mut T) -> Self {
+        let index = cursor.get_index();
+        Self {
+            cursor,
+            index,
+            committed: false,
+        }
+    }
+
+    /// Returns the index that will be restored when the checkpoint is dropped.
+    #[inline(always)]
+    pub fn saved_index(&self) -> usize {
+        self.index
+    }
+
+    /// Keeps the current index instead of restoring the saved index.
+    #[inline(always)]
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+
+impl<'a, T: Cursor + ?Sized> Deref for Checkpoint<'a, T> {
+    type Target = T;
+
+    #[inline(always)]
+    fn deref(&self) -> &T {
+        self.cursor
+    }
+}
+
+impl<'a, T: Cursor + ?Sized> DerefMut for Checkpoint<'a, T> {
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut T {
+        self.cursor
+    }
+}
+
+impl<'a, T: Cursor + ?Sized> Drop for Checkpoint<'a, T> {
+    #[inline(always)]
+    fn drop(&mut self) {
+        if !self.committed {
+            self.cursor.set_index(self.index);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -83,4 +225,123 @@ mod test {
         assert_eq!(previous_index, 3);
         assert_eq!(current_index, 7);
     }
+
+    #[test]
+    fn should_saturate_instead_of_wrapping() {
+        let mut cursor = MockCursor::new(usize::MAX - 1);
+        cursor.increment_by(4);
+        assert_eq!(cursor.get_index(), usize::MAX);
+
+        let previous_index = cursor.swap_incremented_index(4);
+        assert_eq!(previous_index, usize::MAX);
+        assert_eq!(cursor.get_index(), usize::MAX);
+    }
+
+    #[test]
+    fn should_checked_increment_by() {
+        let mut cursor = MockCursor::new(usize::MAX - 4);
+        cursor
+            .checked_increment_by(4)
+            .expect("Increment should have succeeded");
+        assert_eq!(cursor.get_index(), usize::MAX);
+    }
+
+    #[test]
+    fn should_return_error_if_checked_increment_overflows() {
+        let mut cursor = MockCursor::new(usize::MAX - 1);
+        let error = cursor
+            .checked_increment_by(2)
+            .expect_err("Increment should have overflowed");
+
+        assert_eq!(
+            error,
+            Error::OffsetOverflow {
+                offset: usize::MAX - 1,
+                size: 2,
+            }
+        );
+        assert_eq!(cursor.get_index(), usize::MAX - 1);
+    }
+
+    #[test]
+    fn should_restore_the_index_when_a_checkpoint_is_dropped() {
+        let mut cursor = MockCursor::new(3);
+        {
+            let mut checkpoint = cursor.checkpoint();
+            checkpoint.increment_by(4);
+            assert_eq!(checkpoint.get_index(), 7);
+            assert_eq!(checkpoint.saved_index(), 3);
+        }
+        assert_eq!(cursor.get_index(), 3);
+    }
+
+    #[test]
+    fn should_keep_the_index_when_a_checkpoint_is_committed() {
+        let mut cursor = MockCursor::new(3);
+        let mut checkpoint = cursor.checkpoint();
+        checkpoint.increment_by(4);
+        checkpoint.commit();
+
+        assert_eq!(cursor.get_index(), 7);
+    }
+
+    #[test]
+    fn should_restore_the_index_on_early_return() {
+        fn parse(cursor: &mut MockCursor, succeed: bool) -> Option<usize> {
+            let mut checkpoint = cursor.checkpoint();
+            checkpoint.increment_by(2);
+            if !succeed {
+                return None;
+            }
+            let index = checkpoint.get_index();
+            checkpoint.commit();
+            Some(index)
+        }
+
+        let mut cursor = MockCursor::new(3);
+        assert_eq!(parse(&mut cursor, false), None);
+        assert_eq!(cursor.get_index(), 3);
+        assert_eq!(parse(&mut cursor, true), Some(5));
+        assert_eq!(cursor.get_index(), 5);
+    }
+
+    #[test]
+    fn should_restore_nested_checkpoints() {
+        let mut cursor = MockCursor::new(0);
+        {
+            let mut outer = cursor.checkpoint();
+            outer.increment_by(2);
+            {
+                let mut inner = outer.checkpoint();
+                inner.increment_by(3);
+                assert_eq!(inner.get_index(), 5);
+            }
+            assert_eq!(outer.get_index(), 2);
+
+            let mut inner = outer.checkpoint();
+            inner.increment_by(3);
+            inner.commit();
+            assert_eq!(outer.get_index(), 5);
+        }
+        assert_eq!(cursor.get_index(), 0);
+    }
+
+    #[test]
+    fn should_run_with_position() {
+        let mut cursor = MockCursor::new(3);
+        let index = cursor.with_position(10, |cursor| {
+            cursor.increment_by(2);
+            cursor.get_index()
+        });
+
+        assert_eq!(index, 12);
+        assert_eq!(cursor.get_index(), 3);
+    }
+
+    #[test]
+    fn should_rewind_to_the_start() {
+        let mut cursor = MockCursor::new(5);
+        cursor.rewind();
+        assert_eq!(cursor.get_index(), 0);
+    }
 }
@@ -1,7 +1,34 @@
-use crate::{EndianRead, StreamReader};
-use core::marker::PhantomData;
+use crate::{
+    EndianRead, Error, Reader, StaticSize, StreamContainer, StreamReader, StreamRemaining,
+};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{iter::FusedIterator, marker::PhantomData};
+
+/// Returns an iterator for the little endian representation of an [EndianRead] type from the start of a [Reader].
+///
+/// To iterate from somewhere other than the start, use [StreamReader::into_le_iter] on a [StreamContainer] instead.
+#[inline(always)]
+pub fn le_iter<Item: EndianRead + StaticSize, R: Reader>(
+    reader: R,
+) -> LeIter<Item, StreamContainer<R>> {
+    LeIter::new(StreamContainer::new(reader))
+}
+
+/// Same as [le_iter], but for the big endian representation.
+#[inline(always)]
+pub fn be_iter<Item: EndianRead + StaticSize, R: Reader>(
+    reader: R,
+) -> BeIter<Item, StreamContainer<R>> {
+    BeIter::new(StreamContainer::new(reader))
+}
 
 /// An iterator for the little endian representation of an [EndianRead] type from a [StreamReader].
+///
+/// Items must implement [StaticSize], so the iterator knows exactly how many items are left
+/// and can implement [ExactSizeIterator].
+/// Zero sized items never run out, so they report `usize::MAX` items.
+/// Use [TryLeIter] to iterate types whose size depends on their value.
 pub struct LeIter<Item: EndianRead, Stream: StreamReader> {
     data: PhantomData<Item>,
     stream: Stream,
@@ -14,17 +41,49 @@ impl<Item: EndianRead, Stream: StreamReader> LeIter<Item, Stream> {
             stream,
         }
     }
+
+    /// Returns an iterator that borrows the stream, so the stream can be used again after iterating.
+    #[inline(always)]
+    pub fn by_ref(stream: &mut Stream) -> LeIter<Item, &mut Stream> {
+        LeIter::new(stream)
+    }
+
+    /// Returns the index of the stream, which is where the next item will be read from.
+    #[inline(always)]
+    pub fn get_index(&self) -> usize {
+        self.stream.get_index()
+    }
+
+    /// Consumes the iterator and returns the stream, with its index where iteration stopped.
+    #[inline(always)]
+    pub fn into_inner(self) -> Stream {
+        self.stream
+    }
 }
 
-impl<Item: EndianRead, Stream: StreamReader> Iterator for LeIter<Item, Stream> {
+impl<Item: EndianRead + StaticSize, Stream: StreamReader> Iterator for LeIter<Item, Stream> {
     type Item = Item;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.stream.read_stream_le().ok()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        static_size_hint::<Item, Stream>(&self.stream)
+    }
+}
+
+impl<Item: EndianRead + StaticSize, Stream: StreamReader> ExactSizeIterator
+    for LeIter<Item, Stream>
+{
 }
 
+impl<Item: EndianRead + StaticSize, Stream: StreamReader> FusedIterator for LeIter<Item, Stream> {}
+
 /// An iterator for the big endian representation of an [EndianRead] type from a [StreamReader].
+///
+/// Same as [LeIter], items must implement [StaticSize].
+/// Use [TryBeIter] to iterate types whose size depends on their value.
 pub struct BeIter<Item: EndianRead, Stream: StreamReader> {
     data: PhantomData<Item>,
     stream: Stream,
@@ -37,25 +96,306 @@ impl<Item: EndianRead, Stream: StreamReader> BeIter<Item, Stream> {
             stream,
         }
     }
+
+    /// Returns an iterator that borrows the stream, so the stream can be used again after iterating.
+    #[inline(always)]
+    pub fn by_ref(stream: &mut Stream) -> BeIter<Item, &mut Stream> {
+        BeIter::new(stream)
+    }
+
+    /// Returns the index of the stream, which is where the next item will be read from.
+    #[inline(always)]
+    pub fn get_index(&self) -> usize {
+        self.stream.get_index()
+    }
+
+    /// Consumes the iterator and returns the stream, with its index where iteration stopped.
+    #[inline(always)]
+    pub fn into_inner(self) -> Stream {
+        self.stream
+    }
 }
 
-impl<Item: EndianRead, Stream: StreamReader> Iterator for BeIter<Item, Stream> {
+impl<Item: EndianRead + StaticSize, Stream: StreamReader> Iterator for BeIter<Item, Stream> {
     type Item = Item;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.stream.read_stream_be().ok()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        static_size_hint::<Item, Stream>(&self.stream)
+    }
+}
+
+impl<Item: EndianRead + StaticSize, Stream: StreamReader> ExactSizeIterator
+    for BeIter<Item, Stream>
+{
+}
+
+impl<Item: EndianRead + StaticSize, Stream: StreamReader> FusedIterator for BeIter<Item, Stream> {}
+
+/// A fallible iterator for the little endian representation of an [EndianRead] type from a [StreamReader].
+///
+/// Unlike [LeIter], errors are returned instead of ending the iteration.
+/// Iteration ends when the stream is at the end of the data.
+/// If a read fails before then, the error is returned once and the iterator returns `None` afterwards.
+/// A truncated value at the end of the data is an [Error::InvalidSize],
+/// so reaching the end of the data is never an [Error::EndOfData].
+pub struct TryLeIter<Item: EndianRead, Stream: StreamReader> {
+    data: PhantomData<Item>,
+    stream: Stream,
+    failed: bool,
+}
+
+impl<Item: EndianRead, Stream: StreamReader> TryLeIter<Item, Stream> {
+    pub fn new(stream: Stream) -> Self {
+        Self {
+            data: PhantomData,
+            stream,
+            failed: false,
+        }
+    }
+
+    /// Returns an iterator that borrows the stream, so the stream can be used again after iterating.
+    #[inline(always)]
+    pub fn by_ref(stream: &mut Stream) -> TryLeIter<Item, &mut Stream> {
+        TryLeIter::new(stream)
+    }
+
+    /// Returns the index of the stream, which is where the next item will be read from.
+    #[inline(always)]
+    pub fn get_index(&self) -> usize {
+        self.stream.get_index()
+    }
+
+    /// Consumes the iterator and returns the stream, with its index where iteration stopped.
+    #[inline(always)]
+    pub fn into_inner(self) -> Stream {
+        self.stream
+    }
+}
+
+impl<Item: EndianRead, Stream: StreamReader> Iterator for TryLeIter<Item, Stream> {
+    type Item = Result<Item, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.stream.is_at_end() {
+            return None;
+        }
+
+        let result = self.stream.read_stream_le();
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+impl<Item: EndianRead, Stream: StreamReader> FusedIterator for TryLeIter<Item, Stream> {}
+
+/// Same as [TryLeIter], but for the big endian representation.
+pub struct TryBeIter<Item: EndianRead, Stream: StreamReader> {
+    data: PhantomData<Item>,
+    stream: Stream,
+    failed: bool,
+}
+
+impl<Item: EndianRead, Stream: StreamReader> TryBeIter<Item, Stream> {
+    pub fn new(stream: Stream) -> Self {
+        Self {
+            data: PhantomData,
+            stream,
+            failed: false,
+        }
+    }
+
+    /// Returns an iterator that borrows the stream, so the stream can be used again after iterating.
+    #[inline(always)]
+    pub fn by_ref(stream: &mut Stream) -> TryBeIter<Item, &mut Stream> {
+        TryBeIter::new(stream)
+    }
+
+    /// Returns the index of the stream, which is where the next item will be read from.
+    #[inline(always)]
+    pub fn get_index(&self) -> usize {
+        self.stream.get_index()
+    }
+
+    /// Consumes the iterator and returns the stream, with its index where iteration stopped.
+    #[inline(always)]
+    pub fn into_inner(self) -> Stream {
+        self.stream
+    }
+}
+
+impl<Item: EndianRead, Stream: StreamReader> Iterator for TryBeIter<Item, Stream> {
+    type Item = Result<Item, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed || self.stream.is_at_end() {
+            return None;
+        }
+
+        let result = self.stream.read_stream_be();
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+impl<Item: EndianRead, Stream: StreamReader> FusedIterator for TryBeIter<Item, Stream> {}
+
+/// An iterator over the bytes of a [StreamReader] in chunks of a fixed length,
+/// which copies each chunk into a vector.
+///
+/// The index is moved past each chunk as it's read.
+/// The last chunk is shorter if the remaining bytes don't divide evenly.
+#[cfg(feature = "alloc")]
+pub struct VecChunks<Stream: StreamReader> {
+    stream: Stream,
+    chunk_len: usize,
+}
+
+#[cfg(feature = "alloc")]
+impl<Stream: StreamReader> VecChunks<Stream> {
+    /// Panics if `chunk_len` is 0.
+    pub fn new(stream: Stream, chunk_len: usize) -> Self {
+        assert!(chunk_len != 0, "Chunk length must not be 0");
+        Self { stream, chunk_len }
+    }
+
+    /// Consumes the iterator and returns the stream, with its index where iteration stopped.
+    #[inline(always)]
+    pub fn into_inner(self) -> Stream {
+        self.stream
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Stream: StreamReader> Iterator for VecChunks<Stream> {
+    type Item = Vec<u8>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining = self.stream.remaining();
+
+        if remaining == 0 {
+            return None;
+        }
+
+        let len = core::cmp::min(remaining, self.chunk_len);
+        self.stream.read_byte_stream(len).ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.stream.remaining().div_ceil(self.chunk_len);
+        (chunks, Some(chunks))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<Stream: StreamReader> ExactSizeIterator for VecChunks<Stream> {}
+
+#[cfg(feature = "alloc")]
+impl<Stream: StreamReader> FusedIterator for VecChunks<Stream> {}
+
+/// Returns the exact number of items left.
+///
+/// Zero sized items never run out, so they report `usize::MAX` items instead of dividing by zero.
+#[inline(always)]
+fn static_size_hint<Item: StaticSize, Stream: StreamReader>(
+    stream: &Stream,
+) -> (usize, Option<usize>) {
+    let items = match Item::SIZE {
+        0 => usize::MAX,
+        size => stream.remaining() / size,
+    };
+
+    (items, Some(items))
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::StreamContainer;
-    use alloc::vec::Vec;
+    use crate::{Cursor, ReadOutput, StreamContainer};
+    #[cfg(feature = "alloc")]
+    use alloc::{vec, vec::Vec};
+
+    /// A byte that can't be `0xff`.
+    #[derive(Debug, PartialEq)]
+    struct Marker(u8);
+
+    impl EndianRead for Marker {
+        fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            let value = u8::try_read_le(bytes)?.into_data();
+
+            if value == 0xff {
+                return Err(Error::UnexpectedByte {
+                    offset: 0,
+                    expected: 0,
+                    found: value,
+                });
+            }
+
+            Ok(ReadOutput::new(Marker(value), 1))
+        }
+
+        fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            Self::try_read_le(bytes)
+        }
+    }
+
+    /// A pair of bytes with a hand written [StaticSize] impl.
+    #[derive(Debug, PartialEq)]
+    struct Pair(u8, u8);
+
+    impl EndianRead for Pair {
+        fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            let value: [u8; 2] = <[u8; 2]>::try_read_le(bytes)?.into_data();
+            Ok(ReadOutput::new(Pair(value[0], value[1]), 2))
+        }
+
+        fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            Self::try_read_le(bytes)
+        }
+    }
+
+    impl StaticSize for Pair {
+        const SIZE: usize = 2;
+    }
+
+    /// A value that doesn't take any bytes.
+    struct Empty;
+
+    impl EndianRead for Empty {
+        fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            Ok(ReadOutput::new(Empty, 0))
+        }
+
+        fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            Self::try_read_le(bytes)
+        }
+    }
+
+    impl StaticSize for Empty {
+        const SIZE: usize = 0;
+    }
+
+    /// A value whose size depends on its data.
+    struct Dynamic;
+
+    impl EndianRead for Dynamic {
+        fn try_read_le(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            let len = u8::try_read_le(bytes)?.into_data() as usize;
+            Ok(ReadOutput::new(Dynamic, len + 1))
+        }
+
+        fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+            Self::try_read_le(bytes)
+        }
+    }
 
     mod le_iter {
         use super::*;
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_iterate() {
             let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
@@ -63,11 +403,110 @@ mod test {
             let result: Vec<u32> = LeIter::new(stream).collect();
             assert_eq!(result, [0xddccbbaa, 0x44332211])
         }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_iterate_a_reader() {
+            let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+            let result: Vec<u16> = le_iter(bytes.as_slice()).collect();
+            assert_eq!(result, [0xbbaa, 0xddcc, 0x2211, 0x4433])
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_iterate_records_after_a_header() {
+            let bytes: [u8; 8] = [0x02, 0x00, 0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22];
+            let mut stream = StreamContainer::new(bytes);
+            let count: u16 = stream.read_stream_le().expect("Read should have succeeded");
+            let records: Vec<u16> = stream.into_le_iter().take(count as usize).collect();
+
+            assert_eq!(records, [0xbbaa, 0xddcc]);
+        }
+        #[test]
+        fn should_return_the_exact_size_hint() {
+            let bytes: [u8; 7] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33];
+            let mut iter = le_iter::<u16, _>(bytes);
+            assert_eq!(iter.size_hint(), (3, Some(3)));
+            assert_eq!(iter.len(), 3);
+
+            iter.next();
+            assert_eq!(iter.len(), 2);
+            assert_eq!(le_iter::<u32, _>(bytes).size_hint(), (1, Some(1)));
+        }
+
+        #[test]
+        fn should_return_the_len_from_a_hand_written_static_size() {
+            let bytes: [u8; 5] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11];
+            let mut iter = le_iter::<Pair, _>(bytes);
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            assert_eq!(iter.len(), 2);
+
+            assert_eq!(iter.next(), Some(Pair(0xaa, 0xbb)));
+            assert_eq!(iter.len(), 1);
+        }
+
+        #[test]
+        fn should_return_the_max_len_for_zero_sized_items() {
+            let bytes = [0u8; 4];
+            let iter = le_iter::<Empty, _>(bytes);
+            assert_eq!(iter.size_hint(), (usize::MAX, Some(usize::MAX)));
+            assert_eq!(iter.len(), usize::MAX);
+        }
+
+        #[test]
+        fn should_be_fused() {
+            let bytes: [u8; 3] = [0xaa, 0xbb, 0xcc];
+            let mut iter = le_iter::<u16, _>(bytes);
+
+            assert_eq!(iter.next(), Some(0xbbaa));
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.len(), 0);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_continue_reading_after_a_borrowed_iterator() {
+            let bytes: [u8; 10] = [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, 0x11, 0x22, 0x33, 0x44];
+            let mut stream = StreamContainer::new(bytes);
+
+            let mut iter = LeIter::<u16, _>::by_ref(&mut stream);
+            let records: Vec<u16> = iter.by_ref().take(3).collect();
+            assert_eq!(records, [0xbbaa, 0xddcc, 0xffee]);
+            assert_eq!(iter.get_index(), 6);
+
+            let trailer: u32 = stream.read_stream_le().expect("Read should have succeeded");
+            assert_eq!(trailer, 0x44332211);
+        }
+
+        #[test]
+        fn should_return_the_stream() {
+            let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+            let mut iter: LeIter<u16, _> = le_iter(bytes);
+            iter.next();
+
+            let mut stream = iter.into_inner();
+            assert_eq!(stream.get_index(), 2);
+
+            let value: u32 = stream.read_stream_le().expect("Read should have succeeded");
+            assert_eq!(value, 0x2211ddcc);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_preallocate_when_collecting() {
+            let bytes = [0u8; 41];
+            let result: Vec<u16> = le_iter(bytes).collect();
+
+            assert_eq!(result.len(), 20);
+            assert_eq!(result.capacity(), 20);
+        }
     }
 
     mod be_iter {
         use super::*;
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_iterate() {
             let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
@@ -75,5 +514,250 @@ mod test {
             let result: Vec<u32> = BeIter::new(stream).collect();
             assert_eq!(result, [0xaabbccdd, 0x11223344])
         }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_iterate_a_reader() {
+            let bytes: [u8; 8] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33, 0x44];
+            let result: Vec<u16> = be_iter(bytes).collect();
+            assert_eq!(result, [0xaabb, 0xccdd, 0x1122, 0x3344])
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_iterate_records_after_a_header() {
+            let bytes: [u8; 8] = [0x00, 0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22];
+            let mut stream = StreamContainer::new(bytes);
+            let count: u16 = stream.read_stream_be().expect("Read should have succeeded");
+            let records: Vec<u16> = stream.into_be_iter().take(count as usize).collect();
+
+            assert_eq!(records, [0xaabb, 0xccdd]);
+        }
+        #[test]
+        fn should_return_the_exact_size_hint() {
+            let bytes: [u8; 7] = [0xaa, 0xbb, 0xcc, 0xdd, 0x11, 0x22, 0x33];
+            let mut stream = StreamContainer::new(bytes);
+            stream.set_index(2);
+            let iter: BeIter<u16, _> = BeIter::new(stream);
+
+            assert_eq!(iter.size_hint(), (2, Some(2)));
+            assert_eq!(iter.len(), 2);
+        }
+
+        #[test]
+        fn should_return_the_len_from_a_hand_written_static_size() {
+            let bytes: [u8; 4] = [0xaa, 0xbb, 0xcc, 0xdd];
+            let iter = be_iter::<Pair, _>(bytes);
+
+            assert_eq!(iter.len(), 2);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_continue_reading_after_a_borrowed_iterator() {
+            let bytes: [u8; 10] = [0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff, 0x11, 0x22, 0x33, 0x44];
+            let mut stream = StreamContainer::new(bytes);
+
+            let records: Vec<u16> = BeIter::by_ref(&mut stream).take(3).collect();
+            assert_eq!(records, [0xaabb, 0xccdd, 0xeeff]);
+
+            let trailer: u32 = stream.read_stream_be().expect("Read should have succeeded");
+            assert_eq!(trailer, 0x11223344);
+        }
+
+        #[test]
+        fn should_return_no_items_past_the_end() {
+            let mut stream = StreamContainer::new([0u8; 4]);
+            stream.set_index(6);
+            let iter: BeIter<u32, _> = BeIter::new(stream);
+
+            assert_eq!(iter.len(), 0);
+        }
+    }
+    mod try_le_iter {
+        use super::*;
+
+        #[test]
+        fn should_iterate_types_without_a_static_size() {
+            let stream = StreamContainer::new([1u8, 0xaa, 0, 2, 0xbb, 0xcc]);
+            let iter = stream.into_try_le_iter::<Dynamic>();
+
+            assert_eq!(iter.filter(Result::is_ok).count(), 3);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_iterate_to_the_end() {
+            let stream = StreamContainer::new([0xaau8, 0xbb, 0xcc, 0xdd]);
+            let result: Result<Vec<u16>, Error> = stream.into_try_le_iter().collect();
+            assert_eq!(result, Ok(vec![0xbbaa, 0xddcc]));
+        }
+
+        #[test]
+        fn should_return_the_error_once() {
+            let stream = StreamContainer::new([0x01u8, 0x02, 0xff, 0x03]);
+            let mut iter = stream.into_try_le_iter::<Marker>();
+
+            assert_eq!(iter.next(), Some(Ok(Marker(0x01))));
+            assert_eq!(iter.next(), Some(Ok(Marker(0x02))));
+            assert_eq!(
+                iter.next(),
+                Some(Err(Error::UnexpectedByte {
+                    offset: 2,
+                    expected: 0,
+                    found: 0xff,
+                }))
+            );
+            assert_eq!(iter.next(), None);
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_return_error_for_a_truncated_value() {
+            let stream = StreamContainer::new([0xaau8, 0xbb, 0xcc]);
+            let mut iter = stream.into_try_le_iter::<u16>();
+
+            assert_eq!(iter.next(), Some(Ok(0xbbaa)));
+            assert_eq!(
+                iter.next(),
+                Some(Err(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 2,
+                    data_len: 3,
+                }))
+            );
+            assert_eq!(iter.next(), None);
+        }
+
+        #[test]
+        fn should_continue_reading_after_an_error() {
+            let mut stream = StreamContainer::new([0x01u8, 0xff, 0x03, 0x04]);
+            let mut iter = TryLeIter::<Marker, _>::by_ref(&mut stream);
+
+            assert_eq!(iter.next(), Some(Ok(Marker(0x01))));
+            assert!(matches!(iter.next(), Some(Err(_))));
+            assert_eq!(iter.get_index(), 1);
+
+            stream.set_index(2);
+            let value: u16 = stream.read_stream_le().expect("Read should have succeeded");
+            assert_eq!(value, 0x0403);
+        }
+
+        #[test]
+        fn should_not_iterate_past_the_end() {
+            let mut stream = StreamContainer::new([0xaau8, 0xbb]);
+            stream.set_index(4);
+            let mut iter = stream.into_try_le_iter::<u16>();
+
+            assert_eq!(iter.next(), None);
+        }
+    }
+
+    mod try_be_iter {
+        use super::*;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_iterate_to_the_end() {
+            let stream = StreamContainer::new([0xaau8, 0xbb, 0xcc, 0xdd]);
+            let result: Result<Vec<u16>, Error> = stream.into_try_be_iter().collect();
+            assert_eq!(result, Ok(vec![0xaabb, 0xccdd]));
+        }
+
+        #[test]
+        fn should_return_the_error_once() {
+            let mut stream = StreamContainer::new([0x01u8, 0x02, 0x03, 0xff]);
+            stream.set_index(1);
+            let mut iter = stream.into_try_be_iter::<Marker>();
+
+            assert_eq!(iter.next(), Some(Ok(Marker(0x02))));
+            assert_eq!(iter.next(), Some(Ok(Marker(0x03))));
+            assert_eq!(
+                iter.next(),
+                Some(Err(Error::UnexpectedByte {
+                    offset: 3,
+                    expected: 0,
+                    found: 0xff,
+                }))
+            );
+            assert_eq!(iter.next(), None);
+        }
+    }
+    mod chunks {
+        use super::*;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_return_evenly_divided_chunks() {
+            let mut stream = StreamContainer::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+            stream.set_index(2);
+
+            let chunks: Vec<&[u8]> = stream.chunks(3).collect();
+            assert_eq!(chunks, [&[3, 4, 5][..], &[6, 7, 8][..]]);
+            assert_eq!(stream.get_index(), 8);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_return_a_short_last_chunk() {
+            let mut stream = StreamContainer::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+
+            let chunks: Vec<&[u8]> = stream.chunks(3).collect();
+            assert_eq!(chunks, [&[1, 2, 3][..], &[4, 5, 6][..], &[7, 8][..]]);
+        }
+
+        #[test]
+        fn should_return_nothing_past_the_end() {
+            let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+            stream.set_index(6);
+
+            assert_eq!(stream.chunks(2).count(), 0);
+            assert_eq!(stream.get_index(), 6);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod chunks_vec {
+        use super::*;
+
+        #[test]
+        fn should_return_evenly_divided_chunks() {
+            let mut stream = StreamContainer::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+            stream.set_index(2);
+
+            let chunks: Vec<Vec<u8>> = stream.chunks_vec(3).collect();
+            assert_eq!(chunks, [vec![3, 4, 5], vec![6, 7, 8]]);
+            assert_eq!(stream.get_index(), 8);
+        }
+
+        #[test]
+        fn should_move_the_index_as_chunks_are_read() {
+            let mut stream = StreamContainer::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+            let mut chunks = stream.chunks_vec(3);
+
+            assert_eq!(chunks.len(), 3);
+            assert_eq!(chunks.next(), Some(vec![1, 2, 3]));
+            assert_eq!(chunks.next(), Some(vec![4, 5, 6]));
+            assert_eq!(chunks.next(), Some(vec![7, 8]));
+            assert_eq!(chunks.next(), None);
+        }
+
+        #[test]
+        fn should_stop_after_a_partial_read() {
+            let mut stream = StreamContainer::new([1u8, 2, 3, 4, 5, 6, 7, 8]);
+            let first = stream.chunks_vec(4).next();
+
+            assert_eq!(first, Some(vec![1, 2, 3, 4]));
+            assert_eq!(stream.get_index(), 4);
+        }
+
+        #[test]
+        fn should_return_nothing_if_empty() {
+            let stream = StreamContainer::new([0u8; 0]);
+            let mut chunks = VecChunks::new(stream, 2);
+
+            assert_eq!(chunks.len(), 0);
+            assert_eq!(chunks.next(), None);
+        }
     }
 }
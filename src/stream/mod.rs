@@ -1,3 +1,6 @@
+mod bounded_cursor;
+pub use bounded_cursor::*;
+
 mod container;
 pub use container::*;
 
@@ -10,5 +13,14 @@ pub use iter::*;
 mod reader;
 pub use reader::*;
 
+mod remaining;
+pub use remaining::*;
+
+mod seek;
+pub use seek::*;
+
+mod write_container;
+pub use write_container::*;
+
 mod writer;
 pub use writer::*;
@@ -1,161 +1,697 @@
+#[cfg(feature = "alloc")]
+use super::iter::VecChunks;
 use super::{
     cursor::Cursor,
-    iter::{BeIter, LeIter},
+    iter::{BeIter, LeIter, TryBeIter, TryLeIter},
+    writer::StreamWriter,
 };
-use crate::{EndianRead, Reader, ReaderResult};
+use crate::{EndianRead, Error, ReadOutput, Reader, ReaderExt, ReaderResult, StaticSize};
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use alloc::{string::String, vec::Vec};
 use safe_transmute::TriviallyTransmutable;
 
 /// An interface to read values as a stream.
+///
+/// Methods that return a `Vec` or `String` need the `alloc` feature:
+/// `read_remaining_vec`, `read_stream_until`, `read_stream_until_or_end`, `read_stream_c_string`,
+/// `read_byte_stream`, `default_read_byte_stream`, the `read_stream_count_vec_*` methods,
+/// the `read_stream_nt_utf16_*_string` methods, and `chunks_vec`.
+/// Everything else works without allocating.
+///
+/// Reads of values and bytes return [Error::EndOfData] if the index is already at or past the end of the data,
+/// which is expected after the last record.  If there is data left, but not enough of it, [Error::InvalidSize]
+/// is returned instead, since the data is inconsistent.
 pub trait StreamReader: Reader + Cursor + Sized {
-    /// Same as [Reader::read], but uses the current stream instead of an offset.
+    /// Same as [ReaderExt::read], but uses the current stream instead of an offset.
+    /// The index is only moved if the read succeeds.
     #[inline(always)]
     fn read_stream<T: TriviallyTransmutable + Default>(&mut self) -> ReaderResult<T> {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.read(index)
+        let index = self.get_index();
+        let value = end_of_data_context(self.read(index), index, self.get_slice().len())?;
+        self.increment_by(core::mem::size_of::<T>());
+        Ok(value)
     }
 
     /// Same as [StreamReader::read_stream], but returns a default value if the read is invalid.
+    /// The index is only moved if the read succeeds.
     #[inline(always)]
     fn default_read_stream<T: TriviallyTransmutable + Default>(&mut self) -> T {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.default_read(index)
+        self.read_stream().unwrap_or_default()
     }
 
-    /// Same as [Reader::read_le], but uses the current stream instead of an offset.
+    /// Same as [ReaderExt::read_le], but uses the current stream instead of an offset.
     #[inline(always)]
     fn read_stream_le<T: EndianRead>(&mut self) -> ReaderResult<T> {
         let index = self.get_index();
-        let read_value = self.read_le_with_output(index)?;
+        let read_value = end_of_data_context(
+            self.read_le_with_output(index),
+            index,
+            self.get_slice().len(),
+        )?;
         self.increment_by(read_value.get_read_bytes());
         Ok(read_value.into_data())
     }
 
     /// Same as [StreamReader::read_stream_le], but returns a default value if the read is invalid.
+    ///
+    /// Like [StreamReader::read_stream_le], the index is moved by the number of bytes read,
+    /// and is not moved if the read is invalid.
     #[inline(always)]
     fn default_read_stream_le<T: EndianRead + Default>(&mut self) -> T {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.default_read_le(index)
+        self.read_stream_le().unwrap_or_default()
     }
 
-    /// Same as [Reader::read_array_le], but uses the current stream instead of an offset.
+    /// Same as [ReaderExt::read_array_le], but uses the current stream instead of an offset.
     ///
-    /// The index is only incremented on a successful read.
+    /// The index is incremented by the bytes read for each element as it is read,
+    /// so on error the index is left at the element that failed, which is also the offset in the error.
     #[inline(always)]
     fn read_array_stream_le<const SIZE: usize, T: EndianRead>(
         &mut self,
     ) -> ReaderResult<[T; SIZE]> {
-        let index = self.get_index();
-        let mut read_bytes = 0;
-
         let mut data: [Option<T>; SIZE] = core::array::from_fn(|_| None);
+        let start = self.get_index();
+        let data_len = self.get_slice().len();
 
         for elem in &mut data {
-            let read_output = self.read_le_with_output::<T>(index + read_bytes)?;
-            read_bytes += read_output.get_read_bytes();
+            let index = self.get_index();
+            let read_output = self.read_le_with_output::<T>(index);
+            let read_output = if index == start {
+                end_of_data_context(read_output, index, data_len)?
+            } else {
+                read_output?
+            };
+            self.increment_by(read_output.get_read_bytes());
             *elem = Some(read_output.into_data());
         }
 
-        self.increment_by(read_bytes);
-
         // Safety
         // [T]::map has a hard time optimizing, Option::unwrap_unchecked here can help
         // get rid of panic checks since we know all elements are initialized
         Ok(data.map(|elem| unsafe { elem.unwrap_unchecked() }))
     }
 
-    /// Same as [StreamReader::read_stream_le], but returns a default array if the read is invalid.
+    /// Same as [StreamReader::read_array_stream_le], but returns a default array if the read is invalid.
     ///
-    /// The returned value contains everything that could be read with the rest being the default.
+    /// The index is left unchanged if the read is invalid.
     #[inline(always)]
     fn default_read_array_stream_le<const SIZE: usize, T: EndianRead + Default>(
         &mut self,
     ) -> [T; SIZE] {
-        self.read_array_stream_le()
-            .unwrap_or(core::array::from_fn(|_| T::default()))
+        self.checked_read_array_stream_le()
+            .unwrap_or_else(|| core::array::from_fn(|_| T::default()))
+    }
+
+    /// Same as [StreamReader::read_array_stream_le], but returns `None` if the read is invalid.
+    ///
+    /// The index is left unchanged if the read is invalid.
+    #[inline(always)]
+    fn checked_read_array_stream_le<const SIZE: usize, T: EndianRead>(
+        &mut self,
+    ) -> Option<[T; SIZE]> {
+        let index = self.get_index();
+        let result = self.read_array_stream_le().ok();
+
+        if result.is_none() {
+            self.set_index(index);
+        }
+
+        result
     }
 
-    /// Same as [Reader::read_be], but uses the current stream instead of an offset.
+    /// Same as [ReaderExt::read_be], but uses the current stream instead of an offset.
     #[inline(always)]
     fn read_stream_be<T: EndianRead>(&mut self) -> ReaderResult<T> {
         let index = self.get_index();
-        let read_value = self.read_be_with_output(index)?;
+        let read_value = end_of_data_context(
+            self.read_be_with_output(index),
+            index,
+            self.get_slice().len(),
+        )?;
         self.increment_by(read_value.get_read_bytes());
         Ok(read_value.into_data())
     }
 
     /// Same as [StreamReader::read_stream_be], but returns a default value if the read is invalid.
+    ///
+    /// Like [StreamReader::read_stream_be], the index is moved by the number of bytes read,
+    /// and is not moved if the read is invalid.
     #[inline(always)]
     fn default_read_stream_be<T: EndianRead + Default>(&mut self) -> T {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.default_read_be(index)
+        self.read_stream_be().unwrap_or_default()
     }
 
-    /// Same as [Reader::read_array_be], but uses the current stream instead of an offset.
+    /// Same as [ReaderExt::read_array_be], but uses the current stream instead of an offset.
     ///
-    /// The index is only incremented on a successful read.
+    /// The index is incremented by the bytes read for each element as it is read,
+    /// so on error the index is left at the element that failed, which is also the offset in the error.
     #[inline(always)]
     fn read_array_stream_be<const SIZE: usize, T: EndianRead>(
         &mut self,
     ) -> ReaderResult<[T; SIZE]> {
-        let index = self.get_index();
-        let mut read_bytes = 0;
-
         let mut data: [Option<T>; SIZE] = core::array::from_fn(|_| None);
+        let start = self.get_index();
+        let data_len = self.get_slice().len();
 
         for elem in &mut data {
-            let read_output = self.read_be_with_output::<T>(index + read_bytes)?;
-            read_bytes += read_output.get_read_bytes();
+            let index = self.get_index();
+            let read_output = self.read_be_with_output::<T>(index);
+            let read_output = if index == start {
+                end_of_data_context(read_output, index, data_len)?
+            } else {
+                read_output?
+            };
+            self.increment_by(read_output.get_read_bytes());
             *elem = Some(read_output.into_data());
         }
 
-        self.increment_by(read_bytes);
-
         // Safety
         // [T]::map has a hard time optimizing, Option::unwrap_unchecked here can help
         // get rid of panic checks since we know all elements are initialized
         Ok(data.map(|elem| unsafe { elem.unwrap_unchecked() }))
     }
 
-    /// Same as [StreamReader::read_stream_be], but returns a default array if the read is invalid.
+    /// Same as [StreamReader::read_array_stream_be], but returns a default array if the read is invalid.
+    ///
+    /// The index is left unchanged if the read is invalid.
     #[inline(always)]
     fn default_read_array_stream_be<const SIZE: usize, T: EndianRead + Default>(
         &mut self,
     ) -> [T; SIZE] {
-        self.read_array_stream_be()
-            .unwrap_or(core::array::from_fn(|_| T::default()))
+        self.checked_read_array_stream_be()
+            .unwrap_or_else(|| core::array::from_fn(|_| T::default()))
+    }
+
+    /// Same as [StreamReader::read_array_stream_be], but returns `None` if the read is invalid.
+    ///
+    /// The index is left unchanged if the read is invalid.
+    #[inline(always)]
+    fn checked_read_array_stream_be<const SIZE: usize, T: EndianRead>(
+        &mut self,
+    ) -> Option<[T; SIZE]> {
+        let index = self.get_index();
+        let result = self.read_array_stream_be().ok();
+
+        if result.is_none() {
+            self.set_index(index);
+        }
+
+        result
+    }
+
+    /// Same as [ReaderExt::read_ne], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn read_stream_ne<T: EndianRead>(&mut self) -> ReaderResult<T> {
+        if cfg!(target_endian = "little") {
+            self.read_stream_le()
+        } else {
+            self.read_stream_be()
+        }
+    }
+
+    /// Moves the index forward by `size` bytes without reading them, such as to skip reserved fields.
+    ///
+    /// Errors without moving the index if fewer than `size` bytes remain.
+    #[inline(always)]
+    fn skip(&mut self, size: usize) -> ReaderResult<()> {
+        let index = self.get_index();
+        let data_len = self.get_slice().len();
+
+        if index > data_len || size > data_len - index {
+            return Err(Error::InvalidSize {
+                wanted_size: size,
+                offset: index,
+                data_len,
+            });
+        }
+
+        self.increment_by(size);
+        Ok(())
+    }
+
+    /// Moves the index forward to the next multiple of `align` and returns the number of bytes skipped.
+    ///
+    /// Errors without moving the index if `align` is not a power of two,
+    /// or if the aligned index would be past the end of the data.
+    #[inline(always)]
+    fn align_to(&mut self, align: usize) -> ReaderResult<usize> {
+        let size = read_alignment_padding(self.get_index(), align)?;
+        self.skip(size)?;
+        Ok(size)
+    }
+
+    /// Reads a little endian value at the next multiple of `align`, skipping the padding before it.
+    ///
+    /// The returned [ReadOutput] counts the padding as read bytes.
+    /// Errors without moving the index if `align` is not a power of two or the value can't be read.
+    #[inline(always)]
+    fn read_stream_le_padded<T: EndianRead>(
+        &mut self,
+        align: usize,
+    ) -> ReaderResult<ReadOutput<T>> {
+        let index = self.get_index();
+        self.align_to(align)?;
+        let value = self
+            .read_stream_le::<T>()
+            .inspect_err(|_| self.set_index(index))?;
+        Ok(ReadOutput::new(value, self.get_index() - index))
+    }
+
+    /// Same as [StreamReader::read_stream_le_padded], but reads a big endian value.
+    #[inline(always)]
+    fn read_stream_be_padded<T: EndianRead>(
+        &mut self,
+        align: usize,
+    ) -> ReaderResult<ReadOutput<T>> {
+        let index = self.get_index();
+        self.align_to(align)?;
+        let value = self
+            .read_stream_be::<T>()
+            .inspect_err(|_| self.set_index(index))?;
+        Ok(ReadOutput::new(value, self.get_index() - index))
+    }
+
+    /// Same as [Reader::expect_bytes], but uses the current stream instead of an offset.
+    ///
+    /// The index is moved past the bytes if they match, and isn't moved otherwise.
+    #[inline(always)]
+    fn expect_stream_bytes(&mut self, expected: &[u8]) -> ReaderResult<()> {
+        let index = self.get_index();
+        self.expect_bytes(index, expected)?;
+        self.increment_by(expected.len());
+        Ok(())
+    }
+
+    /// Same as [Reader::matches_bytes], but uses the current stream instead of an offset.
+    /// The index is never moved.
+    #[inline(always)]
+    fn peek_matches(&self, expected: &[u8]) -> bool {
+        self.matches_bytes(self.get_index(), expected)
+    }
+
+    /// Copies exactly `dst.len()` bytes from the current stream into `dst`.
+    ///
+    /// Unlike [StreamReader::read_byte_stream], this doesn't allocate.
+    /// The index is only incremented on a successful read.
+    #[inline(always)]
+    fn read_stream_exact(&mut self, dst: &mut [u8]) -> ReaderResult<()> {
+        let index = self.get_index();
+        let data_len = self.get_slice().len();
+        let bytes = end_of_data_context(self.get_slice_of_size(index, dst.len()), index, data_len)?;
+        dst.copy_from_slice(bytes);
+        self.increment_by(dst.len());
+        Ok(())
+    }
+
+    /// Same as [StreamReader::read_stream_exact], but returns the number of bytes copied.
+    /// Returns 0 and copies nothing if `dst` can't be filled.
+    #[inline(always)]
+    fn checked_read_stream_exact(&mut self, dst: &mut [u8]) -> usize {
+        match self.read_stream_exact(dst) {
+            Ok(()) => dst.len(),
+            Err(_) => 0,
+        }
+    }
+
+    /// Copies `len` bytes from the current stream to the current stream of `dst`,
+    /// and moves both indexes past the copied bytes.
+    ///
+    /// The bytes are written straight from the source without a temporary buffer.
+    /// Neither index is moved if the copy fails.
+    /// An [Error::CopySourceTooShort] is returned if the source doesn't have `len` bytes left,
+    /// and an [Error::CopyDestinationWriteFailed] is returned if `dst` can't be written.
+    #[inline(always)]
+    fn copy_to<W: StreamWriter>(&mut self, dst: &mut W, len: usize) -> ReaderResult<usize> {
+        let index = self.get_index();
+        let bytes =
+            Self::get_slice_of_size(self, index, len).map_err(|_| Error::CopySourceTooShort {
+                wanted_size: len,
+                offset: index,
+                data_len: self.get_slice().len(),
+            })?;
+
+        let dst_index = dst.get_index();
+        dst.write_stream_bytes(bytes)
+            .map_err(|_| Error::CopyDestinationWriteFailed {
+                wanted_size: len,
+                offset: dst_index,
+            })?;
+
+        self.increment_by(len);
+        Ok(len)
+    }
+
+    /// Same as [StreamReader::copy_to], but copies every byte from the index to the end of the data.
+    /// Nothing is copied if the index is at or past the end of the data.
+    #[inline(always)]
+    fn copy_remaining_to<W: StreamWriter>(&mut self, dst: &mut W) -> ReaderResult<usize> {
+        let len = self.read_remaining().len();
+        if len == 0 {
+            return Ok(0);
+        }
+
+        self.copy_to(dst, len)
+    }
+
+    /// Returns the bytes from the current stream to the end of the data without moving the index.
+    /// Returns an empty slice if the index is at or past the end of the data.
+    #[inline(always)]
+    fn read_remaining(&self) -> &[u8] {
+        self.get_slice_at_offset(self.get_index())
+    }
+
+    /// Same as [StreamReader::read_remaining], but copies the bytes into a vector
+    /// and moves the index to the end of the data.
+    /// The index is not moved if it is already past the end of the data.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_remaining_vec(&mut self) -> Vec<u8> {
+        let bytes = self.read_remaining().to_vec();
+        self.increment_by(bytes.len());
+        bytes
+    }
+
+    /// Returns the bytes from the current stream up to a delimiter without copying,
+    /// and moves the index past the delimiter.
+    /// The delimiter is not included in the returned bytes.
+    ///
+    /// Errors without moving the index if the delimiter is not found.
+    #[inline(always)]
+    fn read_stream_until_slice(&mut self, delimiter: u8) -> ReaderResult<&[u8]> {
+        let index = self.get_index();
+        let len = self
+            .read_remaining()
+            .iter()
+            .position(|byte| *byte == delimiter)
+            .ok_or(Error::InvalidRead {
+                message: "Delimiter was not found",
+            })?;
+
+        self.increment_by(len + 1);
+        Ok(&Self::get_slice(self)[index..index + len])
+    }
+
+    /// Same as [StreamReader::read_stream_until_slice], but returns the rest of the data
+    /// and moves the index to the end of the data if the delimiter is not found.
+    /// The index is not moved if it is already past the end of the data.
+    #[inline(always)]
+    fn read_stream_until_or_end_slice(&mut self, delimiter: u8) -> &[u8] {
+        let index = self.get_index();
+        let remaining = self.read_remaining();
+        let (len, consumed) = match remaining.iter().position(|byte| *byte == delimiter) {
+            Some(len) => (len, len + 1),
+            None => (remaining.len(), remaining.len()),
+        };
+
+        self.increment_by(consumed);
+        Self::get_slice_of_size(self, index, len).unwrap_or(&[])
+    }
+
+    /// Same as [StreamReader::read_stream_until_slice], but copies the bytes into a vector.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_until(&mut self, delimiter: u8) -> ReaderResult<Vec<u8>> {
+        Ok(self.read_stream_until_slice(delimiter)?.to_vec())
+    }
+
+    /// Same as [StreamReader::read_stream_until_or_end_slice], but copies the bytes into a vector.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_until_or_end(&mut self, delimiter: u8) -> Vec<u8> {
+        self.read_stream_until_or_end_slice(delimiter).to_vec()
+    }
+
+    /// Returns the bytes from the current stream up to a null terminator without copying,
+    /// and moves the index past the terminator.
+    /// The terminator is not included in the returned bytes.
+    ///
+    /// Errors without moving the index if the terminator is missing.
+    #[inline(always)]
+    fn read_stream_c_bytes(&mut self) -> ReaderResult<&[u8]> {
+        self.read_stream_until_slice(0)
+            .map_err(|_| Error::InvalidRead {
+                message: "Missing null terminator",
+            })
+    }
+
+    /// Same as [StreamReader::read_stream_c_bytes], but returns a string.
+    ///
+    /// Errors without moving the index if the terminator is missing or the string is not valid UTF-8.
+    #[inline(always)]
+    fn read_stream_c_str(&mut self) -> ReaderResult<&str> {
+        let index = self.get_index();
+        let len = self
+            .read_remaining()
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or(Error::InvalidRead {
+                message: "Missing null terminator",
+            })?;
+        let invalid_utf8 = |_| Error::InvalidRead {
+            message: "Invalid UTF-8 string",
+        };
+        core::str::from_utf8(&self.get_slice()[index..index + len]).map_err(invalid_utf8)?;
+
+        // The string is borrowed from self, so it's sliced again after the index moves.
+        self.increment_by(len + 1);
+        core::str::from_utf8(&Self::get_slice(self)[index..index + len]).map_err(invalid_utf8)
+    }
+
+    /// Same as [StreamReader::read_stream_c_str], but copies the string.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_c_string(&mut self) -> ReaderResult<String> {
+        Ok(String::from(self.read_stream_c_str()?))
     }
 
     /// Same as [Reader::read_byte_vec], but uses the current stream instead of an offset.
+    /// The index is only moved if the read succeeds.
     #[cfg(feature = "alloc")]
     #[inline(always)]
     fn read_byte_stream(&mut self, size: usize) -> ReaderResult<Vec<u8>> {
-        let index = self.swap_incremented_index(size);
-        self.read_byte_vec(index, size)
+        let index = self.get_index();
+        let bytes = end_of_data_context(
+            self.read_byte_vec(index, size),
+            index,
+            self.get_slice().len(),
+        )?;
+        self.increment_by(size);
+        Ok(bytes)
     }
 
     /// Same as [Reader::default_read_byte_vec], but returns a default value if the read is invalid.
+    /// The index is only moved if the read succeeds.
     #[cfg(feature = "alloc")]
     #[inline(always)]
     fn default_read_byte_stream(&mut self, size: usize) -> Vec<u8> {
-        let index = self.swap_incremented_index(size);
-        self.default_read_byte_vec(index, size)
+        let index = self.get_index();
+        match self.read_byte_stream(size) {
+            Ok(bytes) => bytes,
+            Err(_) => self.default_read_byte_vec(index, size),
+        }
+    }
+
+    /// Reads `count` values in their little endian representation from the current stream.
+    ///
+    /// The index is incremented past each value as it's read.
+    /// If a value can't be read, an [Error::IncompleteCountRead] is returned
+    /// with the offset of the value and the number of values read before it, and the index is left at that value.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_count_vec_le<T: EndianRead>(&mut self, count: usize) -> ReaderResult<Vec<T>> {
+        let remaining = self.get_slice_at_offset(self.get_index()).len();
+        let mut values = Vec::with_capacity(core::cmp::min(count, remaining));
+
+        for items_read in 0..count {
+            let index = self.get_index();
+            let value = self
+                .read_stream_le()
+                .map_err(|_| Error::IncompleteCountRead {
+                    offset: index,
+                    items_read,
+                })?;
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    /// Same as [StreamReader::read_stream_count_vec_le], but errors with [Error::InvalidData] without reading anything
+    /// if `count` is greater than `max_count`, such as when the count comes from a corrupt header.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_count_vec_le_bounded<T: EndianRead>(
+        &mut self,
+        count: usize,
+        max_count: usize,
+    ) -> ReaderResult<Vec<T>> {
+        if count > max_count {
+            return Err(Error::InvalidData {
+                offset: self.get_index(),
+                expected: max_count as u64,
+                found: count as u64,
+                description: Some("Count is greater than the maximum count"),
+            });
+        }
+
+        self.read_stream_count_vec_le(count)
+    }
+
+    /// Reads `count` values in their big endian representation from the current stream.
+    ///
+    /// The index is incremented past each value as it's read.
+    /// If a value can't be read, an [Error::IncompleteCountRead] is returned
+    /// with the offset of the value and the number of values read before it, and the index is left at that value.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_count_vec_be<T: EndianRead>(&mut self, count: usize) -> ReaderResult<Vec<T>> {
+        let remaining = self.get_slice_at_offset(self.get_index()).len();
+        let mut values = Vec::with_capacity(core::cmp::min(count, remaining));
+
+        for items_read in 0..count {
+            let index = self.get_index();
+            let value = self
+                .read_stream_be()
+                .map_err(|_| Error::IncompleteCountRead {
+                    offset: index,
+                    items_read,
+                })?;
+            values.push(value);
+        }
+
+        Ok(values)
+    }
+
+    /// Same as [StreamReader::read_stream_count_vec_be], but errors with [Error::InvalidData] without reading anything
+    /// if `count` is greater than `max_count`, such as when the count comes from a corrupt header.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_count_vec_be_bounded<T: EndianRead>(
+        &mut self,
+        count: usize,
+        max_count: usize,
+    ) -> ReaderResult<Vec<T>> {
+        if count > max_count {
+            return Err(Error::InvalidData {
+                offset: self.get_index(),
+                expected: max_count as u64,
+                found: count as u64,
+                description: Some("Count is greater than the maximum count"),
+            });
+        }
+
+        self.read_stream_count_vec_be(count)
+    }
+
+    /// Same as [Reader::read_nt_utf16_le_string], but uses the current stream instead of an offset.
+    ///
+    /// The index is incremented past the terminator on a successful read.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_nt_utf16_le_string(&mut self) -> ReaderResult<String> {
+        let index = self.get_index();
+        let read_value = self.read_nt_utf16_le_string(index)?;
+        self.increment_by(read_value.get_read_bytes());
+        Ok(read_value.into_data())
+    }
+
+    /// Same as [Reader::read_nt_utf16_be_string], but uses the current stream instead of an offset.
+    ///
+    /// The index is incremented past the terminator on a successful read.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_stream_nt_utf16_be_string(&mut self) -> ReaderResult<String> {
+        let index = self.get_index();
+        let read_value = self.read_nt_utf16_be_string(index)?;
+        self.increment_by(read_value.get_read_bytes());
+        Ok(read_value.into_data())
     }
 
     #[inline(always)]
-    fn into_le_iter<Item: EndianRead>(self) -> LeIter<Item, Self> {
+    fn into_le_iter<Item: EndianRead + StaticSize>(self) -> LeIter<Item, Self> {
         LeIter::new(self)
     }
 
     #[inline(always)]
-    fn into_be_iter<Item: EndianRead>(self) -> BeIter<Item, Self> {
+    fn into_be_iter<Item: EndianRead + StaticSize>(self) -> BeIter<Item, Self> {
         BeIter::new(self)
     }
+
+    /// Returns an iterator over the bytes from the current stream to the end of the data
+    /// in chunks of `chunk_len` bytes, and moves the index to the end of the data.
+    ///
+    /// The last chunk is shorter if the remaining bytes don't divide evenly.
+    /// Since the chunks borrow the data, the index is moved when this is called instead of as the chunks are read.
+    /// Use [StreamReader::chunks_vec] to move the index as each chunk is read.
+    ///
+    /// Panics if `chunk_len` is 0.
+    #[inline(always)]
+    fn chunks(&mut self, chunk_len: usize) -> core::slice::Chunks<'_, u8> {
+        let index = self.get_index();
+        let len = self.read_remaining().len();
+        self.increment_by(len);
+        Self::get_slice_at_offset(self, index).chunks(chunk_len)
+    }
+
+    /// Same as [StreamReader::chunks], but copies each chunk into a vector
+    /// and moves the index past each chunk as it's read.
+    ///
+    /// Panics if `chunk_len` is 0.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn chunks_vec(&mut self, chunk_len: usize) -> VecChunks<&mut Self> {
+        VecChunks::new(self, chunk_len)
+    }
+
+    /// Same as [StreamReader::into_le_iter], but returns errors instead of ending the iteration.
+    #[inline(always)]
+    fn into_try_le_iter<Item: EndianRead>(self) -> TryLeIter<Item, Self> {
+        TryLeIter::new(self)
+    }
+
+    /// Same as [StreamReader::into_be_iter], but returns errors instead of ending the iteration.
+    #[inline(always)]
+    fn into_try_be_iter<Item: EndianRead>(self) -> TryBeIter<Item, Self> {
+        TryBeIter::new(self)
+    }
 }
 
 impl<T> StreamReader for T where T: Reader + Cursor {}
 
+/// Replaces a size error with [Error::EndOfData] if the read started at or past the end of the data,
+/// since there was nothing left to read rather than inconsistent data.
+#[inline(always)]
+fn end_of_data_context<T>(
+    result: ReaderResult<T>,
+    index: usize,
+    data_len: usize,
+) -> ReaderResult<T> {
+    result.map_err(|error| {
+        if index >= data_len {
+            if let Error::InvalidSize { wanted_size, .. } = error.root() {
+                return Error::EndOfData {
+                    offset: index,
+                    requested: *wanted_size,
+                };
+            }
+        }
+
+        error
+    })
+}
+
+/// Returns the number of bytes needed to move an index to the next multiple of `align`.
+#[inline(always)]
+fn read_alignment_padding(index: usize, align: usize) -> ReaderResult<usize> {
+    if !align.is_power_of_two() {
+        return Err(Error::InvalidRead {
+            message: "Alignment must be a power of two",
+        });
+    }
+
+    Ok(index.wrapping_neg() & (align - 1))
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -187,6 +723,24 @@ mod test {
         }
     }
 
+    /// A 24 bit integer, which is smaller on the wire than in memory.
+    #[derive(Debug, Default, PartialEq)]
+    struct U24(u32);
+
+    impl EndianRead for U24 {
+        fn try_read_le(bytes: &[u8]) -> Result<crate::ReadOutput<Self>, Error> {
+            let bytes: [u8; 3] = EndianRead::try_read_le(bytes)?.into_data();
+            let value = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0]);
+            Ok(crate::ReadOutput::new(U24(value), 3))
+        }
+
+        fn try_read_be(bytes: &[u8]) -> Result<crate::ReadOutput<Self>, Error> {
+            let bytes: [u8; 3] = EndianRead::try_read_be(bytes)?.into_data();
+            let value = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+            Ok(crate::ReadOutput::new(U24(value), 3))
+        }
+    }
+
     mod read_stream {
         use super::*;
         use crate::Error;
@@ -202,6 +756,17 @@ mod test {
             assert_eq!(reader.get_index(), 4);
         }
 
+        #[test]
+        fn should_not_move_the_index_if_the_read_is_partly_past_the_end() {
+            let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
+            reader.set_index(6);
+            reader
+                .read_stream::<u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(reader.get_index(), 6);
+        }
+
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut reader = MockStream::new(u64::to_ne_bytes(0x1122334411223344));
@@ -212,12 +777,12 @@ mod test {
 
             assert_eq!(
                 error,
-                Error::InvalidSize {
-                    wanted_size: 4,
+                Error::EndOfData {
                     offset: 8,
-                    data_len: 8,
+                    requested: 4,
                 }
             );
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[test]
@@ -236,6 +801,7 @@ mod test {
                     source_offset: 3,
                 }
             );
+            assert_eq!(reader.get_index(), 3);
         }
     }
 
@@ -248,6 +814,7 @@ mod test {
             reader.set_index(4);
             let value = reader.default_read_stream::<u32>();
             assert_eq!(value, 0x11223344);
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[test]
@@ -256,6 +823,7 @@ mod test {
             reader.set_index(8);
             let value = reader.default_read_stream::<u32>();
             assert_eq!(value, u32::default());
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[test]
@@ -264,6 +832,7 @@ mod test {
             reader.set_index(2);
             let value = reader.default_read_stream::<u32>();
             assert_eq!(value, u32::default());
+            assert_eq!(reader.get_index(), 2);
         }
     }
 
@@ -292,10 +861,9 @@ mod test {
 
             assert_eq!(
                 error,
-                Error::InvalidSize {
-                    wanted_size: 4,
+                Error::EndOfData {
                     offset: 8,
-                    data_len: 8,
+                    requested: 4,
                 }
             );
         }
@@ -363,6 +931,23 @@ mod test {
                 .read_array_stream_le::<2, u16>()
                 .expect_err("Length should have been too large");
 
+            assert_eq!(
+                error,
+                Error::EndOfData {
+                    offset: 8,
+                    requested: 2,
+                }
+            );
+        }
+
+        #[test]
+        fn should_leave_the_index_at_the_failed_element() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let error = reader
+                .read_array_stream_le::<3, u16>()
+                .expect_err("Length should have been too large");
+
             assert_eq!(
                 error,
                 Error::InvalidSize {
@@ -371,6 +956,7 @@ mod test {
                     data_len: 8,
                 }
             );
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[derive(Debug, PartialEq)]
@@ -413,103 +999,1392 @@ mod test {
         }
     }
 
-    mod default_read_array_stream_le {
+    mod read_array_stream_be {
         use super::*;
+        use crate::{Error, ReadOutput};
 
         #[test]
         fn should_return_a_value() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             reader.set_index(4);
-            let value = reader.default_read_array_stream_le::<2, u16>();
+            let value = reader
+                .read_array_stream_be::<2, u16>()
+                .expect("Read should have been successful.");
 
-            assert_eq!(value, [0xbbaa, 0xddcc]);
+            assert_eq!(value, [0xaabb, 0xccdd]);
+            assert_eq!(reader.get_index(), 8);
         }
 
         #[test]
-        fn should_return_default_if_size_is_too_large_for_offset() {
+        fn should_return_error_if_size_is_too_large_for_offset() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(6);
-            let value = reader.default_read_array_stream_le::<2, u16>();
-            assert_eq!(value, [0u16; 2]);
-        }
-    }
+            reader.set_index(8);
+            let error = reader
+                .read_array_stream_be::<2, u16>()
+                .expect_err("Length should have been too large");
 
-    mod default_read_stream_le {
-        use super::*;
+            assert_eq!(
+                error,
+                Error::EndOfData {
+                    offset: 8,
+                    requested: 2,
+                }
+            );
+        }
 
         #[test]
-        fn should_return_a_value() {
+        fn should_leave_the_index_at_the_failed_element() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(4);
-            let value = reader.default_read_stream_le::<u32>();
-            assert_eq!(value, 0xddccbbaa);
+            reader.set_index(2);
+            let error = reader
+                .read_array_stream_be::<2, u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Prefixed(u8);
+
+        impl EndianRead for Prefixed {
+            fn try_read_le(_bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                unimplemented!()
+            }
+
+            fn try_read_be(bytes: &[u8]) -> Result<ReadOutput<Self>, Error> {
+                let len = bytes[0] as usize;
+                Ok(ReadOutput::new(Prefixed(bytes[len]), len + 1))
+            }
+        }
+
+        #[test]
+        fn should_read_values_with_dynamic_read_lengths() {
+            let mut reader = MockStream::new([0x01, 0xaa, 0x02, 0x00, 0xbb, 0x00, 0x00, 0x00]);
+            let value = reader
+                .read_array_stream_be::<2, Prefixed>()
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, [Prefixed(0xaa), Prefixed(0xbb)]);
+            assert_eq!(reader.get_index(), 5);
+        }
+    }
+
+    mod default_read_array_stream_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.default_read_array_stream_le::<2, u16>();
+
+            assert_eq!(value, [0xbbaa, 0xddcc]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_default_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let value = reader.default_read_array_stream_le::<2, u16>();
+            assert_eq!(value, [0u16; 2]);
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod default_read_array_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.default_read_array_stream_be::<2, u16>();
+
+            assert_eq!(value, [0xaabb, 0xccdd]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_default_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let value = reader.default_read_array_stream_be::<2, u16>();
+            assert_eq!(value, [0u16; 2]);
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod checked_read_array_stream_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.checked_read_array_stream_le::<2, u16>();
+
+            assert_eq!(value, Some([0xbbaa, 0xddcc]));
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_none_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.checked_read_array_stream_le::<3, u16>();
+
+            assert_eq!(value, None);
+            assert_eq!(reader.get_index(), 4);
+        }
+    }
+
+    mod checked_read_array_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.checked_read_array_stream_be::<2, u16>();
+
+            assert_eq!(value, Some([0xaabb, 0xccdd]));
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_none_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.checked_read_array_stream_be::<3, u16>();
+
+            assert_eq!(value, None);
+            assert_eq!(reader.get_index(), 4);
+        }
+    }
+
+    mod default_read_stream_le {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.default_read_stream_le::<u32>();
+            assert_eq!(value, 0xddccbbaa);
+        }
+
+        #[test]
+        fn should_return_default_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let value = reader.default_read_stream_le::<u32>();
+            assert_eq!(value, u32::default());
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_move_the_index_by_the_bytes_read() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+            assert_eq!(reader.default_read_stream_le::<U24>(), U24(0x332211));
+            assert_eq!(reader.get_index(), 3);
+            assert_eq!(reader.default_read_stream_le::<U24>(), U24(0xbbaa44));
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_not_move_the_index_if_a_dynamic_read_is_invalid() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+
+            assert_eq!(reader.default_read_stream_le::<U24>(), U24::default());
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod default_read_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.default_read_stream_be::<u32>();
+            assert_eq!(value, 0xaabbccdd);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_move_the_index_by_the_bytes_read() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+            assert_eq!(reader.default_read_stream_be::<U24>(), U24(0x112233));
+            assert_eq!(reader.get_index(), 3);
+            assert_eq!(reader.default_read_stream_be::<U24>(), U24(0x44aabb));
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_default_and_not_move_the_index_if_the_read_is_invalid() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+
+            assert_eq!(reader.default_read_stream_be::<U24>(), U24::default());
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod read_stream_ne {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader
+                .read_stream_ne::<u32>()
+                .expect("Read should have been successful.");
+
+            #[cfg(target_endian = "little")]
+            assert_eq!(value, 0xddccbbaa);
+            #[cfg(target_endian = "big")]
+            assert_eq!(value, 0xaabbccdd);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .read_stream_ne::<u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod skip {
+        use super::*;
+
+        #[test]
+        fn should_move_the_index() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+            reader.skip(4).expect("Skip should have succeeded");
+
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_skip_to_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+            reader.skip(6).expect("Skip should have succeeded");
+
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader.skip(3).expect_err("Skip should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_if_index_is_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(10);
+            let error = reader.skip(0).expect_err("Skip should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 0,
+                    offset: 10,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 10);
+        }
+    }
+
+    mod align_to {
+        use super::*;
+
+        #[test]
+        fn should_not_move_aligned_indexes() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let skipped = reader.align_to(4).expect("Align should have succeeded");
+
+            assert_eq!(skipped, 0);
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_align_odd_indexes() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(1);
+            let skipped = reader.align_to(4).expect("Align should have succeeded");
+
+            assert_eq!(skipped, 3);
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_align_to_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(5);
+            let skipped = reader.align_to(8).expect("Align should have succeeded");
+
+            assert_eq!(skipped, 3);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_error_if_alignment_is_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(5);
+            let error = reader
+                .align_to(16)
+                .expect_err("Alignment should have been past the end");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 11,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 5);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_alignments() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(1);
+
+            for align in [0, 3, 6] {
+                let error = reader
+                    .align_to(align)
+                    .expect_err("Alignment should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidRead {
+                        message: "Alignment must be a power of two",
+                    }
+                );
+            }
+            assert_eq!(reader.get_index(), 1);
+        }
+    }
+
+    mod read_stream_le_padded {
+        use super::*;
+
+        #[test]
+        fn should_skip_padding_before_the_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+            for (index, padding) in [(1, 3), (2, 2), (3, 1)] {
+                reader.set_index(index);
+                let result = reader
+                    .read_stream_le_padded::<u16>(4)
+                    .expect("Read should have succeeded");
+
+                assert_eq!(result.get_read_bytes(), padding + 2);
+                assert_eq!(result.into_data(), 0xbbaa);
+                assert_eq!(reader.get_index(), 6);
+            }
+        }
+
+        #[test]
+        fn should_not_pad_aligned_indexes() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let result = reader
+                .read_stream_le_padded::<u32>(4)
+                .expect("Read should have succeeded");
+
+            assert_eq!(result.get_read_bytes(), 4);
+            assert_eq!(result.into_data(), 0xddccbbaa);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_end_of_data_if_the_padding_reaches_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(1);
+            let error = reader
+                .read_stream_le_padded::<u32>(8)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::EndOfData {
+                    offset: 8,
+                    requested: 4,
+                }
+            );
+            assert_eq!(reader.get_index(), 1);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_alignments() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(1);
+
+            for align in [0, 3, 6] {
+                let error = reader
+                    .read_stream_le_padded::<u16>(align)
+                    .expect_err("Alignment should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidRead {
+                        message: "Alignment must be a power of two",
+                    }
+                );
+            }
+            assert_eq!(reader.get_index(), 1);
+        }
+    }
+
+    mod read_stream_be_padded {
+        use super::*;
+
+        #[test]
+        fn should_skip_padding_before_the_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(3);
+            let result = reader
+                .read_stream_be_padded::<u16>(2)
+                .expect("Read should have succeeded");
+
+            assert_eq!(result.get_read_bytes(), 3);
+            assert_eq!(result.into_data(), 0xaabb);
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod expect_stream_bytes {
+        use super::*;
+
+        #[test]
+        fn should_move_the_index_past_matching_bytes() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+            reader
+                .expect_stream_bytes(&[0x33, 0x44, 0xaa])
+                .expect("Bytes should have matched");
+
+            assert_eq!(reader.get_index(), 5);
+        }
+
+        #[test]
+        fn should_match_bytes_at_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            reader
+                .expect_stream_bytes(&[0xcc, 0xdd])
+                .expect("Bytes should have matched");
+
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_error_with_the_first_mismatched_byte() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+
+            let error = reader
+                .expect_stream_bytes(&[0x00, 0x44])
+                .expect_err("Bytes should not have matched");
+            assert_eq!(
+                error,
+                Error::UnexpectedByte {
+                    offset: 2,
+                    expected: 0x00,
+                    found: 0x33,
+                }
+            );
+
+            let error = reader
+                .expect_stream_bytes(&[0x33, 0x44, 0xaa, 0x00])
+                .expect_err("Bytes should not have matched");
+            assert_eq!(
+                error,
+                Error::UnexpectedByte {
+                    offset: 5,
+                    expected: 0x00,
+                    found: 0xbb,
+                }
+            );
+            assert_eq!(reader.get_index(), 2);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .expect_stream_bytes(&[0xcc, 0xdd, 0x00])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod peek_matches {
+        use super::*;
+
+        #[test]
+        fn should_return_whether_the_bytes_match() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+
+            assert!(reader.peek_matches(&[0xaa, 0xbb]));
+            assert!(!reader.peek_matches(&[0xaa, 0x00]));
+            assert!(!reader.peek_matches(&[0xaa, 0xbb, 0xcc, 0xdd, 0x00]));
+            assert_eq!(reader.get_index(), 4);
+        }
+    }
+
+    mod read_stream_exact {
+        use super::*;
+
+        #[test]
+        fn should_fill_the_buffer() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let mut dst = [0; 4];
+            reader
+                .read_stream_exact(&mut dst)
+                .expect("Read should have been successful.");
+
+            assert_eq!(dst, [0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_move_the_index_if_the_buffer_cannot_be_filled() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let mut dst = [0; 4];
+            let error = reader
+                .read_stream_exact(&mut dst)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(dst, [0; 4]);
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod checked_read_stream_exact {
+        use super::*;
+
+        #[test]
+        fn should_return_the_number_of_bytes_copied() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(1);
+            let mut dst = [0; 3];
+            let copied = reader.checked_read_stream_exact(&mut dst);
+
+            assert_eq!(copied, 3);
+            assert_eq!(dst, [0x22, 0x33, 0x44]);
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_return_0_if_the_buffer_cannot_be_filled() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(7);
+            let mut dst = [0; 3];
+            let copied = reader.checked_read_stream_exact(&mut dst);
+
+            assert_eq!(copied, 0);
+            assert_eq!(dst, [0; 3]);
+            assert_eq!(reader.get_index(), 7);
+        }
+    }
+
+    mod copy_to {
+        use super::*;
+        use crate::StreamContainer;
+
+        #[test]
+        fn should_copy_bytes_and_move_both_indexes() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+            let mut writer = StreamContainer::new([0u8; 4]);
+            writer.set_index(1);
+
+            let copied = reader
+                .copy_to(&mut writer, 3)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(copied, 3);
+            assert_eq!(reader.get_index(), 5);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.into_raw(), [0, 0x33, 0x44, 0xaa]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            use alloc::vec;
+
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut writer = StreamContainer::new(vec![]);
+
+            reader
+                .copy_to(&mut writer, 2)
+                .expect("Copy should have succeeded");
+            reader.set_index(4);
+            reader
+                .copy_to(&mut writer, 4)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(reader.get_index(), 8);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.into_raw(), [0x11, 0x22, 0xaa, 0xbb, 0xcc, 0xdd]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_source_is_too_short() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let mut writer = StreamContainer::new([0u8; 4]);
+
+            let error = reader
+                .copy_to(&mut writer, 3)
+                .expect_err("Source should have been too short");
+
+            assert_eq!(
+                error,
+                Error::CopySourceTooShort {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+            assert_eq!(writer.get_index(), 0);
+            assert_eq!(writer.into_raw(), [0; 4]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_destination_can_not_be_written() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let mut writer = StreamContainer::new([0u8; 4]);
+            writer.set_index(2);
+
+            let error = reader
+                .copy_to(&mut writer, 3)
+                .expect_err("Destination should have been too short");
+
+            assert_eq!(
+                error,
+                Error::CopyDestinationWriteFailed {
+                    wanted_size: 3,
+                    offset: 2,
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.into_raw(), [0; 4]);
+        }
+    }
+
+    mod copy_remaining_to {
+        use super::*;
+        use crate::StreamContainer;
+
+        #[test]
+        fn should_copy_the_rest_of_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(5);
+            let mut writer = StreamContainer::new([0u8; 4]);
+
+            let copied = reader
+                .copy_remaining_to(&mut writer)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(copied, 3);
+            assert_eq!(reader.get_index(), 8);
+            assert_eq!(writer.get_index(), 3);
+            assert_eq!(writer.into_raw(), [0xbb, 0xcc, 0xdd, 0]);
+        }
+
+        #[test]
+        fn should_copy_nothing_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(10);
+            let mut writer = StreamContainer::new([0u8; 4]);
+
+            let copied = reader
+                .copy_remaining_to(&mut writer)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(copied, 0);
+            assert_eq!(reader.get_index(), 10);
+            assert_eq!(writer.get_index(), 0);
+        }
+    }
+
+    mod read_remaining {
+        use super::*;
+
+        #[test]
+        fn should_return_the_rest_of_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(5);
+
+            assert_eq!(reader.read_remaining(), [0xbb, 0xcc, 0xdd]);
+            assert_eq!(reader.get_index(), 5);
+        }
+
+        #[test]
+        fn should_return_nothing_at_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(8);
+            assert!(reader.read_remaining().is_empty());
+        }
+
+        #[test]
+        fn should_return_nothing_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(10);
+            assert!(reader.read_remaining().is_empty());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_remaining_vec {
+        use super::*;
+
+        #[test]
+        fn should_return_the_rest_of_the_data() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(5);
+
+            assert_eq!(reader.read_remaining_vec(), [0xbb, 0xcc, 0xdd]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_nothing_at_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(8);
+
+            assert!(reader.read_remaining_vec().is_empty());
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_move_the_index_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(10);
+
+            assert!(reader.read_remaining_vec().is_empty());
+            assert_eq!(reader.get_index(), 10);
+        }
+    }
+
+    mod read_stream_until_slice {
+        use super::*;
+
+        #[test]
+        fn should_read_up_to_the_delimiter() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+
+            assert_eq!(reader.read_stream_until_slice(0x0a), Ok(&[0x11, 0x22][..]));
+            assert_eq!(reader.get_index(), 3);
+        }
+
+        #[test]
+        fn should_read_multiple_records() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+
+            assert_eq!(reader.read_stream_until_slice(0x0a), Ok(&[0x11, 0x22][..]));
+            assert_eq!(reader.read_stream_until_slice(0x0a), Ok(&[0x33][..]));
+            assert_eq!(reader.read_stream_until_slice(0x0a), Ok(&[][..]));
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_nothing_if_the_delimiter_is_at_the_index() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+            reader.set_index(2);
+
+            assert_eq!(reader.read_stream_until_slice(0x0a), Ok(&[][..]));
+            assert_eq!(reader.get_index(), 3);
+        }
+
+        #[test]
+        fn should_return_error_if_the_delimiter_is_missing() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+            reader.set_index(6);
+
+            let error = reader
+                .read_stream_until_slice(0x0a)
+                .expect_err("Delimiter should have been missing");
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Delimiter was not found",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+            reader.set_index(10);
+
+            assert!(reader.read_stream_until_slice(0x0a).is_err());
+            assert_eq!(reader.get_index(), 10);
+        }
+    }
+
+    mod read_stream_until_or_end_slice {
+        use super::*;
+
+        #[test]
+        fn should_read_up_to_the_delimiter() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+
+            assert_eq!(reader.read_stream_until_or_end_slice(0x0a), [0x11, 0x22]);
+            assert_eq!(reader.get_index(), 3);
+        }
+
+        #[test]
+        fn should_return_the_rest_of_the_data_if_the_delimiter_is_missing() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+            reader.set_index(6);
+
+            assert_eq!(reader.read_stream_until_or_end_slice(0x0a), [0x44, 0x55]);
+            assert_eq!(reader.get_index(), 8);
+            assert!(reader.read_stream_until_or_end_slice(0x0a).is_empty());
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_move_the_index_past_the_end() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x0a, 0x33, 0x0a, 0x0a, 0x44, 0x55]);
+            reader.set_index(10);
+
+            assert!(reader.read_stream_until_or_end_slice(0x0a).is_empty());
+            assert_eq!(reader.get_index(), 10);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_stream_until {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_read_multiple_records() {
+            let mut reader = MockStream::new([0x11, 0x22, 0xff, 0x33, 0xff, 0xff, 0x44, 0x55]);
+
+            assert_eq!(reader.read_stream_until(0xff), Ok(vec![0x11, 0x22]));
+            assert_eq!(reader.read_stream_until(0xff), Ok(vec![0x33]));
+            assert_eq!(reader.read_stream_until(0xff), Ok(vec![]));
+            assert!(reader.read_stream_until(0xff).is_err());
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_stream_until_or_end {
+        use super::*;
+
+        #[test]
+        fn should_read_multiple_records() {
+            let mut reader = MockStream::new([0x11, 0x22, 0xff, 0x33, 0xff, 0xff, 0x44, 0x55]);
+
+            assert_eq!(reader.read_stream_until_or_end(0xff), [0x11, 0x22]);
+            assert_eq!(reader.read_stream_until_or_end(0xff), [0x33]);
+            assert!(reader.read_stream_until_or_end(0xff).is_empty());
+            assert_eq!(reader.read_stream_until_or_end(0xff), [0x44, 0x55]);
+            assert_eq!(reader.get_index(), 8);
+        }
+    }
+
+    mod read_stream_c_bytes {
+        use super::*;
+
+        #[test]
+        fn should_read_consecutive_strings() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x00, 0xff, 0x00, 0x63, 0x64]);
+
+            assert_eq!(reader.read_stream_c_bytes(), Ok(&[0x61, 0x62][..]));
+            assert_eq!(reader.read_stream_c_bytes(), Ok(&[][..]));
+            assert_eq!(reader.read_stream_c_bytes(), Ok(&[0xff][..]));
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_if_unterminated() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x00, 0xff, 0x00, 0x63, 0x64]);
+            reader.set_index(6);
+
+            let error = reader
+                .read_stream_c_bytes()
+                .expect_err("Read should have been unterminated");
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Missing null terminator",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    mod read_stream_c_str {
+        use super::*;
+
+        #[test]
+        fn should_read_consecutive_strings() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x63, 0x00, 0x00, 0x64, 0x65]);
+
+            assert_eq!(reader.read_stream_c_str(), Ok("ab"));
+            assert_eq!(reader.read_stream_c_str(), Ok("c"));
+            assert_eq!(reader.read_stream_c_str(), Ok(""));
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_if_unterminated() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x63, 0x00, 0x00, 0x64, 0x65]);
+            reader.set_index(6);
+
+            let error = reader
+                .read_stream_c_str()
+                .expect_err("Read should have been unterminated");
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Missing null terminator",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_return_error_if_invalid_utf8() {
+            let mut reader = MockStream::new([0x61, 0xff, 0x00, 0x63, 0x00, 0x00, 0x64, 0x65]);
+
+            let error = reader
+                .read_stream_c_str()
+                .expect_err("Read should have been invalid UTF-8");
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Invalid UTF-8 string",
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_stream_c_string {
+        use super::*;
+        use alloc::string::ToString;
+
+        #[test]
+        fn should_read_consecutive_strings() {
+            let mut reader = MockStream::new([0x61, 0x62, 0x00, 0x63, 0x00, 0x00, 0x64, 0x65]);
+
+            assert_eq!(reader.read_stream_c_string(), Ok("ab".to_string()));
+            assert_eq!(reader.read_stream_c_string(), Ok("c".to_string()));
+            assert_eq!(reader.read_stream_c_string(), Ok("".to_string()));
+            assert!(reader.read_stream_c_string().is_err());
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_byte_stream {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader
+                .read_byte_stream(3)
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            assert_eq!(reader.get_index(), 7);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(6);
+            let error = reader
+                .read_byte_stream(4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod default_read_byte_stream {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(4);
+            let value = reader.default_read_byte_stream(3);
+            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
         }
 
         #[test]
         fn should_return_default_if_size_is_too_large_for_offset() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
             reader.set_index(6);
-            let value = reader.default_read_stream_le::<u32>();
-            assert_eq!(value, u32::default());
+            let value = reader.default_read_byte_stream(4);
+            assert_eq!(value, vec![0, 0, 0, 0]);
+            assert_eq!(reader.get_index(), 6);
         }
     }
 
-    mod read_byte_stream {
+    #[cfg(feature = "alloc")]
+    mod read_stream_count_vec_le {
         use super::*;
-        use crate::Error;
-        use alloc::vec;
 
         #[test]
-        fn should_return_a_value() {
+        fn should_read_values() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(4);
-            let value = reader
-                .read_byte_stream(3)
+            let values = reader
+                .read_stream_count_vec_le::<u16>(2)
                 .expect("Read should have been successful.");
 
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            assert_eq!(values, [0x2211, 0x4433]);
+            assert_eq!(reader.get_index(), 4);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_read_nothing_for_a_count_of_0() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(6);
+            reader.set_index(8);
+            let values = reader
+                .read_stream_count_vec_le::<u32>(0)
+                .expect("Read should have been successful.");
+
+            assert!(values.is_empty());
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_read_values_that_fit_exactly() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let values = reader
+                .read_stream_count_vec_le::<u16>(4)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, [0x2211, 0x4433, 0xbbaa, 0xddcc]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_error_if_data_runs_out() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
             let error = reader
-                .read_byte_stream(4)
+                .read_stream_count_vec_le::<u16>(4)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
-                    data_len: 8,
+                Error::IncompleteCountRead {
+                    offset: 8,
+                    items_read: 3,
+                }
+            );
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_reserve_absurd_counts() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_stream_count_vec_le::<u8>(usize::MAX)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::IncompleteCountRead {
+                    offset: 8,
+                    items_read: 8,
                 }
             );
         }
     }
 
-    mod default_read_byte_stream {
+    #[cfg(feature = "alloc")]
+    mod read_stream_count_vec_le_bounded {
         use super::*;
-        use alloc::vec;
 
         #[test]
-        fn should_return_a_value() {
+        fn should_read_values_up_to_the_maximum_count() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
-            reader.set_index(4);
-            let value = reader.default_read_byte_stream(3);
-            assert_eq!(value, vec![0xaa, 0xbb, 0xcc]);
+            let values = reader
+                .read_stream_count_vec_le_bounded::<u16>(2, 2)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, [0x2211, 0x4433]);
         }
 
         #[test]
-        fn should_return_default_if_size_is_too_large_for_offset() {
+        fn should_return_error_if_count_is_greater_than_the_maximum_count() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_stream_count_vec_le_bounded::<u16>(3, 2)
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidData {
+                    offset: 0,
+                    expected: 2,
+                    found: 3,
+                    description: Some("Count is greater than the maximum count"),
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_stream_count_vec_be {
+        use super::*;
+
+        #[test]
+        fn should_read_values() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let values = reader
+                .read_stream_count_vec_be::<u16>(2)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, [0x1122, 0x3344]);
+            assert_eq!(reader.get_index(), 4);
+        }
+
+        #[test]
+        fn should_read_nothing_for_a_count_of_0() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(8);
+            let values = reader
+                .read_stream_count_vec_be::<u32>(0)
+                .expect("Read should have been successful.");
+
+            assert!(values.is_empty());
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_read_values_that_fit_exactly() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let values = reader
+                .read_stream_count_vec_be::<u16>(4)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, [0x1122, 0x3344, 0xaabb, 0xccdd]);
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_return_error_if_data_runs_out() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            reader.set_index(2);
+            let error = reader
+                .read_stream_count_vec_be::<u16>(4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::IncompleteCountRead {
+                    offset: 8,
+                    items_read: 3,
+                }
+            );
+            assert_eq!(reader.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_reserve_absurd_counts() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_stream_count_vec_be::<u8>(usize::MAX)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::IncompleteCountRead {
+                    offset: 8,
+                    items_read: 8,
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_stream_count_vec_be_bounded {
+        use super::*;
+
+        #[test]
+        fn should_read_values_up_to_the_maximum_count() {
+            let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let values = reader
+                .read_stream_count_vec_be_bounded::<u16>(2, 2)
+                .expect("Read should have been successful.");
+
+            assert_eq!(values, [0x1122, 0x3344]);
+        }
+
+        #[test]
+        fn should_return_error_if_count_is_greater_than_the_maximum_count() {
             let mut reader = MockStream::new([0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]);
+            let error = reader
+                .read_stream_count_vec_be_bounded::<u16>(3, 2)
+                .expect_err("Count should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidData {
+                    offset: 0,
+                    expected: 2,
+                    found: 3,
+                    description: Some("Count is greater than the maximum count"),
+                }
+            );
+            assert_eq!(reader.get_index(), 0);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod read_stream_nt_utf16_le_string {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x48, 0x00, 0x69, 0x00, 0x00, 0x00, 0x41, 0x00]);
+            let value = reader
+                .read_stream_nt_utf16_le_string()
+                .expect("Read should have been successful.");
+
+            assert_eq!(value, "Hi");
+            assert_eq!(reader.get_index(), 6);
+        }
+
+        #[test]
+        fn should_not_increment_the_index_on_error() {
+            let mut reader = MockStream::new([0x48, 0x00, 0x69, 0x00, 0x00, 0x00, 0x41, 0x00]);
             reader.set_index(6);
-            let value = reader.default_read_byte_stream(4);
-            assert_eq!(value, vec![0, 0, 0, 0]);
+            let error = reader
+                .read_stream_nt_utf16_le_string()
+                .expect_err("Terminator should have been missing");
+
+            assert_eq!(
+                error,
+                Error::InvalidRead {
+                    message: "Missing null terminator",
+                }
+            );
+            assert_eq!(reader.get_index(), 6);
         }
     }
 
+    #[cfg(feature = "alloc")]
+    mod read_stream_nt_utf16_be_string {
+        use super::*;
+
+        #[test]
+        fn should_return_a_value() {
+            let mut reader = MockStream::new([0x00, 0x48, 0x00, 0x00, 0x00, 0x69, 0x00, 0x00]);
+            let first = reader
+                .read_stream_nt_utf16_be_string()
+                .expect("Read should have been successful.");
+            let second = reader
+                .read_stream_nt_utf16_be_string()
+                .expect("Read should have been successful.");
+
+            assert_eq!(first, "H");
+            assert_eq!(second, "i");
+            assert_eq!(reader.get_index(), 8);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
     mod into_le_iter {
         use super::*;
 
@@ -532,6 +2407,7 @@ mod test {
         }
     }
 
+    #[cfg(feature = "alloc")]
     mod into_be_iter {
         use super::*;
 
@@ -553,4 +2429,136 @@ mod test {
             assert_eq!(second, [0x11223344]);
         }
     }
+
+    mod end_of_data {
+        use super::*;
+
+        fn read_record<R: StreamReader>(stream: &mut R) -> ReaderResult<[u8; 2]> {
+            let len = stream.read_stream_le::<u8>()? as usize;
+            let mut record = [0; 2];
+            stream.read_stream_exact(&mut record[..len])?;
+            Ok(record)
+        }
+
+        #[test]
+        fn should_return_end_of_data_after_the_last_record() {
+            let mut reader = MockStream::new([0x02, 0xaa, 0xbb, 0x01, 0xcc, 0x02, 0xdd, 0xee]);
+
+            assert_eq!(read_record(&mut reader), Ok([0xaa, 0xbb]));
+            assert_eq!(read_record(&mut reader), Ok([0xcc, 0]));
+            assert_eq!(read_record(&mut reader), Ok([0xdd, 0xee]));
+            assert_eq!(
+                read_record(&mut reader),
+                Err(Error::EndOfData {
+                    offset: 8,
+                    requested: 1,
+                })
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_size_for_a_bad_inner_length() {
+            let mut reader = MockStream::new([0x02, 0xaa, 0xbb, 0x01, 0xcc, 0x00, 0x02, 0xdd]);
+            reader.set_index(6);
+
+            assert_eq!(
+                read_record(&mut reader),
+                Err(Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                })
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_size_for_a_truncated_value() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(6);
+            let error = reader
+                .read_stream_be::<u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_end_of_data_past_the_end() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(10);
+            let error = reader
+                .read_stream_be::<u16>()
+                .expect_err("Index should have been past the end");
+
+            assert_eq!(
+                error,
+                Error::EndOfData {
+                    offset: 10,
+                    requested: 2,
+                }
+            );
+            assert_eq!(reader.get_index(), 10);
+        }
+
+        #[test]
+        fn should_return_end_of_data_for_exact_reads() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(8);
+            let mut dst = [0; 2];
+            let error = reader
+                .read_stream_exact(&mut dst)
+                .expect_err("Index should have been at the end");
+
+            assert_eq!(
+                error,
+                Error::EndOfData {
+                    offset: 8,
+                    requested: 2,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_return_end_of_data_for_byte_streams() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(8);
+            let error = reader
+                .read_byte_stream(3)
+                .expect_err("Index should have been at the end");
+
+            assert_eq!(
+                error,
+                Error::EndOfData {
+                    offset: 8,
+                    requested: 3,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_invalid_size_if_an_array_ends_partway() {
+            let mut reader = MockStream::new([0; 8]);
+            reader.set_index(6);
+            let error = reader
+                .read_array_stream_le::<2, u16>()
+                .expect_err("Array should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+    }
 }
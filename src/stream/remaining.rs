@@ -0,0 +1,119 @@
+use super::cursor::Cursor;
+use crate::Reader;
+
+/// Helpers to check how much data is left in a stream.
+///
+/// This is implemented for anything that is both a [Reader] and a [Cursor].
+pub trait StreamRemaining: Reader + Cursor {
+    /// Returns the length of the data, regardless of the index.
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.get_slice().len()
+    }
+
+    /// Returns true if there is no data, regardless of the index.
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of bytes from the index to the end of the data.
+    /// Returns 0 if the index is past the end of the data.
+    #[inline(always)]
+    fn remaining(&self) -> usize {
+        self.len().saturating_sub(self.get_index())
+    }
+
+    /// Returns true if the index is at or past the end of the data.
+    #[inline(always)]
+    fn is_at_end(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// Returns the unread bytes from the index to the end of the data without copying.
+    /// Returns an empty slice if the index is at or past the end of the data.
+    #[inline(always)]
+    fn remaining_slice(&self) -> &[u8] {
+        self.get_slice_at_offset(self.get_index())
+    }
+
+    /// Returns the bytes before the index without copying.
+    /// Returns all of the data if the index is past the end of the data.
+    #[inline(always)]
+    fn consumed_slice(&self) -> &[u8] {
+        let data = self.get_slice();
+        &data[..core::cmp::min(self.get_index(), data.len())]
+    }
+}
+
+impl<T> StreamRemaining for T where T: Reader + Cursor {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::StreamContainer;
+
+    #[test]
+    fn should_return_the_remaining_length() {
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(1);
+
+        assert_eq!(stream.len(), 4);
+        assert!(!stream.is_empty());
+        assert_eq!(stream.remaining(), 3);
+        assert!(!stream.is_at_end());
+    }
+
+    #[test]
+    fn should_be_at_the_end() {
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(4);
+
+        assert_eq!(stream.remaining(), 0);
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_not_underflow_if_index_is_past_the_end() {
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(10);
+
+        assert_eq!(stream.len(), 4);
+        assert_eq!(stream.remaining(), 0);
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_be_empty() {
+        let stream = StreamContainer::new([0u8; 0]);
+
+        assert!(stream.is_empty());
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_return_the_slices_at_the_start() {
+        let stream = StreamContainer::new([1u8, 2, 3, 4]);
+
+        assert_eq!(stream.remaining_slice(), [1, 2, 3, 4]);
+        assert!(stream.consumed_slice().is_empty());
+    }
+
+    #[test]
+    fn should_return_the_slices_mid_stream() {
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(1);
+
+        assert_eq!(stream.remaining_slice(), [2, 3, 4]);
+        assert_eq!(stream.consumed_slice(), [1]);
+    }
+
+    #[test]
+    fn should_return_the_slices_past_the_end() {
+        let mut stream = StreamContainer::new([1u8, 2, 3, 4]);
+        stream.set_index(10);
+
+        assert!(stream.remaining_slice().is_empty());
+        assert_eq!(stream.consumed_slice(), [1, 2, 3, 4]);
+    }
+}
@@ -0,0 +1,219 @@
+use super::cursor::Cursor;
+use crate::{Error, Reader, ReaderResult};
+
+/// A position to seek to, relative to the start, the current index, or the end of the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+    Start(usize),
+    Current(isize),
+    End(isize),
+}
+
+/// An interface to move the index of a stream relative to the start, the current index, or the end of the data.
+///
+/// This is implemented for anything that is both a [Reader] and a [Cursor].
+pub trait Seek: Reader + Cursor {
+    /// Moves the index to a position and returns the index the cursor moved to.
+    ///
+    /// Seeking past the end of the data is allowed, the same as [Cursor::set_index].
+    /// Errors without moving the index if the position is before the start of the data or overflows.
+    #[inline(always)]
+    fn seek(&mut self, pos: SeekFrom) -> ReaderResult<usize> {
+        let index = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::Current(delta) => self.get_index().checked_add_signed(delta),
+            SeekFrom::End(delta) => self.get_slice().len().checked_add_signed(delta),
+        }
+        .ok_or(Error::InvalidRead {
+            message: "Seek position is out of range",
+        })?;
+
+        self.set_index(index);
+        Ok(self.get_index())
+    }
+
+    /// Moves the index to the end of the data and returns the new index.
+    #[inline(always)]
+    fn seek_to_end(&mut self) -> usize {
+        let index = self.get_slice().len();
+        self.set_index(index);
+        index
+    }
+
+    /// Moves the index forward to an absolute offset and returns the number of bytes skipped.
+    ///
+    /// This is useful to skip to a section at a known offset while catching offsets that point backwards.
+    /// Errors without moving the index if the offset is before the index or past the end of the data.
+    #[inline(always)]
+    fn advance_to(&mut self, offset: usize) -> ReaderResult<usize> {
+        let index = self.get_index();
+
+        if offset < index {
+            return Err(Error::InvalidRead {
+                message: "Offset is before the index",
+            });
+        }
+
+        if offset > self.get_slice().len() {
+            return Err(Error::InvalidRead {
+                message: "Offset is past the end of the data",
+            });
+        }
+
+        self.set_index(offset);
+        Ok(offset - index)
+    }
+}
+
+impl<T> Seek for T where T: Reader + Cursor {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{StreamContainer, StreamRemaining};
+
+    #[test]
+    fn should_seek_from_the_start() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(5);
+
+        assert_eq!(stream.seek(SeekFrom::Start(2)), Ok(2));
+        assert_eq!(stream.get_index(), 2);
+    }
+
+    #[test]
+    fn should_seek_from_the_current_index() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(5);
+
+        assert_eq!(stream.seek(SeekFrom::Current(2)), Ok(7));
+        assert_eq!(stream.seek(SeekFrom::Current(-3)), Ok(4));
+        assert_eq!(stream.get_index(), 4);
+    }
+
+    #[test]
+    fn should_seek_from_the_end() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+
+        assert_eq!(stream.seek(SeekFrom::End(-2)), Ok(6));
+        assert_eq!(stream.seek(SeekFrom::End(0)), Ok(8));
+        assert_eq!(stream.get_index(), 8);
+    }
+
+    #[test]
+    fn should_allow_seeking_past_the_end() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+
+        assert_eq!(stream.seek(SeekFrom::End(4)), Ok(12));
+        assert_eq!(stream.get_index(), 12);
+    }
+
+    #[test]
+    fn should_return_error_if_seeking_before_the_start() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(2);
+
+        let expected = Err(Error::InvalidRead {
+            message: "Seek position is out of range",
+        });
+        assert_eq!(stream.seek(SeekFrom::Current(-3)), expected);
+        assert_eq!(stream.seek(SeekFrom::End(-9)), expected);
+        assert_eq!(stream.get_index(), 2);
+    }
+
+    #[test]
+    fn should_return_error_on_overflow() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(usize::MAX);
+
+        let error = stream
+            .seek(SeekFrom::Current(1))
+            .expect_err("Seek should have overflowed");
+        assert_eq!(
+            error,
+            Error::InvalidRead {
+                message: "Seek position is out of range",
+            }
+        );
+        assert_eq!(stream.get_index(), usize::MAX);
+    }
+
+    #[test]
+    fn should_seek_to_the_end() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(3);
+
+        assert_eq!(stream.seek_to_end(), 8);
+        assert_eq!(stream.get_index(), 8);
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_seek_to_the_end_from_past_the_end() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(12);
+
+        assert_eq!(stream.seek_to_end(), 8);
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_advance_to_an_offset() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(2);
+
+        assert_eq!(stream.advance_to(6), Ok(4));
+        assert_eq!(stream.get_index(), 6);
+    }
+
+    #[test]
+    fn should_advance_to_the_current_index() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(2);
+
+        assert_eq!(stream.advance_to(2), Ok(0));
+        assert_eq!(stream.get_index(), 2);
+    }
+
+    #[test]
+    fn should_advance_to_the_end() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+
+        assert_eq!(stream.advance_to(8), Ok(8));
+        assert!(stream.is_at_end());
+    }
+
+    #[test]
+    fn should_return_error_if_advancing_backwards() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(5);
+
+        let error = stream
+            .advance_to(4)
+            .expect_err("Advance should have been backwards");
+        assert_eq!(
+            error,
+            Error::InvalidRead {
+                message: "Offset is before the index",
+            }
+        );
+        assert_eq!(stream.get_index(), 5);
+    }
+
+    #[test]
+    fn should_return_error_if_advancing_past_the_end() {
+        let mut stream = StreamContainer::new([0u8; 8]);
+        stream.set_index(5);
+
+        let error = stream
+            .advance_to(9)
+            .expect_err("Advance should have been past the end");
+        assert_eq!(
+            error,
+            Error::InvalidRead {
+                message: "Offset is past the end of the data",
+            }
+        );
+        assert_eq!(stream.get_index(), 5);
+    }
+}
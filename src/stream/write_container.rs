@@ -0,0 +1,178 @@
+use crate::{
+    writer::{forward_sized_writer_methods, forward_writer_methods},
+    Cursor, EndianWrite, GrowableWriter, Writer, WriterResult,
+};
+
+/// A convenience container that allows streaming writes to anything that implements [Writer].
+///
+/// Unlike [StreamContainer](crate::StreamContainer), the container doesn't need [Reader](crate::Reader),
+/// which is useful for write-only targets.
+pub struct WriteStreamContainer<T: Writer> {
+    raw: T,
+    cursor: usize,
+}
+
+impl<T: Writer> WriteStreamContainer<T> {
+    #[inline(always)]
+    pub fn new(raw: T) -> Self {
+        Self { raw, cursor: 0 }
+    }
+
+    /// Creates a container with the cursor starting at the provided offset.
+    #[inline(always)]
+    pub fn new_at(raw: T, offset: usize) -> Self {
+        Self {
+            raw,
+            cursor: offset,
+        }
+    }
+
+    #[inline(always)]
+    pub fn into_raw(self) -> T {
+        self.raw
+    }
+}
+
+/// Every method is forwarded to the inner writer, so writers that customize them, such as transforms, behave the same in a container.
+impl<T: Writer> Writer for WriteStreamContainer<T> {
+    forward_writer_methods!(this => this.raw);
+    forward_sized_writer_methods!(this => this.raw);
+}
+
+/// Inserting and removing bytes does not move the cursor.
+impl<T: GrowableWriter> GrowableWriter for WriteStreamContainer<T> {
+    #[inline(always)]
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.raw.insert_bytes(offset, bytes)
+    }
+
+    #[inline(always)]
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.raw.remove_bytes(offset, len)
+    }
+}
+
+impl<T: Writer> Cursor for WriteStreamContainer<T> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.cursor
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.cursor = index;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Error, StreamWriter, TransformWriter};
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    struct MockWriter {
+        bytes: [u8; 8],
+    }
+
+    impl MockWriter {
+        fn new() -> Self {
+            Self { bytes: [0; 8] }
+        }
+    }
+
+    impl Writer for MockWriter {
+        fn get_mut_slice(&mut self) -> &mut [u8] {
+            &mut self.bytes
+        }
+    }
+
+    #[test]
+    fn should_write_to_a_writer_only_target() {
+        let mut stream = WriteStreamContainer::new(MockWriter::new());
+        stream
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        stream
+            .write_stream_be(&0x1122u16)
+            .expect("Write should have succeeded");
+
+        assert_eq!(stream.get_index(), 6);
+        assert_eq!(
+            stream.into_raw().bytes,
+            [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22, 0, 0]
+        );
+    }
+
+    #[test]
+    fn should_checked_write_stream_bytes() {
+        let mut stream = WriteStreamContainer::new_at(MockWriter::new(), 4);
+
+        let written = stream.checked_write_stream_bytes(&[1, 2, 3]);
+        assert_eq!(written, 3);
+        assert_eq!(stream.get_index(), 7);
+
+        let written = stream.checked_write_stream_bytes(&[4, 5]);
+        assert_eq!(written, 0);
+        assert_eq!(stream.get_index(), 7);
+        assert_eq!(stream.into_raw().bytes, [0, 0, 0, 0, 1, 2, 3, 0]);
+    }
+
+    #[test]
+    fn should_return_error_if_write_is_past_the_end() {
+        let mut stream = WriteStreamContainer::new_at(MockWriter::new(), 6);
+
+        let error = stream
+            .write_stream_le(&0u32)
+            .expect_err("Write should have failed");
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            }
+        );
+    }
+
+    #[test]
+    fn should_forward_transformed_writes() {
+        let writer = TransformWriter::new([0u8; 8], |_, byte| byte ^ 0xff);
+        let mut stream = WriteStreamContainer::new(writer);
+        stream
+            .write_bits_msb(0, 0, 4, 0x5)
+            .expect("Write should have succeeded");
+        stream
+            .write_all_slices(1, &[&[0x01], &[0x02]])
+            .expect("Write should have succeeded");
+        stream
+            .write_array_le(3, &[0x0102u16])
+            .expect("Write should have succeeded");
+        stream
+            .write_slice_be(5, &[0x0304u16])
+            .expect("Write should have succeeded");
+        stream
+            .write_repeated_le(7, &0x05u8, 1)
+            .expect("Write should have succeeded");
+
+        assert_eq!(
+            stream.into_raw().into_inner(),
+            [0xa0, 0xfe, 0xfd, 0xfd, 0xfe, 0xfc, 0xfb, 0xfa]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_grow_a_vector_if_needed() {
+        let mut stream = WriteStreamContainer::new(vec![]);
+        stream
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        stream
+            .write_stream_be(&0x1122u16)
+            .expect("Write should have succeeded");
+
+        assert_eq!(stream.get_index(), 6);
+        assert_eq!(stream.into_raw(), [0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0x22]);
+    }
+}
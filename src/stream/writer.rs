@@ -1,21 +1,33 @@
 use super::cursor::Cursor;
-use crate::{EndianWrite, Writer, WriterResult};
+use crate::{
+    alignment_padding, checked_offset_end, fits_at, incomplete_iter_write, total_size, EndianWrite,
+    Error, Writer, WriterExt, WriterResult,
+};
 use safe_transmute::TriviallyTransmutable;
 
 /// An interface to write values as a stream.
-pub trait StreamWriter: Writer + Cursor {
-    /// Same as [Writer::write], but uses the current stream instead of an offset.
+///
+/// The cursor is only moved past the bytes that were written, so it stays where it was if a write fails.
+///
+/// The `write_array_stream_*_all` and `write_slice_stream_*_all` methods need the `alloc` feature.
+/// Everything else works without allocating.
+pub trait StreamWriter: Writer + Cursor + Sized {
+    /// Same as [WriterExt::write], but uses the current stream instead of an offset.
     #[inline(always)]
     fn write_stream<T: TriviallyTransmutable>(&mut self, value: &T) -> WriterResult<usize> {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.write(index, value)
+        let index = self.get_index();
+        let bytes_written = self.write(index, value)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
     }
 
     /// Same as [StreamWriter::write_stream], but does not write if there is not enough space.
     #[inline(always)]
     fn checked_write_stream<T: TriviallyTransmutable>(&mut self, value: &T) -> usize {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.checked_write(index, value)
+        let index = self.get_index();
+        let bytes_written = self.checked_write(index, value);
+        self.increment_by(bytes_written);
+        bytes_written
     }
 
     /// Same as [Writer::write_le], but uses the current stream instead of an offset.
@@ -28,10 +40,28 @@ pub trait StreamWriter: Writer + Cursor {
     }
 
     /// Same as [StreamWriter::write_stream_le], but does not write if there is not enough space.
+    ///
+    /// The cursor is moved by the bytes written, which is [EndianWrite::get_size] rather than the size of the type.
     #[inline(always)]
     fn checked_write_stream_le<T: EndianWrite>(&mut self, value: &T) -> usize {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.checked_write_le(index, value)
+        let index = self.get_index();
+        if !fits_at(self, index, value.get_size()) {
+            return 0;
+        }
+
+        let bytes_written = self.checked_write_le(index, value);
+        self.increment_by(bytes_written);
+        bytes_written
+    }
+
+    /// Same as [WriterExt::write_le_opt], but uses the current stream instead of an offset.
+    /// The cursor is only moved if the value was written.
+    #[inline(always)]
+    fn write_stream_le_opt<T: EndianWrite>(&mut self, value: &T) -> Option<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_le_opt(index, value)?;
+        self.increment_by(bytes_written);
+        Some(bytes_written)
     }
 
     /// Same as [Writer::write_array_le], but uses the current stream instead of an offset.
@@ -44,7 +74,7 @@ pub trait StreamWriter: Writer + Cursor {
         let mut write_size = 0;
 
         for val in value {
-            self.write_le(index + write_size, val)?;
+            self.write_le(checked_offset_end(index, write_size)?, val)?;
             let size = val.get_size();
             self.increment_by(size);
             write_size += size;
@@ -64,9 +94,11 @@ pub trait StreamWriter: Writer + Cursor {
             return 0;
         }
 
-        let size = value.iter().map(|val| val.get_size()).sum::<usize>();
-        let len = self.get_mut_slice().len();
-        if index + size > len {
+        let size = match total_size(value) {
+            Some(size) => size,
+            None => return 0,
+        };
+        if !fits_at(self, index, size) {
             return 0;
         }
 
@@ -83,10 +115,28 @@ pub trait StreamWriter: Writer + Cursor {
     }
 
     /// Same as [StreamWriter::write_stream_be], but does not write if there is not enough space.
+    ///
+    /// The cursor is moved by the bytes written, which is [EndianWrite::get_size] rather than the size of the type.
     #[inline(always)]
     fn checked_write_stream_be<T: EndianWrite>(&mut self, value: &T) -> usize {
-        let index = self.swap_incremented_index_for_type::<T>();
-        self.checked_write_be(index, value)
+        let index = self.get_index();
+        if !fits_at(self, index, value.get_size()) {
+            return 0;
+        }
+
+        let bytes_written = self.checked_write_be(index, value);
+        self.increment_by(bytes_written);
+        bytes_written
+    }
+
+    /// Same as [WriterExt::write_be_opt], but uses the current stream instead of an offset.
+    /// The cursor is only moved if the value was written.
+    #[inline(always)]
+    fn write_stream_be_opt<T: EndianWrite>(&mut self, value: &T) -> Option<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_be_opt(index, value)?;
+        self.increment_by(bytes_written);
+        Some(bytes_written)
     }
 
     /// Same as [Writer::write_array_be], but uses the current stream instead of an offset.
@@ -99,7 +149,7 @@ pub trait StreamWriter: Writer + Cursor {
         let mut write_size = 0;
 
         for val in value {
-            self.write_be(index + write_size, val)?;
+            self.write_be(checked_offset_end(index, write_size)?, val)?;
             let size = val.get_size();
             self.increment_by(size);
             write_size += size;
@@ -119,413 +169,2544 @@ pub trait StreamWriter: Writer + Cursor {
             return 0;
         }
 
-        let size = value.iter().map(|val| val.get_size()).sum::<usize>();
-        let len = self.get_mut_slice().len();
-        if index + size > len {
+        let size = match total_size(value) {
+            Some(size) => size,
+            None => return 0,
+        };
+        if !fits_at(self, index, size) {
             return 0;
         }
 
         self.write_array_stream_be(value).unwrap_or(0)
     }
 
-    /// Same as [Writer::write_bytes], but uses the current stream instead of an offset.
+    /// Same as [Writer::write_slice_le], but uses the current stream instead of an offset.
     #[inline(always)]
-    fn write_stream_bytes(&mut self, bytes: &[u8]) -> WriterResult<usize> {
-        let index = self.swap_incremented_index(bytes.len());
-        self.write_bytes(index, bytes)
-    }
+    fn write_slice_stream_le<T: EndianWrite>(&mut self, values: &[T]) -> WriterResult<usize> {
+        let index = self.get_index();
+        let mut write_size = 0;
 
-    /// Same as [Writer::checked_write_bytes], but does not write if there is not enough space.
-    #[inline(always)]
-    fn checked_write_stream_bytes(&mut self, bytes: &[u8]) -> usize {
-        let index = self.swap_incremented_index(bytes.len());
-        self.checked_write_bytes(index, bytes)
+        for val in values {
+            self.write_le(checked_offset_end(index, write_size)?, val)?;
+            let size = val.get_size();
+            self.increment_by(size);
+            write_size += size;
+        }
+
+        Ok(write_size)
     }
-}
 
-impl<T> StreamWriter for T where T: Writer + Cursor {}
+    /// Same as [Writer::write_slice_be], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_slice_stream_be<T: EndianWrite>(&mut self, values: &[T]) -> WriterResult<usize> {
+        let index = self.get_index();
+        let mut write_size = 0;
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::Reader;
+        for val in values {
+            self.write_be(checked_offset_end(index, write_size)?, val)?;
+            let size = val.get_size();
+            self.increment_by(size);
+            write_size += size;
+        }
 
-    pub struct MockStream {
-        bytes: [u8; 8],
-        index: usize,
+        Ok(write_size)
     }
 
-    impl MockStream {
-        fn new(bytes: [u8; 8]) -> Self {
-            Self { bytes, index: 0 }
-        }
+    /// Same as [WriterExt::write_iter_le], but uses the current stream instead of an offset.
+    ///
+    /// The cursor is moved past each value as it's written,
+    /// so it points to the value that failed if an error is returned.
+    #[inline(always)]
+    fn write_stream_iter_le<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> WriterResult<usize> {
+        let mut write_size = 0;
 
-        fn get_bytes(&self) -> [u8; 8] {
-            self.bytes.clone()
+        for (items_written, value) in iter.into_iter().enumerate() {
+            let index = self.get_index();
+            write_size += self
+                .write_stream_le(&value)
+                .map_err(|error| incomplete_iter_write(error, index, items_written))?;
         }
-    }
 
-    impl Writer for MockStream {
-        fn get_mut_slice(&mut self) -> &mut [u8] {
-            &mut self.bytes
-        }
+        Ok(write_size)
     }
 
-    impl Reader for MockStream {
-        fn get_slice(&self) -> &[u8] {
-            &self.bytes
-        }
-    }
+    /// Same as [WriterExt::write_iter_be], but uses the current stream instead of an offset.
+    ///
+    /// The cursor is moved past each value as it's written,
+    /// so it points to the value that failed if an error is returned.
+    #[inline(always)]
+    fn write_stream_iter_be<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> WriterResult<usize> {
+        let mut write_size = 0;
 
-    impl Cursor for MockStream {
-        fn get_index(&self) -> usize {
-            self.index
+        for (items_written, value) in iter.into_iter().enumerate() {
+            let index = self.get_index();
+            write_size += self
+                .write_stream_be(&value)
+                .map_err(|error| incomplete_iter_write(error, index, items_written))?;
         }
 
-        fn set_index(&mut self, index: usize) {
-            self.index = index;
-        }
+        Ok(write_size)
     }
 
-    mod write_stream_bytes {
-        use super::*;
-        use crate::Error;
-
-        #[test]
-        fn should_write_bytes() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
-            writer.set_index(2);
-            let written_length = writer
-                .write_stream_bytes(&bytes)
-                .expect("Write should have succeeded");
+    /// Same as [StreamWriter::write_stream_iter_le], but stops at the first value that can't be written
+    /// and returns the number of values that were written.
+    ///
+    /// The cursor is left after the last value that was written.
+    #[inline(always)]
+    fn checked_write_stream_iter_le<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> usize {
+        let mut items_written = 0;
 
-            assert_eq!(written_length, 4);
+        for value in iter {
+            if self.write_stream_le(&value).is_err() {
+                break;
+            }
 
-            let inner = writer.get_bytes();
-            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+            items_written += 1;
         }
 
-        #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
-            writer.set_index(6);
-            let error = writer
-                .write_stream_bytes(&bytes)
-                .expect_err("Length should have been too large");
-
-            assert_eq!(
-                error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
-                    data_len: 8,
-                }
-            );
-        }
+        items_written
     }
 
-    mod checked_write_stream_bytes {
-        use super::*;
-
-        #[test]
-        fn should_write_bytes() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
-            writer.set_index(2);
-            let written_length = writer.checked_write_stream_bytes(&bytes);
+    /// Same as [StreamWriter::write_stream_iter_be], but stops at the first value that can't be written
+    /// and returns the number of values that were written.
+    ///
+    /// The cursor is left after the last value that was written.
+    #[inline(always)]
+    fn checked_write_stream_iter_be<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> usize {
+        let mut items_written = 0;
 
-            assert_eq!(written_length, 4);
+        for value in iter {
+            if self.write_stream_be(&value).is_err() {
+                break;
+            }
 
-            let inner = writer.get_bytes();
-            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+            items_written += 1;
         }
 
-        #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockStream::new(initial_bytes.clone());
-            let bytes_to_write = [0xaa, 0xbb, 0xcc, 0xdd];
-            writer.set_index(6);
-            let written_length = writer.checked_write_stream_bytes(&bytes_to_write);
+        items_written
+    }
 
-            assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), initial_bytes);
-        }
+    /// Same as [WriterExt::write_array_le_all], but uses the current stream instead of an offset.
+    ///
+    /// The cursor is only moved if every value is written.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_array_stream_le_all<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        value: &[T; SIZE],
+    ) -> WriterResult<usize> {
+        self.write_slice_stream_le_all(value)
     }
 
-    mod write_stream {
-        use super::*;
-        use crate::Error;
+    /// Same as [WriterExt::write_array_be_all], but uses the current stream instead of an offset.
+    ///
+    /// The cursor is only moved if every value is written.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_array_stream_be_all<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        value: &[T; SIZE],
+    ) -> WriterResult<usize> {
+        self.write_slice_stream_be_all(value)
+    }
 
-        #[test]
-        fn should_write_value() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            writer.set_index(4);
-            let written_length = writer
-                .write_stream(&value)
-                .expect("Write should have succeeded");
+    /// Same as [WriterExt::write_slice_le_all], but uses the current stream instead of an offset.
+    ///
+    /// The cursor is only moved if every value is written.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_slice_stream_le_all<T: EndianWrite>(&mut self, values: &[T]) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_slice_le_all(index, values)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
 
-            assert_eq!(written_length, 4);
+    /// Same as [WriterExt::write_slice_be_all], but uses the current stream instead of an offset.
+    ///
+    /// The cursor is only moved if every value is written.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_slice_stream_be_all<T: EndianWrite>(&mut self, values: &[T]) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_slice_be_all(index, values)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
 
-            let result = writer.read::<u32>(4).expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
-        }
+    /// Same as [Writer::write_repeated_le], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_repeated_stream_le<T: EndianWrite>(
+        &mut self,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_repeated_le(index, value, count)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
 
-        #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            writer.set_index(6);
-            let error = writer
-                .write_stream(&value)
-                .expect_err("Length should have been too large");
+    /// Same as [Writer::write_repeated_be], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_repeated_stream_be<T: EndianWrite>(
+        &mut self,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_repeated_be(index, value, count)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
 
-            assert_eq!(
-                error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
-                    data_len: 8,
-                }
-            );
+    /// Same as [WriterExt::write_ne], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_stream_ne<T: EndianWrite>(&mut self, value: &T) -> WriterResult<usize> {
+        if cfg!(target_endian = "little") {
+            self.write_stream_le(value)
+        } else {
+            self.write_stream_be(value)
         }
     }
 
-    mod checked_write {
-        use super::*;
-
-        #[test]
-        fn should_write_value() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write(4, &value);
-
-            assert_eq!(written_length, 4);
+    /// Same as [Writer::write_bytes], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_stream_bytes(&mut self, bytes: &[u8]) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_bytes(index, bytes)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
 
-            let result = writer.read::<u32>(4).expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
-        }
+    /// Same as [Writer::checked_write_bytes], but does not write if there is not enough space.
+    #[inline(always)]
+    fn checked_write_stream_bytes(&mut self, bytes: &[u8]) -> usize {
+        let index = self.get_index();
+        let bytes_written = self.checked_write_bytes(index, bytes);
+        self.increment_by(bytes_written);
+        bytes_written
+    }
 
-        #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockStream::new(bytes.clone());
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write(6, &value);
+    /// Same as [Writer::write_bytes_opt], but uses the current stream instead of an offset.
+    /// The cursor is only moved if the bytes were written.
+    #[inline(always)]
+    fn write_stream_bytes_opt(&mut self, bytes: &[u8]) -> Option<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_bytes_opt(index, bytes)?;
+        self.increment_by(bytes_written);
+        Some(bytes_written)
+    }
 
-            assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
-        }
+    /// Same as [Writer::write_bytes_truncated], but uses the current stream instead of an offset.
+    /// The cursor is moved by the number of bytes written.
+    #[inline(always)]
+    fn write_stream_bytes_truncated(&mut self, bytes: &[u8]) -> usize {
+        let index = self.get_index();
+        let bytes_written = self.write_bytes_truncated(index, bytes);
+        self.increment_by(bytes_written);
+        bytes_written
+    }
+
+    /// Same as [Writer::write_all_slices], but uses the current stream instead of an offset.
+    /// The cursor is only moved if the write succeeds.
+    #[inline(always)]
+    fn write_stream_all_slices(&mut self, parts: &[&[u8]]) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_all_slices(index, parts)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Same as [Writer::fill], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn fill_stream(&mut self, len: usize, value: u8) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.fill(index, len, value)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Writes `len` zeroes at the current stream and moves the cursor past them.
+    ///
+    /// The cursor is only moved if the write succeeds.
+    #[inline(always)]
+    fn pad(&mut self, len: usize) -> WriterResult<usize> {
+        self.pad_with(len, 0)
+    }
+
+    /// Same as [StreamWriter::pad], but writes `value` instead of zeroes.
+    #[inline(always)]
+    fn pad_with(&mut self, len: usize, value: u8) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.fill(index, len, value)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Writes `value` up to the next multiple of `align` from the cursor, moves the cursor to it,
+    /// and returns the number of bytes written.
+    ///
+    /// This is the writing counterpart of [StreamReader::align_to](crate::StreamReader::align_to).
+    /// Errors without moving the cursor if `align` is not a power of two, or if the padding will not fit.
+    #[inline(always)]
+    fn pad_to_alignment(&mut self, align: usize, value: u8) -> WriterResult<usize> {
+        let padding = alignment_padding(self.get_index(), align)?;
+        self.pad_with(padding, value)
+    }
+
+    /// Same as [Writer::checked_fill], but does not write if there is not enough space.
+    #[inline(always)]
+    fn checked_fill_stream(&mut self, len: usize, value: u8) -> usize {
+        let index = self.get_index();
+        let bytes_written = self.checked_fill(index, len, value);
+        self.increment_by(bytes_written);
+        bytes_written
+    }
+
+    /// Same as [Writer::write_zeroes], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_zeroes_stream(&mut self, len: usize) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_zeroes(index, len)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Same as [Writer::erase], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn erase_stream(&mut self, len: usize) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.erase(index, len)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Same as [Writer::write_str], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_stream_str(&mut self, value: &str) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_str(index, value)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Same as [Writer::write_ascii], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_stream_ascii(&mut self, value: &str) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_ascii(index, value)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Same as [Writer::write_c_string], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_stream_c_string(&mut self, value: &str) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_c_string(index, value)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Same as [Writer::write_c_string_padded], but uses the current stream instead of an offset.
+    #[inline(always)]
+    fn write_stream_c_string_padded(
+        &mut self,
+        value: &str,
+        field_len: usize,
+    ) -> WriterResult<usize> {
+        let index = self.get_index();
+        let bytes_written = self.write_c_string_padded(index, value, field_len)?;
+        self.increment_by(bytes_written);
+        Ok(bytes_written)
+    }
+
+    /// Pads with `fill` up to the next multiple of `align`, then writes a little endian value.
+    ///
+    /// Returns the total bytes written, including the padding.
+    /// Errors without writing or moving the cursor if `align` is not a power of two or the value doesn't fit.
+    #[inline(always)]
+    fn write_stream_le_padded<T: EndianWrite>(
+        &mut self,
+        value: &T,
+        align: usize,
+        fill: u8,
+    ) -> WriterResult<usize> {
+        let index = self.get_index();
+        let value_offset = checked_offset_end(index, alignment_padding(index, align)?)?;
+        let value_end = checked_offset_end(value_offset, value.get_size())?;
+        self.get_sized_mut_slice(index, value_end - index)?;
+
+        let padding = self.pad_to_alignment(align, fill)?;
+        let bytes_written = self
+            .write_stream_le(value)
+            .inspect_err(|_| self.set_index(index))?;
+        Ok(padding + bytes_written)
+    }
+
+    /// Same as [StreamWriter::write_stream_le_padded], but writes a big endian value.
+    #[inline(always)]
+    fn write_stream_be_padded<T: EndianWrite>(
+        &mut self,
+        value: &T,
+        align: usize,
+        fill: u8,
+    ) -> WriterResult<usize> {
+        let index = self.get_index();
+        let value_offset = checked_offset_end(index, alignment_padding(index, align)?)?;
+        let value_end = checked_offset_end(value_offset, value.get_size())?;
+        self.get_sized_mut_slice(index, value_end - index)?;
+
+        let padding = self.pad_to_alignment(align, fill)?;
+        let bytes_written = self
+            .write_stream_be(value)
+            .inspect_err(|_| self.set_index(index))?;
+        Ok(padding + bytes_written)
+    }
+
+    /// Returns the writable bytes from the cursor to the end of the data without moving the cursor.
+    /// Returns an empty slice if the cursor is at or past the end of the data.
+    #[inline(always)]
+    fn remaining_mut_slice(&mut self) -> &mut [u8] {
+        let index = self.get_index();
+        self.get_mut_slice_at_offset(index)
+    }
+
+    /// Writes a default placeholder for a little endian value that isn't known yet, such as a length prefix,
+    /// and returns a [Reservation] that records where it was written.
+    ///
+    /// Use [StreamWriter::backpatch_le] to write the real value once it's known.
+    #[inline(always)]
+    fn reserve_le<T: EndianWrite + Default>(&mut self) -> WriterResult<Reservation> {
+        let offset = self.get_index();
+        let size = self.write_stream_le(&T::default())?;
+        Ok(Reservation { offset, size })
+    }
+
+    /// Same as [StreamWriter::reserve_le], but for a big endian value.
+    ///
+    /// Use [StreamWriter::backpatch_be] to write the real value once it's known.
+    #[inline(always)]
+    fn reserve_be<T: EndianWrite + Default>(&mut self) -> WriterResult<Reservation> {
+        let offset = self.get_index();
+        let size = self.write_stream_be(&T::default())?;
+        Ok(Reservation { offset, size })
+    }
+
+    /// Writes a little endian value at the offset recorded by a [Reservation] without moving the cursor.
+    ///
+    /// Returns an error without writing if the value's size doesn't match the reserved size,
+    /// since it would either overwrite the bytes after the reservation or leave part of the placeholder behind.
+    #[inline(always)]
+    fn backpatch_le<T: EndianWrite>(
+        &mut self,
+        reservation: Reservation,
+        value: &T,
+    ) -> WriterResult<usize> {
+        reservation.check_size(value)?;
+        self.write_le(reservation.offset, value)
+    }
+
+    /// Same as [StreamWriter::backpatch_le], but writes a big endian value.
+    #[inline(always)]
+    fn backpatch_be<T: EndianWrite>(
+        &mut self,
+        reservation: Reservation,
+        value: &T,
+    ) -> WriterResult<usize> {
+        reservation.check_size(value)?;
+        self.write_be(reservation.offset, value)
+    }
+}
+
+impl<T> StreamWriter for T where T: Writer + Cursor {}
+
+/// The location of a placeholder written by [StreamWriter::reserve_le] or [StreamWriter::reserve_be].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Reservation {
+    offset: usize,
+    size: usize,
+}
+
+impl Reservation {
+    /// Returns the offset the placeholder was written at.
+    #[inline(always)]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of bytes reserved for the value.
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the offset right after the placeholder, which is where the reserved block's body starts.
+    #[inline(always)]
+    pub fn end(&self) -> usize {
+        self.offset + self.size
+    }
+
+    #[inline(always)]
+    fn check_size<T: EndianWrite>(&self, value: &T) -> WriterResult<()> {
+        if value.get_size() != self.size {
+            return Err(Error::InvalidWrite {
+                message: "Value size does not match the reservation",
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Reader, ReaderExt};
+
+    pub struct MockStream {
+        bytes: [u8; 8],
+        index: usize,
+    }
+
+    impl MockStream {
+        fn new(bytes: [u8; 8]) -> Self {
+            Self { bytes, index: 0 }
+        }
+
+        fn get_bytes(&self) -> [u8; 8] {
+            self.bytes
+        }
+    }
+
+    impl Writer for MockStream {
+        fn get_mut_slice(&mut self) -> &mut [u8] {
+            &mut self.bytes
+        }
+    }
+
+    impl Reader for MockStream {
+        fn get_slice(&self) -> &[u8] {
+            &self.bytes
+        }
+    }
+
+    impl Cursor for MockStream {
+        fn get_index(&self) -> usize {
+            self.index
+        }
+
+        fn set_index(&mut self, index: usize) {
+            self.index = index;
+        }
+    }
+
+    mod write_stream_bytes {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_write_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_bytes(&bytes)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let inner = writer.get_bytes();
+            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            writer.set_index(6);
+            let error = writer
+                .write_stream_bytes(&bytes)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+        }
+    }
+
+    mod checked_write_stream_bytes {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_an_empty_vector() {
+            let mut writer = StreamContainer::new(vec![]);
+            let written_length = writer.checked_write_stream_bytes(&[0xaa, 0xbb]);
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.into_raw(), [0xaa, 0xbb]);
+        }
+
+        #[test]
+        fn should_write_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            writer.set_index(2);
+            let written_length = writer.checked_write_stream_bytes(&bytes);
+
+            assert_eq!(written_length, 4);
+
+            let inner = writer.get_bytes();
+            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(initial_bytes);
+            let bytes_to_write = [0xaa, 0xbb, 0xcc, 0xdd];
+            writer.set_index(6);
+            let written_length = writer.checked_write_stream_bytes(&bytes_to_write);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), initial_bytes);
+        }
+    }
+
+    mod fill_stream {
+        use super::*;
+        use crate::Error;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_fill_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer
+                .fill_stream(3, 0)
+                .expect("Fill should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 2, 0, 0, 0, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let error = writer
+                .fill_stream(4, 0)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut stream = crate::StreamContainer::new(vec![1]);
+            stream.set_index(1);
+            stream
+                .fill_stream(3, 0xff)
+                .expect("Fill should have succeeded");
+
+            assert_eq!(stream.get_index(), 4);
+            assert_eq!(stream.into_raw(), [1, 0xff, 0xff, 0xff]);
+        }
+    }
+
+    mod checked_fill_stream {
+        use super::*;
+
+        #[test]
+        fn should_fill_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let written_length = writer.checked_fill_stream(2, 0xaa);
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 0xaa, 0xaa]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(initial_bytes);
+            writer.set_index(7);
+            let written_length = writer.checked_fill_stream(2, 0xaa);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), initial_bytes);
+        }
+    }
+
+    mod write_zeroes_stream {
+        use super::*;
+
+        #[test]
+        fn should_zero_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(3);
+            let written_length = writer
+                .write_zeroes_stream(2)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 0, 0, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_move_the_cursor_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(7);
+            writer
+                .write_zeroes_stream(2)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod erase_stream {
+        use super::*;
+
+        #[test]
+        fn should_zero_bytes() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer.erase_stream(4).expect("Erase should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0, 0, 0, 0, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let error = writer
+                .erase_stream(4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod pad {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_zeroes_to_the_end() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(5);
+            let written_length = writer.pad(3).expect("Pad should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_not_write_or_move_the_cursor_past_the_end() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let error = writer
+                .pad(3)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = StreamContainer::new(vec![1, 2]);
+            writer.set_index(1);
+            let written_length = writer.pad(3).expect("Pad should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.into_raw(), [1, 0, 0, 0]);
+        }
+    }
+
+    mod pad_with {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_the_value_to_the_end() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let written_length = writer.pad_with(2, 0xff).expect("Pad should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 0xff, 0xff]);
+        }
+
+        #[test]
+        fn should_not_write_or_move_the_cursor_past_the_end() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(8);
+            writer
+                .pad_with(1, 0xff)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = StreamContainer::new(vec![]);
+            writer.pad_with(2, 0x20).expect("Pad should have succeeded");
+
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.into_raw(), [0x20, 0x20]);
+        }
+    }
+
+    mod pad_to_alignment {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_not_write_at_aligned_cursors() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(4);
+            let written_length = writer
+                .pad_to_alignment(4, 0xff)
+                .expect("Pad should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_pad_odd_cursors() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let written_length = writer
+                .pad_to_alignment(4, 0xff)
+                .expect("Pad should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.get_bytes(), [1, 0xff, 0xff, 0xff, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_alignment_is_past_the_end() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(5);
+            let error = writer
+                .pad_to_alignment(16, 0xff)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 11,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_alignments() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let error = writer
+                .pad_to_alignment(3, 0xff)
+                .expect_err("Alignment should have been invalid");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Alignment must be a power of two",
+                }
+            );
+            assert_eq!(writer.get_index(), 1);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = StreamContainer::new(vec![1]);
+            writer.set_index(1);
+            writer
+                .pad_to_alignment(4, 0)
+                .expect("Pad should have succeeded");
+
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.into_raw(), [1, 0, 0, 0]);
+        }
+    }
+
+    mod write_stream_bytes_truncated {
+        use super::*;
+
+        #[test]
+        fn should_write_bytes_that_fit_exactly() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let written_length = writer.write_stream_bytes_truncated(&[0xaa, 0xbb]);
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 0xaa, 0xbb]);
+        }
+
+        #[test]
+        fn should_write_bytes_that_fit() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(5);
+            let written_length = writer.write_stream_bytes_truncated(&[0xaa, 0xbb, 0xcc, 0xdd]);
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 0xaa, 0xbb, 0xcc]);
+        }
+
+        #[test]
+        fn should_not_move_cursor_if_there_is_no_space() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(8);
+            let written_length = writer.write_stream_bytes_truncated(&[0xaa]);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_stream_all_slices {
+        use super::*;
+
+        #[test]
+        fn should_write_slices_and_move_the_cursor() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_all_slices(&[&[0xaa, 0xbb], &[0xcc]])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 2, 0xaa, 0xbb, 0xcc, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_write_or_move_the_cursor_on_error() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(6);
+            let error = writer
+                .write_stream_all_slices(&[&[0xaa, 0xbb], &[0xcc]])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_repeated_stream_le {
+        use super::*;
+
+        #[test]
+        fn should_write_value_repeatedly() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer
+                .write_repeated_stream_le(&0xaabbu16, 2)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0xbb, 0xaa, 0xbb, 0xaa, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_move_cursor_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(4);
+            writer
+                .write_repeated_stream_le(&0xaabbu16, 3)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_repeated_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value_repeatedly() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(5);
+            let written_length = writer
+                .write_repeated_stream_be(&0xaau8, 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 0xaa, 0xaa, 0xaa]);
+        }
+    }
+
+    mod write_stream {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(4);
+            let written_length = writer
+                .write_stream(&value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer.read::<u32>(4).expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(6);
+            let error = writer
+                .write_stream(&value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_index(), 6);
+        }
+    }
+
+    mod checked_write {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write(4, &value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer.read::<u32>(4).expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod checked_write_stream {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(4);
+            let written_length = writer.checked_write_stream(&0xaabbccddu32);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 8);
+
+            let result = writer.read::<u32>(4).expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_and_not_move_the_cursor_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            writer.set_index(6);
+            let written_length = writer.checked_write_stream(&0xaabbccddu32);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
     }
 
     mod write_stream_le {
         use super::*;
-        use crate::Error;
+        use crate::Error;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_le(&value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(6);
+            let error = writer
+                .write_stream_le(&value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Repeat(u8);
+
+        impl EndianWrite for Repeat {
+            fn get_size(&self) -> usize {
+                3
+            }
+
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                let bytes: [u8; 3] = [self.0, self.0, self.0];
+                dst[0..3].copy_from_slice(&bytes);
+                Ok(bytes.len())
+            }
+
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[test]
+        fn should_write_values_with_dynamic_read_lengths() {
+            let mut writer = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
+            let written_bytes = writer
+                .write_stream_le(&Repeat(0x50))
+                .expect("Should have been written successfully");
+            assert_eq!(written_bytes, 3);
+
+            let result = writer.get_bytes();
+            let expected = [0x50, 0x50, 0x50, 0xbb, 0x88, 0x99, 0x01, 0x02];
+            assert_eq!(result, expected);
+        }
+    }
+
+    mod checked_write_stream_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_an_empty_vector() {
+            let mut writer = StreamContainer::new(vec![]);
+            let written_length = writer.checked_write_stream_le(&0xaabbccddu32);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.into_raw(), [0xdd, 0xcc, 0xbb, 0xaa]);
+        }
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(2);
+            let written_length = writer.checked_write_stream_le(&value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            let value = 0xaabbccddu32;
+            writer.set_index(6);
+            let written_length = writer.checked_write_stream_le(&value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+
+        #[test]
+        fn should_move_the_cursor_by_the_written_size() {
+            let mut checked_writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            checked_writer.set_index(1);
+            let checked_length = checked_writer.checked_write_stream_le(&Triple(0x50));
+            checked_writer.checked_write_stream_le(&Triple(0x50));
+
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let length = writer
+                .write_stream_le(&Triple(0x50))
+                .expect("Write should have succeeded");
+            writer
+                .write_stream_le(&Triple(0x50))
+                .expect("Write should have succeeded");
+
+            assert_eq!(checked_length, 3);
+            assert_eq!(checked_length, length);
+            assert_eq!(checked_writer.get_index(), 7);
+            assert_eq!(checked_writer.get_index(), writer.get_index());
+            assert_eq!(
+                checked_writer.get_bytes(),
+                [1, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 8]
+            );
+        }
+
+        #[test]
+        fn should_return_0_if_the_written_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            writer.set_index(6);
+            let written_length = writer.checked_write_stream_le(&Triple(0x50));
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod checked_write_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer.checked_write_stream_be(&0xaabbccddu32);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_0_and_not_move_the_cursor_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            writer.set_index(6);
+            let written_length = writer.checked_write_stream_be(&0xaabbccddu32);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+
+        #[test]
+        fn should_move_the_cursor_by_the_written_size() {
+            let mut checked_writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            checked_writer.set_index(1);
+            let checked_length = checked_writer.checked_write_stream_be(&Triple(0x50));
+            checked_writer.checked_write_stream_be(&Triple(0x50));
+
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let length = writer
+                .write_stream_be(&Triple(0x50))
+                .expect("Write should have succeeded");
+            writer
+                .write_stream_be(&Triple(0x50))
+                .expect("Write should have succeeded");
+
+            assert_eq!(checked_length, 3);
+            assert_eq!(checked_length, length);
+            assert_eq!(checked_writer.get_index(), 7);
+            assert_eq!(checked_writer.get_index(), writer.get_index());
+            assert_eq!(
+                checked_writer.get_bytes(),
+                [1, 0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 8]
+            );
+        }
+
+        #[test]
+        fn should_return_0_if_the_written_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            writer.set_index(6);
+            let written_length = writer.checked_write_stream_be(&Triple(0x50));
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_stream_bytes_opt {
+        use super::*;
+
+        #[test]
+        fn should_write_bytes_and_move_the_cursor() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            assert_eq!(writer.write_stream_bytes_opt(&[0xaa, 0xbb]), Some(2));
+            assert_eq!(writer.get_index(), 3);
+            assert_eq!(writer.get_bytes(), [1, 0xaa, 0xbb, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_move_the_cursor_if_the_bytes_were_not_written() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(7);
+            assert_eq!(writer.write_stream_bytes_opt(&[0xaa, 0xbb]), None);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_stream_le_opt {
+        use super::*;
+
+        #[test]
+        fn should_write_value_and_move_the_cursor() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(6);
+            assert_eq!(writer.write_stream_le_opt(&0xaabbu16), Some(2));
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes()[6..], [0xbb, 0xaa]);
+        }
+
+        #[test]
+        fn should_return_some_for_zero_sized_values() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(8);
+            assert_eq!(writer.write_stream_le_opt(&()), Some(0));
+            assert_eq!(writer.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_move_the_cursor_if_the_value_was_not_written() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(7);
+            assert_eq!(writer.write_stream_le_opt(&0xaabbu16), None);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+    }
+
+    mod write_stream_be_opt {
+        use super::*;
+
+        #[test]
+        fn should_write_value_and_move_the_cursor() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(6);
+            assert_eq!(writer.write_stream_be_opt(&0xaabbu16), Some(2));
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes()[6..], [0xaa, 0xbb]);
+        }
+
+        #[test]
+        fn should_return_some_for_zero_sized_values() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(8);
+            assert_eq!(writer.write_stream_be_opt(&()), Some(0));
+            assert_eq!(writer.get_index(), 8);
+        }
+
+        #[test]
+        fn should_not_move_the_cursor_if_the_value_was_not_written() {
+            let mut writer = MockStream::new([0; 8]);
+            writer.set_index(7);
+            assert_eq!(writer.write_stream_be_opt(&0xaabbu16), None);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+    }
+
+    mod write_array_stream_le {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344];
+            writer.set_index(2);
+            let written_length = writer
+                .write_array_stream_le(&value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_array_le::<2, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344];
+            writer.set_index(6);
+            let error = writer
+                .write_array_stream_le(&value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[derive(Debug, PartialEq)]
+        struct Repeat(u8);
+
+        impl EndianWrite for Repeat {
+            fn get_size(&self) -> usize {
+                3
+            }
+
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                let bytes: [u8; 3] = [self.0, self.0, self.0];
+                dst[0..3].copy_from_slice(&bytes);
+                Ok(bytes.len())
+            }
+
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[test]
+        fn should_write_values_with_dynamic_read_lengths() {
+            let mut writer = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
+            let written_bytes = writer
+                .write_array_stream_le(&[Repeat(0x50), Repeat(0x50)])
+                .expect("Should have been written successfully");
+            assert_eq!(written_bytes, 6);
+
+            let result = writer.get_bytes();
+            let expected = [0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x01, 0x02];
+            assert_eq!(result, expected);
+        }
+    }
+
+    mod checked_write_array_stream_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_an_empty_vector() {
+            let mut writer = StreamContainer::new(vec![]);
+            let written_length = writer.checked_write_array_stream_le(&[0x1122u16, 0x3344]);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 4);
+            assert_eq!(writer.into_raw(), [0x22, 0x11, 0x44, 0x33]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = StreamContainer::new(vec![1, 2]);
+            writer.set_index(2);
+            let written_length = writer.checked_write_array_stream_le(&[0x1122u16, 0x3344]);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.into_raw(), [1, 2, 0x22, 0x11, 0x44, 0x33]);
+        }
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344];
+            writer.set_index(2);
+            let written_length = writer.checked_write_array_stream_le(&value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_array_le::<2, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockStream::new(bytes);
+            let value = [0x1122u16, 0x3344];
+            writer.set_index(6);
+            let written_length = writer.checked_write_array_stream_le(&value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+
+        #[test]
+        fn should_write_values_of_different_sizes() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(1);
+            let value = [Prefixed(&[1, 2, 3, 4]), Prefixed(&[])];
+            let written_length = writer.checked_write_array_stream_le(&value);
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [0xff, 4, 1, 2, 3, 4, 0, 0xff]);
+        }
+
+        #[test]
+        fn should_return_0_if_later_values_are_too_large() {
+            let bytes = [0xff; 8];
+            let mut writer = MockStream::new(bytes);
+            let value = [Prefixed(&[]), Prefixed(&[1, 2, 3, 4, 5, 6, 7])];
+            let written_length = writer.checked_write_array_stream_le(&value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod checked_write_array_stream_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer.checked_write_array_stream_be(&[0x1122u16, 0x3344]);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x11, 0x22, 0x33, 0x44, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_values_of_different_sizes() {
+            let mut writer = MockStream::new([0xff; 8]);
+            writer.set_index(1);
+            let value = [Prefixed(&[1, 2, 3, 4]), Prefixed(&[])];
+            let written_length = writer.checked_write_array_stream_be(&value);
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [0xff, 4, 1, 2, 3, 4, 0, 0xff]);
+        }
+
+        #[test]
+        fn should_return_0_if_later_values_are_too_large() {
+            let bytes = [0xff; 8];
+            let mut writer = MockStream::new(bytes);
+            let value = [Prefixed(&[]), Prefixed(&[1, 2, 3, 4, 5, 6, 7])];
+            let written_length = writer.checked_write_array_stream_be(&value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_stream_ne {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_ne(&value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+
+            #[cfg(target_endian = "little")]
+            assert_eq!(writer.get_bytes(), [1, 2, 0xdd, 0xcc, 0xbb, 0xaa, 7, 8]);
+            #[cfg(target_endian = "big")]
+            assert_eq!(writer.get_bytes(), [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            writer.set_index(6);
+            let error = writer
+                .write_stream_ne(&value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod write_slice_stream_le {
+        use super::*;
+        use crate::Error;
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = vec![0x1122u16, 0x3344];
+            writer.set_index(2);
+            let written_length = writer
+                .write_slice_stream_le(&value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x22, 0x11, 0x44, 0x33, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = vec![0x1122u16, 0x3344];
+            writer.set_index(6);
+            let error = writer
+                .write_slice_stream_le(&value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut stream = crate::StreamContainer::new(vec![]);
+            stream
+                .write_slice_stream_le(&[0x1122u16, 0x3344])
+                .expect("Write should have succeeded");
+
+            assert_eq!(stream.get_index(), 4);
+            assert_eq!(stream.into_raw(), [0x22, 0x11, 0x44, 0x33]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod write_slice_stream_be {
+        use super::*;
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = vec![0x1122u16, 0x3344];
+            writer.set_index(2);
+            let written_length = writer
+                .write_slice_stream_be(&value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x11, 0x22, 0x33, 0x44, 7, 8]);
+        }
+    }
+
+    mod write_stream_str {
+        use super::*;
+
+        #[test]
+        fn should_write_string() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(3);
+            let written_length = writer
+                .write_stream_str("\u{e9}!")
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 0xc3, 0xa9, b'!', 7, 8]);
+        }
+    }
+
+    mod write_stream_ascii {
+        use super::*;
+
+        #[test]
+        fn should_write_string() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_stream_ascii("ab")
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.get_bytes(), [b'a', b'b', 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_move_cursor_if_string_is_not_ascii() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer
+                .write_stream_ascii("\u{e9}")
+                .expect_err("String should have been rejected");
+
+            assert_eq!(writer.get_index(), 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_stream_c_string {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn should_write_string_with_terminator() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let written_length = writer
+                .write_stream_c_string("abc")
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, b'a', b'b', b'c', 0, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_move_cursor_if_string_contains_null_byte() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let error = writer
+                .write_stream_c_string("a\0")
+                .expect_err("String should have been rejected");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String contains an interior null byte",
+                }
+            );
+            assert_eq!(writer.get_index(), 1);
+        }
+    }
+
+    mod write_stream_c_string_padded {
+        use super::*;
+        use crate::Error;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_padded_fields() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer
+                .write_stream_c_string_padded("ab", 4)
+                .expect("Write should have succeeded");
+            let written_length = writer
+                .write_stream_c_string_padded("c", 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_index(), 7);
+            assert_eq!(writer.get_bytes(), [b'a', b'b', 0, 0, b'c', 0, 0, 8]);
+        }
+
+        #[test]
+        fn should_not_move_cursor_if_string_does_not_fit_in_the_field() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let error = writer
+                .write_stream_c_string_padded("abc", 3)
+                .expect_err("String should have been too long");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String does not fit in the field width",
+                }
+            );
+            assert_eq!(writer.get_index(), 1);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector() {
+            let mut writer = StreamContainer::new(vec![]);
+            writer
+                .write_stream_c_string_padded("ab", 4)
+                .expect("Write should have succeeded");
+            writer
+                .write_stream_c_string("c")
+                .expect("Write should have succeeded");
+
+            assert_eq!(writer.into_raw(), [b'a', b'b', 0, 0, b'c', 0]);
+        }
+    }
+
+    /// A value written as the same byte three times, which is larger on the wire than in memory.
+    #[derive(Debug)]
+    struct Triple(u8);
+
+    impl EndianWrite for Triple {
+        fn get_size(&self) -> usize {
+            3
+        }
+
+        fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+            if dst.len() < 3 {
+                return Err(Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 0,
+                    data_len: dst.len(),
+                });
+            }
+
+            dst[0..3].copy_from_slice(&[self.0; 3]);
+            Ok(3)
+        }
+
+        fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+            self.try_write_le(dst)
+        }
+    }
+
+    /// A value written as a length byte followed by its bytes.
+    #[derive(Debug)]
+    struct Prefixed(&'static [u8]);
+
+    impl EndianWrite for Prefixed {
+        fn get_size(&self) -> usize {
+            self.0.len() + 1
+        }
+
+        fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+            let size = self.get_size();
+
+            if dst.len() < size {
+                return Err(Error::InvalidSize {
+                    wanted_size: size,
+                    offset: 0,
+                    data_len: dst.len(),
+                });
+            }
+
+            dst[0] = self.0.len() as u8;
+            dst[1..size].copy_from_slice(self.0);
+            Ok(size)
+        }
+
+        fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+            self.try_write_le(dst)
+        }
+    }
+
+    mod write_stream_iter_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_values() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_iter_le((1..=2u16).map(|value| value * 0x0102))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x02, 0x01, 0x04, 0x02, 7, 8]);
+        }
+
+        #[test]
+        fn should_stop_at_the_failing_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+            let error = writer
+                .write_stream_iter_le([0x11223344u32, 0x55667788])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::IncompleteIterWrite {
+                    offset: 5,
+                    items_written: 1,
+                    #[cfg(feature = "alloc")]
+                    source: alloc::boxed::Box::new(Error::InvalidSize {
+                        wanted_size: 4,
+                        offset: 5,
+                        data_len: 8,
+                    }),
+                }
+            );
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 0x44, 0x33, 0x22, 0x11, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut stream = crate::StreamContainer::new(vec![]);
+            stream
+                .write_stream_iter_le([1u8, 2, 3])
+                .expect("Write should have succeeded");
+
+            assert_eq!(stream.get_index(), 3);
+            assert_eq!(stream.into_raw(), [1, 2, 3]);
+        }
+
+        #[test]
+        fn should_write_nothing_for_an_empty_iterator() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            let written_length = writer
+                .write_stream_iter_le(core::iter::empty::<u32>())
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_values_with_dynamic_sizes() {
+            let mut writer = MockStream::new([0; 8]);
+            let written_length = writer
+                .write_stream_iter_le([Prefixed(&[0xaa]), Prefixed(&[0xbb, 0xcc, 0xdd])])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 0xaa, 3, 0xbb, 0xcc, 0xdd, 0, 0]);
+        }
+    }
+
+    mod write_stream_iter_be {
+        use super::*;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_values() {
             let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             writer.set_index(2);
             let written_length = writer
-                .write_stream_le(&value)
+                .write_stream_iter_be([0x0102u16, 0x0304])
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
-
-            let result = writer
-                .read_le::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x01, 0x02, 0x03, 0x04, 7, 8]);
         }
+    }
+
+    mod checked_write_stream_iter_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_write_values_that_fit() {
             let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            writer.set_index(6);
-            let error = writer
-                .write_stream_le(&value)
-                .expect_err("Length should have been too large");
+            writer.set_index(2);
+            let items_written =
+                writer.checked_write_stream_iter_le([0x11223344u32, 0x55667788, 0x99aabbcc]);
 
-            assert_eq!(
-                error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
-                    data_len: 8,
-                }
-            );
+            assert_eq!(items_written, 1);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x44, 0x33, 0x22, 0x11, 7, 8]);
         }
 
-        #[derive(Debug, PartialEq)]
-        struct Repeat(u8);
-
-        impl EndianWrite for Repeat {
-            fn get_size(&self) -> usize {
-                3
-            }
+        #[test]
+        fn should_write_nothing_for_an_empty_iterator() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let items_written = writer.checked_write_stream_iter_le(core::iter::empty::<u16>());
 
-            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
-                let bytes: [u8; 3] = [self.0, self.0, self.0];
-                dst[0..3].copy_from_slice(&bytes);
-                Ok(bytes.len())
-            }
+            assert_eq!(items_written, 0);
+            assert_eq!(writer.get_index(), 0);
+        }
 
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
+        #[test]
+        fn should_write_values_with_dynamic_sizes() {
+            let mut writer = MockStream::new([0; 8]);
+            let items_written = writer.checked_write_stream_iter_le([
+                Prefixed(&[0xaa]),
+                Prefixed(&[0xbb, 0xcc]),
+                Prefixed(&[0xdd, 0xee, 0xff]),
+            ]);
+
+            assert_eq!(items_written, 2);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 0xaa, 2, 0xbb, 0xcc, 0, 0, 0]);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
-        fn should_write_values_with_dynamic_read_lengths() {
-            let mut writer = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
-            let written_bytes = writer
-                .write_stream_le(&Repeat(0x50))
-                .expect("Should have been written successfully");
-            assert_eq!(written_bytes, 3);
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = StreamContainer::new(vec![]);
+            let items_written = writer.checked_write_stream_iter_le([1u8, 2, 3]);
 
-            let result = writer.get_bytes();
-            let expected = [0x50, 0x50, 0x50, 0xbb, 0x88, 0x99, 0x01, 0x02];
-            assert_eq!(result, expected);
+            assert_eq!(items_written, 3);
+            assert_eq!(writer.into_raw(), [1, 2, 3]);
         }
     }
 
-    mod checked_write_stream_le {
+    mod checked_write_stream_iter_be {
         use super::*;
+        #[cfg(feature = "alloc")]
+        use crate::StreamContainer;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_values_that_fit() {
             let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             writer.set_index(2);
-            let written_length = writer.checked_write_stream_le(&value);
+            let items_written =
+                writer.checked_write_stream_iter_be([0x11223344u32, 0x55667788, 0x99aabbcc]);
 
-            assert_eq!(written_length, 4);
+            assert_eq!(items_written, 1);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x11, 0x22, 0x33, 0x44, 7, 8]);
+        }
 
-            let result = writer
-                .read_le::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+        #[test]
+        fn should_write_nothing_for_an_empty_iterator() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let items_written = writer.checked_write_stream_iter_be(core::iter::empty::<u16>());
+
+            assert_eq!(items_written, 0);
+            assert_eq!(writer.get_index(), 0);
         }
 
         #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockStream::new(bytes.clone());
-            let value = 0xaabbccddu32;
-            writer.set_index(6);
-            let written_length = writer.checked_write_stream_le(&value);
+        fn should_write_values_with_dynamic_sizes() {
+            let mut writer = MockStream::new([0; 8]);
+            let items_written = writer.checked_write_stream_iter_be([
+                Prefixed(&[0xaa]),
+                Prefixed(&[0xbb, 0xcc]),
+                Prefixed(&[0xdd, 0xee, 0xff]),
+            ]);
+
+            assert_eq!(items_written, 2);
+            assert_eq!(writer.get_index(), 5);
+            assert_eq!(writer.get_bytes(), [1, 0xaa, 2, 0xbb, 0xcc, 0, 0, 0]);
+        }
 
-            assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = StreamContainer::new(vec![]);
+            let items_written = writer.checked_write_stream_iter_be([1u8, 2, 3]);
+
+            assert_eq!(items_written, 3);
+            assert_eq!(writer.into_raw(), [1, 2, 3]);
         }
     }
 
-    mod write_array_stream_le {
+    #[cfg(feature = "alloc")]
+    mod write_array_stream_le_all {
         use super::*;
-        use crate::Error;
+
+        #[derive(Debug)]
+        struct FailingValue(u16, bool);
+
+        impl EndianWrite for FailingValue {
+            fn get_size(&self) -> usize {
+                2
+            }
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                if self.1 {
+                    return Err(Error::InvalidWrite {
+                        message: "Custom error!",
+                    });
+                }
+                dst[..2].copy_from_slice(&self.0.to_le_bytes());
+                Ok(2)
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
 
         #[test]
         fn should_write_value() {
             let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344];
             writer.set_index(2);
             let written_length = writer
-                .write_array_stream_le(&value)
+                .write_array_stream_le_all(&[0x1122u16, 0x3344])
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x22, 0x11, 0x44, 0x33, 7, 8]);
+        }
 
-            let result = writer
-                .read_array_le::<2, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344]);
+        #[test]
+        fn should_restore_original_bytes_and_cursor_if_a_value_fails() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(2);
+            writer
+                .write_array_stream_le_all(&[
+                    FailingValue(0x1122, false),
+                    FailingValue(0x3344, true),
+                ])
+                .expect_err("Write should have failed");
+
+            assert_eq!(writer.get_index(), 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod write_slice_stream_be_all {
+        use super::*;
+
+        #[test]
+        fn should_write_values() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(4);
+            let written_length = writer
+                .write_slice_stream_be_all(&[0x1122u16, 0x3344])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0x11, 0x22, 0x33, 0x44]);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_not_write_anything_if_size_is_too_large_for_offset() {
             let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344];
             writer.set_index(6);
+            writer
+                .write_slice_stream_be_all(&[0x1122u16, 0x3344])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(writer.get_index(), 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_stream_le_padded {
+        use super::*;
+
+        #[test]
+        fn should_pad_before_the_value() {
+            for (index, padding, expected) in [
+                (1, 3, [1, 0xff, 0xff, 0xff, 0xbb, 0xaa, 7, 8]),
+                (2, 2, [1, 2, 0xff, 0xff, 0xbb, 0xaa, 7, 8]),
+                (3, 1, [1, 2, 3, 0xff, 0xbb, 0xaa, 7, 8]),
+                (4, 0, [1, 2, 3, 4, 0xbb, 0xaa, 7, 8]),
+            ] {
+                let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+                writer.set_index(index);
+                let written = writer
+                    .write_stream_le_padded(&0xaabbu16, 4, 0xff)
+                    .expect("Write should have succeeded");
+
+                assert_eq!(written, padding + 2);
+                assert_eq!(writer.get_index(), 6);
+                assert_eq!(writer.get_bytes(), expected);
+            }
+        }
+
+        #[test]
+        fn should_not_write_or_move_the_cursor_if_the_value_does_not_fit() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
             let error = writer
-                .write_array_stream_le(&value)
+                .write_stream_le_padded(&0u32, 8, 0xff)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
-                    wanted_size: 2,
-                    offset: 8,
+                    wanted_size: 11,
+                    offset: 1,
                     data_len: 8,
                 }
             );
+            assert_eq!(writer.get_index(), 1);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
-        #[derive(Debug, PartialEq)]
-        struct Repeat(u8);
-
-        impl EndianWrite for Repeat {
-            fn get_size(&self) -> usize {
-                3
+        #[test]
+        fn should_return_error_for_invalid_alignments() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(1);
+
+            for align in [0, 3, 6] {
+                let error = writer
+                    .write_stream_le_padded(&0u16, align, 0xff)
+                    .expect_err("Alignment should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidWrite {
+                        message: "Alignment must be a power of two",
+                    }
+                );
             }
+            assert_eq!(writer.get_index(), 1);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
 
-            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
-                let bytes: [u8; 3] = [self.0, self.0, self.0];
-                dst[0..3].copy_from_slice(&bytes);
-                Ok(bytes.len())
-            }
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            use crate::StreamContainer;
+            use alloc::vec;
+
+            let mut writer = StreamContainer::new(vec![1]);
+            writer.set_index(1);
+            let written = writer
+                .write_stream_le_padded(&0xaabbccddu32, 4, 0xff)
+                .expect("Write should have succeeded");
 
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
+            assert_eq!(written, 7);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(
+                writer.into_raw(),
+                [1, 0xff, 0xff, 0xff, 0xdd, 0xcc, 0xbb, 0xaa]
+            );
         }
+    }
+
+    mod write_stream_be_padded {
+        use super::*;
 
         #[test]
-        fn should_write_values_with_dynamic_read_lengths() {
-            let mut writer = MockStream::new([0x11, 0x22, 0xaa, 0xbb, 0x88, 0x99, 0x01, 0x02]);
-            let written_bytes = writer
-                .write_array_stream_le(&[Repeat(0x50), Repeat(0x50)])
-                .expect("Should have been written successfully");
-            assert_eq!(written_bytes, 6);
+        fn should_pad_before_the_value() {
+            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer.set_index(5);
+            let written = writer
+                .write_stream_be_padded(&0xaabbu16, 2, 0)
+                .expect("Write should have succeeded");
 
-            let result = writer.get_bytes();
-            let expected = [0x50, 0x50, 0x50, 0x50, 0x50, 0x50, 0x01, 0x02];
-            assert_eq!(result, expected);
+            assert_eq!(written, 3);
+            assert_eq!(writer.get_index(), 8);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 0, 0xaa, 0xbb]);
         }
     }
 
-    mod checked_write_array_stream_le {
+    mod remaining_mut_slice {
         use super::*;
 
         #[test]
-        fn should_write_value() {
-            let mut writer = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344];
-            writer.set_index(2);
-            let written_length = writer.checked_write_array_stream_le(&value);
+        fn should_return_the_bytes_after_the_cursor() {
+            let mut stream = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            stream.set_index(6);
+            stream.remaining_mut_slice().copy_from_slice(&[0xaa, 0xbb]);
 
-            assert_eq!(written_length, 4);
+            assert_eq!(stream.get_index(), 6);
+            assert_eq!(stream.get_bytes(), [1, 2, 3, 4, 5, 6, 0xaa, 0xbb]);
+        }
 
-            let result = writer
-                .read_array_le::<2, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344]);
+        #[test]
+        fn should_return_all_bytes_at_the_start() {
+            let mut stream = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            assert_eq!(stream.remaining_mut_slice().len(), 8);
         }
 
         #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockStream::new(bytes.clone());
-            let value = [0x1122u16, 0x3344];
-            writer.set_index(6);
-            let written_length = writer.checked_write_array_stream_le(&value);
+        fn should_return_an_empty_slice_past_the_end() {
+            let mut stream = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            stream.set_index(10);
+            assert!(stream.remaining_mut_slice().is_empty());
+        }
+    }
 
-            assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+    mod reserve_le {
+        use super::*;
+
+        #[test]
+        fn should_write_a_placeholder_and_move_the_cursor() {
+            let mut stream = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            stream.set_index(1);
+            let reservation = stream
+                .reserve_le::<u16>()
+                .expect("Reserve should have succeeded");
+
+            assert_eq!(reservation.offset(), 1);
+            assert_eq!(reservation.size(), 2);
+            assert_eq!(reservation.end(), 3);
+            assert_eq!(stream.get_index(), 3);
+            assert_eq!(stream.get_bytes(), [1, 0, 0, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut stream = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            stream.set_index(6);
+            let error = stream
+                .reserve_le::<u32>()
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(stream.get_index(), 6);
+            assert_eq!(stream.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            use crate::StreamContainer;
+            use alloc::vec;
+
+            let mut stream = StreamContainer::new(vec![]);
+            let reservation = stream
+                .reserve_le::<u32>()
+                .expect("Reserve should have succeeded");
+            stream
+                .write_stream_bytes(&[0xaa, 0xbb, 0xcc])
+                .expect("Write should have succeeded");
+
+            let body_len = (stream.get_index() - reservation.end()) as u32;
+            stream
+                .backpatch_le(reservation, &body_len)
+                .expect("Backpatch should have succeeded");
+
+            assert_eq!(stream.get_index(), 7);
+            assert_eq!(stream.into_raw(), [3, 0, 0, 0, 0xaa, 0xbb, 0xcc]);
+        }
+    }
+
+    mod reserve_be {
+        use super::*;
+
+        #[test]
+        fn should_write_a_placeholder_and_move_the_cursor() {
+            let mut stream = MockStream::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let reservation = stream
+                .reserve_be::<u32>()
+                .expect("Reserve should have succeeded");
+
+            assert_eq!(reservation.offset(), 0);
+            assert_eq!(reservation.size(), 4);
+            assert_eq!(stream.get_index(), 4);
+            assert_eq!(stream.get_bytes(), [0, 0, 0, 0, 5, 6, 7, 8]);
+        }
+    }
+
+    mod backpatch_le {
+        use super::*;
+
+        #[test]
+        fn should_backpatch_a_block_length() {
+            let mut stream = MockStream::new([0; 8]);
+            let reservation = stream
+                .reserve_le::<u16>()
+                .expect("Reserve should have succeeded");
+            stream
+                .write_stream_le(&0xaabbccddu32)
+                .expect("Write should have succeeded");
+            stream
+                .write_stream_le(&0x11u8)
+                .expect("Write should have succeeded");
+
+            let body_len = (stream.get_index() - reservation.end()) as u16;
+            let written = stream
+                .backpatch_le(reservation, &body_len)
+                .expect("Backpatch should have succeeded");
+
+            assert_eq!(written, 2);
+            assert_eq!(stream.get_index(), 7);
+            assert_eq!(stream.get_bytes(), [5, 0, 0xdd, 0xcc, 0xbb, 0xaa, 0x11, 0]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_value_size_does_not_match() {
+            let mut stream = MockStream::new([0; 8]);
+            let reservation = stream
+                .reserve_le::<u32>()
+                .expect("Reserve should have succeeded");
+            stream
+                .write_stream_le(&0xffu8)
+                .expect("Write should have succeeded");
+
+            let error = stream
+                .backpatch_le(reservation, &Triple(0xaa))
+                .expect_err("Backpatch should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Value size does not match the reservation",
+                }
+            );
+            assert_eq!(stream.get_index(), 5);
+            assert_eq!(stream.get_bytes(), [0, 0, 0, 0, 0xff, 0, 0, 0]);
+        }
+    }
+
+    mod backpatch_be {
+        use super::*;
+
+        #[test]
+        fn should_backpatch_a_block_length() {
+            let mut stream = MockStream::new([0; 8]);
+            stream
+                .write_stream_le(&0x11u8)
+                .expect("Write should have succeeded");
+            let reservation = stream
+                .reserve_be::<u16>()
+                .expect("Reserve should have succeeded");
+            stream
+                .write_stream_bytes(&[0xaa, 0xbb, 0xcc])
+                .expect("Write should have succeeded");
+
+            let body_len = (stream.get_index() - reservation.end()) as u16;
+            stream
+                .backpatch_be(reservation, &body_len)
+                .expect("Backpatch should have succeeded");
+
+            assert_eq!(stream.get_index(), 6);
+            assert_eq!(stream.get_bytes(), [0x11, 0, 3, 0xaa, 0xbb, 0xcc, 0, 0]);
         }
     }
 }
@@ -0,0 +1,240 @@
+use crate::{Cursor, EndianWrite, Writer, WriterResult};
+
+/// A [Writer] that performs every write against two writers.
+///
+/// Writes are made to the first writer, and then to the second writer.
+/// If the first write fails, the second writer is left untouched.
+/// If the second write fails, the first writer keeps what was written to it.
+/// Either way, the error is returned.
+///
+/// [Writer::get_mut_slice] and [Writer::get_sized_mut_slice] only return the first writer's data,
+/// so changes made directly to those slices aren't copied to the second writer.
+/// [Writer::get_sized_mut_slice] still checks, and grows, the second writer so both writers stay the same size.
+///
+/// [Cursor] is implemented when both writers implement it, and keeps both cursors at the same index.
+pub struct TeeWriter<A: Writer, B: Writer> {
+    first: A,
+    second: B,
+}
+
+impl<A: Writer, B: Writer> TeeWriter<A, B> {
+    #[inline(always)]
+    pub fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> (A, B) {
+        (self.first, self.second)
+    }
+}
+
+impl<A: Writer, B: Writer> Writer for TeeWriter<A, B> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self.first.get_mut_slice()
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        self.second.get_sized_mut_slice(offset, length)?;
+        self.first.get_sized_mut_slice(offset, length)
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, offset: usize) -> Option<usize> {
+        match (
+            self.first.writable_len(offset),
+            self.second.writable_len(offset),
+        ) {
+            (Some(first), Some(second)) => Some(core::cmp::min(first, second)),
+            (first, None) => first,
+            (None, second) => second,
+        }
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.first.write_bytes(offset, bytes)?;
+        self.second.write_bytes(offset, bytes)
+    }
+
+    #[inline(always)]
+    fn write_all_slices(&mut self, offset: usize, parts: &[&[u8]]) -> WriterResult<usize> {
+        self.first.write_all_slices(offset, parts)?;
+        self.second.write_all_slices(offset, parts)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        self.first.fill(offset, len, value)?;
+        self.second.fill(offset, len, value)
+    }
+
+    #[inline(always)]
+    fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.first.erase(offset, len)?;
+        self.second.erase(offset, len)
+    }
+
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        src_offset: usize,
+        dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        self.first.copy_within(src_offset, dst_offset, len)?;
+        self.second.copy_within(src_offset, dst_offset, len)
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.first.write_le(offset, value)?;
+        self.second.write_le(offset, value)
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.first.write_be(offset, value)?;
+        self.second.write_be(offset, value)
+    }
+
+    #[inline(always)]
+    fn write_bits_msb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        self.first
+            .write_bits_msb(byte_offset, bit_offset, bit_len, value)?;
+        self.second
+            .write_bits_msb(byte_offset, bit_offset, bit_len, value)
+    }
+
+    #[inline(always)]
+    fn write_bits_lsb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        self.first
+            .write_bits_lsb(byte_offset, bit_offset, bit_len, value)?;
+        self.second
+            .write_bits_lsb(byte_offset, bit_offset, bit_len, value)
+    }
+}
+
+impl<A: Writer + Cursor, B: Writer + Cursor> Cursor for TeeWriter<A, B> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.first.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.first.set_index(index);
+        self.second.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use crate::StreamWriter;
+    use crate::{Error, StreamContainer};
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_write_to_both_writers() {
+        let mut writer = TeeWriter::new(StreamContainer::new(vec![]), StreamContainer::new(vec![]));
+        writer
+            .write_stream_le(&0xaabbccddu32)
+            .expect("Write should have succeeded");
+        writer
+            .write_stream_bytes(&[1, 2])
+            .expect("Write should have succeeded");
+        writer
+            .fill_stream(2, 0xff)
+            .expect("Write should have succeeded");
+        writer
+            .write_stream_c_string("hi")
+            .expect("Write should have succeeded");
+        writer
+            .write_stream_all_slices(&[&[1], &[2]])
+            .expect("Write should have succeeded");
+        writer
+            .write_bits_msb(4, 4, 4, 0xa)
+            .expect("Write should have succeeded");
+
+        let (first, second) = writer.into_inner();
+        let expected = [
+            0xdd, 0xcc, 0xbb, 0xaa, 0x0a, 2, 0xff, 0xff, b'h', b'i', 0, 1, 2,
+        ];
+        assert_eq!(first.into_raw(), expected);
+        assert_eq!(second.into_raw(), expected);
+    }
+
+    #[test]
+    fn should_keep_cursors_together() {
+        let mut writer = TeeWriter::new(
+            StreamContainer::new([0u8; 4]),
+            StreamContainer::new([0u8; 4]),
+        );
+        writer.set_index(2);
+
+        let (first, second) = writer.into_inner();
+        assert_eq!(first.get_index(), 2);
+        assert_eq!(second.get_index(), 2);
+    }
+
+    #[test]
+    fn should_return_errors_from_the_second_writer() {
+        let mut writer = TeeWriter::new([0u8; 8], [0u8; 4]);
+        let error = writer
+            .write_be(2, &0xaabbccddu32)
+            .expect_err("Write should have failed");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+        );
+
+        let (first, second) = writer.into_inner();
+        assert_eq!(first, [0, 0, 0xaa, 0xbb, 0xcc, 0xdd, 0, 0]);
+        assert_eq!(second, [0; 4]);
+    }
+
+    #[test]
+    fn should_truncate_writes_to_the_shorter_writer() {
+        let mut writer = TeeWriter::new([0u8; 16], [0u8; 4]);
+        let written = writer.write_bytes_truncated(0, &[1; 8]);
+
+        assert_eq!(written, 4);
+        let (_, second) = writer.into_inner();
+        assert_eq!(second, [1; 4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_not_write_to_the_second_writer_if_the_first_fails() {
+        let mut writer = TeeWriter::new([0u8; 4], vec![]);
+        writer
+            .write_bytes(2, &[1, 2, 3])
+            .expect_err("Write should have failed");
+
+        let (_, second) = writer.into_inner();
+        assert!(second.is_empty());
+    }
+}
@@ -0,0 +1,784 @@
+use crate::{
+    add_error_context, checked_offset_end, checked_total_size, checksum, validate_bits, Cursor,
+    EndianRead, EndianWrite, Error, ReadOutput, Reader, ReaderResult, Writer, WriterResult,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+/// The largest value [TransformReader] and [TransformWriter] transform on the stack.
+///
+/// Larger values are transformed in a vector when the `alloc` feature is enabled, and error otherwise.
+pub const TRANSFORM_STAGING_SIZE: usize = 64;
+
+/// A [Reader] that applies a transform to every byte of another reader, such as XOR decoding.
+///
+/// The transform is given the offset of each byte in the wrapped reader along with the byte,
+/// and returns the byte that should be read.
+///
+/// Bytes are transformed as they're read, so changes made to the wrapped reader are seen, and no buffer of the whole reader is needed.
+/// Endian reads, including derived types, iterators, and stream reads, decode values from bytes transformed onto the stack.
+/// Byte checks, checksums, bit reads, and byte and string reads also see the transformed bytes.
+///
+/// Methods that return a slice or a reference, such as [Reader::get_slice], [Reader::hexdump],
+/// [crate::ReaderExt::get_transmutable], and borrowed reads, return the untransformed bytes of the wrapped reader.
+pub struct TransformReader<R: Reader, F: Fn(usize, u8) -> u8> {
+    reader: R,
+    transform: F,
+}
+
+impl<R: Reader, F: Fn(usize, u8) -> u8> TransformReader<R, F> {
+    #[inline(always)]
+    pub fn new(reader: R, transform: F) -> Self {
+        Self { reader, transform }
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Returns the transform applied to every byte.
+    #[inline(always)]
+    pub fn transform(&self) -> &F {
+        &self.transform
+    }
+
+    /// Returns the transformed bytes starting at an offset.
+    #[inline(always)]
+    fn transformed<'a>(&'a self, offset: usize, bytes: &'a [u8]) -> impl Iterator<Item = u8> + 'a {
+        bytes
+            .iter()
+            .enumerate()
+            .map(move |(index, byte)| (self.transform)(offset + index, *byte))
+    }
+
+    /// Returns `len` transformed bytes starting at an offset, or errors if there aren't enough bytes.
+    #[inline(always)]
+    fn transformed_of_size(
+        &self,
+        offset: usize,
+        len: usize,
+    ) -> ReaderResult<impl Iterator<Item = u8> + '_> {
+        let bytes = self.reader.get_slice_of_size(offset, len)?;
+        Ok(self.transformed(offset, bytes))
+    }
+
+    /// Transforms the bytes at an offset onto the stack, then decodes the value from them.
+    #[inline(always)]
+    fn read_staged<T>(
+        &self,
+        offset: usize,
+        decode: impl Fn(&[u8]) -> ReaderResult<ReadOutput<T>>,
+    ) -> ReaderResult<ReadOutput<T>> {
+        let bytes = self.reader.get_slice_at_offset(offset);
+        let data_len = self.reader.get_slice().len();
+        let staged = core::cmp::min(bytes.len(), TRANSFORM_STAGING_SIZE);
+        let mut staging = [0; TRANSFORM_STAGING_SIZE];
+
+        for (dst, src) in staging
+            .iter_mut()
+            .zip(self.transformed(offset, &bytes[..staged]))
+        {
+            *dst = src;
+        }
+
+        let result = decode(&staging[..staged]);
+
+        if result.is_ok() || staged == bytes.len() {
+            return add_error_context(result, offset, data_len);
+        }
+
+        // Values that need more bytes than the stack holds are decoded again from every remaining byte,
+        // which also covers decoders that don't report how many bytes they need, such as null terminated strings.
+        #[cfg(feature = "alloc")]
+        {
+            let transformed: Vec<u8> = self.transformed(offset, bytes).collect();
+            add_error_context(decode(&transformed), offset, data_len)
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let needs_more = match result.as_ref().map_err(Error::root) {
+                Err(Error::InvalidSize {
+                    wanted_size,
+                    offset,
+                    ..
+                }) => offset.saturating_add(*wanted_size) > staged,
+                Err(Error::EndOfData { offset, requested }) => {
+                    offset.saturating_add(*requested) > staged
+                }
+                _ => false,
+            };
+
+            if needs_more {
+                return Err(Error::InvalidRead {
+                    message: "Value is too large to transform without alloc",
+                });
+            }
+
+            add_error_context(result, offset, data_len)
+        }
+    }
+
+    /// Transforms the bytes a bit field touches onto the stack, then reads the bits from them.
+    #[inline(always)]
+    fn read_bits(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        read: fn(&[u8; 9], usize, usize, usize) -> ReaderResult<u64>,
+    ) -> ReaderResult<u64> {
+        if bit_len > 64 {
+            return Err(Error::InvalidRead {
+                message: "Bit length must be 64 or less",
+            });
+        }
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let bytes = self
+            .reader
+            .get_slice_of_size(byte_offset, bit_end.div_ceil(8))?;
+
+        // The field starts in the byte at bit_offset / 8, and 64 bits after a bit offset of up to 7 touch at most 9 bytes.
+        let first = bit_offset / 8;
+        let mut staging = [0; 9];
+
+        for (dst, src) in staging
+            .iter_mut()
+            .zip(self.transformed(byte_offset + first, &bytes[first..]))
+        {
+            *dst = src;
+        }
+
+        read(&staging, 0, bit_offset % 8, bit_len)
+    }
+}
+
+impl<R: Reader, F: Fn(usize, u8) -> u8> Reader for TransformReader<R, F> {
+    /// Returns the untransformed bytes of the wrapped reader.
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        self.reader.get_slice()
+    }
+
+    #[inline(always)]
+    fn expect_bytes(&self, offset: usize, expected: &[u8]) -> ReaderResult<()> {
+        let bytes = self.transformed_of_size(offset, expected.len())?;
+
+        for (index, (found, expected)) in bytes.zip(expected).enumerate() {
+            if found != *expected {
+                return Err(Error::UnexpectedByte {
+                    offset: offset + index,
+                    expected: *expected,
+                    found,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn checksum_sum8(&self, offset: usize, len: usize) -> ReaderResult<u8> {
+        Ok(self
+            .transformed_of_size(offset, len)?
+            .fold(0, |sum, byte| sum.wrapping_add(byte)))
+    }
+
+    #[inline(always)]
+    fn checksum_sum16(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+        Ok(self
+            .transformed_of_size(offset, len)?
+            .fold(0, |sum: u16, byte| sum.wrapping_add(byte as u16)))
+    }
+
+    #[inline(always)]
+    fn checksum_sum32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+        Ok(self
+            .transformed_of_size(offset, len)?
+            .fold(0, |sum: u32, byte| sum.wrapping_add(byte as u32)))
+    }
+
+    #[inline(always)]
+    fn crc16_ccitt(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+        Ok(checksum::crc16_ccitt(
+            self.transformed_of_size(offset, len)?,
+        ))
+    }
+
+    #[inline(always)]
+    fn crc32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+        Ok(checksum::crc32(self.transformed_of_size(offset, len)?))
+    }
+
+    #[inline(always)]
+    fn read_bits_msb(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+    ) -> ReaderResult<u64> {
+        self.read_bits(byte_offset, bit_offset, bit_len, <[u8; 9]>::read_bits_msb)
+    }
+
+    #[inline(always)]
+    fn read_bits_lsb(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+    ) -> ReaderResult<u64> {
+        self.read_bits(byte_offset, bit_offset, bit_len, <[u8; 9]>::read_bits_lsb)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_byte_vec(&self, offset: usize, size: usize) -> ReaderResult<Vec<u8>> {
+        Ok(self.transformed_of_size(offset, size)?.collect())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_nt_utf16_le_string(&self, offset: usize) -> ReaderResult<ReadOutput<String>> {
+        let bytes = self.reader.get_slice_at_offset(offset);
+        let transformed: Vec<u8> = self.transformed(offset, bytes).collect();
+        transformed.read_nt_utf16_le_string(0)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_nt_utf16_be_string(&self, offset: usize) -> ReaderResult<ReadOutput<String>> {
+        let bytes = self.reader.get_slice_at_offset(offset);
+        let transformed: Vec<u8> = self.transformed(offset, bytes).collect();
+        transformed.read_nt_utf16_be_string(0)
+    }
+
+    #[inline(always)]
+    fn read_le_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>> {
+        self.read_staged(offset, T::try_read_le)
+    }
+
+    #[inline(always)]
+    fn read_be_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>> {
+        self.read_staged(offset, T::try_read_be)
+    }
+}
+
+/// A [Writer] that applies a transform to every byte written to another writer, such as XOR encoding.
+///
+/// The transform is given the offset of each byte in the wrapped writer along with the byte,
+/// and returns the byte that should be stored.
+/// Values are encoded onto the stack and transformed as they're written, so the wrapped writer never holds untransformed bytes,
+/// even if encoding fails.  Values larger than [TRANSFORM_STAGING_SIZE] need the `alloc` feature.
+///
+/// Methods that hand out or move raw bytes, such as [Writer::get_mut_slice], [Writer::get_sized_mut_slice],
+/// [Writer::copy_within], and [Writer::erase], work with the transformed bytes of the wrapped writer.
+///
+/// Bit writes keep the bits around the field, so they decode the bytes they touch by applying the transform,
+/// write the bits, then encode the bytes again.  They need a transform that undoes itself, such as XOR.
+/// [Cursor] is forwarded to the wrapped writer when it implements it.
+pub struct TransformWriter<W: Writer, F: Fn(usize, u8) -> u8> {
+    writer: W,
+    transform: F,
+}
+
+impl<W: Writer, F: Fn(usize, u8) -> u8> TransformWriter<W, F> {
+    #[inline(always)]
+    pub fn new(writer: W, transform: F) -> Self {
+        Self { writer, transform }
+    }
+
+    #[inline(always)]
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    #[inline(always)]
+    fn transform_range(&mut self, offset: usize, length: usize) -> WriterResult<()> {
+        let slice = self.writer.get_sized_mut_slice(offset, length)?;
+
+        for (index, byte) in slice.iter_mut().enumerate() {
+            *byte = (self.transform)(offset + index, *byte);
+        }
+
+        Ok(())
+    }
+
+    /// Encodes a value onto the stack, then transforms it into the wrapped writer,
+    /// so the wrapped writer never holds untransformed bytes, even if encoding fails.
+    #[inline(always)]
+    fn write_staged(
+        &mut self,
+        offset: usize,
+        size: usize,
+        encode: impl FnOnce(&mut [u8]) -> WriterResult<usize>,
+    ) -> WriterResult<usize> {
+        let data_len = self.writer.get_mut_slice().len();
+
+        if size <= TRANSFORM_STAGING_SIZE {
+            let mut staging = [0; TRANSFORM_STAGING_SIZE];
+            let written = add_error_context(encode(&mut staging[..size]), offset, data_len)?;
+            return self.write_bytes(offset, &staging[..written]);
+        }
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut staging = vec![0; size];
+            let written = add_error_context(encode(&mut staging), offset, data_len)?;
+            self.write_bytes(offset, &staging[..written])
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        Err(Error::InvalidWrite {
+            message: "Value is too large to transform without alloc",
+        })
+    }
+
+    /// Decodes the bytes a bit field touches, writes the bits to the wrapped writer, and encodes the bytes again.
+    #[inline(always)]
+    fn write_bits(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+        write: fn(&mut W, usize, usize, usize, u64) -> WriterResult<()>,
+    ) -> WriterResult<()> {
+        validate_bits(bit_len, value)?;
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let byte_len = bit_end.div_ceil(8);
+        self.writer.get_sized_mut_slice(byte_offset, byte_len)?;
+
+        self.transform_range(byte_offset, byte_len)?;
+        let result = write(&mut self.writer, byte_offset, bit_offset, bit_len, value);
+        self.transform_range(byte_offset, byte_len)?;
+        result
+    }
+}
+
+impl<W: Writer, F: Fn(usize, u8) -> u8> Writer for TransformWriter<W, F> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self.writer.get_mut_slice()
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        self.writer.get_sized_mut_slice(offset, length)
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, offset: usize) -> Option<usize> {
+        self.writer.writable_len(offset)
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        let slice = self.writer.get_sized_mut_slice(offset, bytes.len())?;
+
+        for (index, (dst, src)) in slice.iter_mut().zip(bytes).enumerate() {
+            *dst = (self.transform)(offset + index, *src);
+        }
+
+        Ok(bytes.len())
+    }
+
+    #[inline(always)]
+    fn write_all_slices(&mut self, offset: usize, parts: &[&[u8]]) -> WriterResult<usize> {
+        let written = self.writer.write_all_slices(offset, parts)?;
+        self.transform_range(offset, written)?;
+        Ok(written)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        let slice = self.writer.get_sized_mut_slice(offset, len)?;
+
+        for (index, byte) in slice.iter_mut().enumerate() {
+            *byte = (self.transform)(offset + index, value);
+        }
+
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.write_staged(offset, value.get_size(), |bytes| value.try_write_le(bytes))
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.write_staged(offset, value.get_size(), |bytes| value.try_write_be(bytes))
+    }
+
+    #[inline(always)]
+    fn write_repeated_le<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        if count == 0 {
+            return Ok(0);
+        }
+
+        self.get_sized_mut_slice(offset, checked_total_size(offset, value.get_size(), count)?)?;
+        let mut write_size = 0;
+
+        for _ in 0..count {
+            write_size += self.write_le(checked_offset_end(offset, write_size)?, value)?;
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn write_repeated_be<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize> {
+        if count == 0 {
+            return Ok(0);
+        }
+
+        self.get_sized_mut_slice(offset, checked_total_size(offset, value.get_size(), count)?)?;
+        let mut write_size = 0;
+
+        for _ in 0..count {
+            write_size += self.write_be(checked_offset_end(offset, write_size)?, value)?;
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn write_bits_msb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        self.write_bits(byte_offset, bit_offset, bit_len, value, W::write_bits_msb)
+    }
+
+    #[inline(always)]
+    fn write_bits_lsb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        self.write_bits(byte_offset, bit_offset, bit_len, value, W::write_bits_lsb)
+    }
+}
+
+impl<W: Writer + Cursor, F: Fn(usize, u8) -> u8> Cursor for TransformWriter<W, F> {
+    #[inline(always)]
+    fn get_index(&self) -> usize {
+        self.writer.get_index()
+    }
+
+    #[inline(always)]
+    fn set_index(&mut self, index: usize) {
+        self.writer.set_index(index);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ReaderExt;
+    #[cfg(feature = "alloc")]
+    use crate::{StreamContainer, StreamReader, StreamWriter};
+
+    fn rolling_xor(offset: usize, byte: u8) -> u8 {
+        byte ^ (0xa5u8.wrapping_add(offset as u8))
+    }
+
+    #[test]
+    fn should_decode_a_buffer() {
+        let encoded = [0xb7, 0x80, 0xc9, 0xc4];
+        let reader = TransformReader::new(encoded, |_, byte| byte ^ 0xaa);
+
+        assert_eq!(
+            reader
+                .read_be::<u32>(0)
+                .expect("Read should have succeeded"),
+            0x1d2a636e
+        );
+        assert_eq!(reader.get_slice(), encoded);
+    }
+
+    #[test]
+    fn should_pass_offsets_to_the_transform() {
+        let reader = TransformReader::new([0u8; 4], |offset, byte| byte + offset as u8);
+        let bytes: [u8; 4] = reader.read_array_le(0).expect("Read should have succeeded");
+
+        assert_eq!(bytes, [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn should_see_changes_to_the_wrapped_reader() {
+        let mut encoded = [0xaa, 0xaa];
+        let value = TransformReader::new(&encoded, |_, byte| byte ^ 0xaa).read_le::<u16>(0);
+        assert_eq!(value, Ok(0));
+
+        encoded[0] = 0xab;
+        let value = TransformReader::new(&encoded, |_, byte| byte ^ 0xaa).read_le::<u16>(0);
+        assert_eq!(value, Ok(1));
+    }
+
+    #[test]
+    fn should_check_and_checksum_decoded_bytes() {
+        let decoded = [0x12, 0x34, 0x56, 0x78];
+        let encoded = [
+            rolling_xor(0, 0x12),
+            rolling_xor(1, 0x34),
+            rolling_xor(2, 0x56),
+            rolling_xor(3, 0x78),
+        ];
+        let reader = TransformReader::new(encoded, rolling_xor);
+
+        assert_eq!(reader.expect_bytes(1, &[0x34, 0x56]), Ok(()));
+        assert_eq!(
+            reader.expect_bytes(1, &[0x34, 0x57]),
+            Err(Error::UnexpectedByte {
+                offset: 2,
+                expected: 0x57,
+                found: 0x56,
+            })
+        );
+        assert_eq!(reader.checksum_sum8(0, 4), decoded.checksum_sum8(0, 4));
+        assert_eq!(reader.checksum_sum16(0, 4), decoded.checksum_sum16(0, 4));
+        assert_eq!(reader.checksum_sum32(0, 4), decoded.checksum_sum32(0, 4));
+        assert_eq!(reader.crc16_ccitt(0, 4), decoded.crc16_ccitt(0, 4));
+        assert_eq!(reader.crc32(0, 4), decoded.crc32(0, 4));
+        assert_eq!(
+            reader.read_bits_msb(1, 4, 8),
+            decoded.read_bits_msb(1, 4, 8)
+        );
+        assert_eq!(
+            reader.read_bits_lsb(0, 12, 16),
+            decoded.read_bits_lsb(0, 12, 16)
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_read_is_past_the_end() {
+        let reader = TransformReader::new([0u8; 4], rolling_xor);
+        let error = reader
+            .read_le::<u32>(2)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_read_values_larger_than_the_staging_buffer() {
+        let reader = TransformReader::new([0u8; 80], |offset, byte| byte + offset as u8);
+        let bytes: [u8; 80] = reader.read_array_le(0).expect("Read should have succeeded");
+
+        assert!(bytes
+            .iter()
+            .enumerate()
+            .all(|(index, byte)| *byte == index as u8));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_read_decoded_byte_vectors_and_strings() {
+        let encoded = [0x41 ^ 0xaa, 0xaa, 0xaa, 0xaa];
+        let reader = TransformReader::new(encoded, |_, byte| byte ^ 0xaa);
+
+        assert_eq!(reader.read_byte_vec(0, 2), Ok(vec![0x41, 0]));
+        assert_eq!(
+            reader
+                .read_nt_utf16_le_string(0)
+                .expect("Read should have succeeded")
+                .into_data(),
+            "A"
+        );
+    }
+
+    #[test]
+    fn should_encode_written_values() {
+        let mut writer = TransformWriter::new([0u8; 4], |_, byte| byte ^ 0xaa);
+        let written = writer
+            .write_be(0, &0x1d2a636eu32)
+            .expect("Write should have succeeded");
+
+        assert_eq!(written, 4);
+        assert_eq!(writer.into_inner(), [0xb7, 0x80, 0xc9, 0xc4]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_encode_written_bytes_at_their_offsets() {
+        let mut writer = TransformWriter::new(vec![], |offset, byte| byte + offset as u8);
+        writer
+            .write_bytes(2, &[0, 0])
+            .expect("Write should have succeeded");
+        writer.fill(4, 2, 1).expect("Fill should have succeeded");
+        writer
+            .write_all_slices(6, &[&[0], &[0]])
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.into_inner(), [0, 0, 2, 3, 5, 6, 6, 7]);
+    }
+
+    #[test]
+    fn should_encode_repeated_values() {
+        let mut writer = TransformWriter::new([0u8; 4], |offset, byte| byte + offset as u8);
+        writer
+            .write_repeated_le(0, &0u16, 2)
+            .expect("Write should have succeeded");
+
+        assert_eq!(writer.into_inner(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn should_return_error_if_the_repeated_size_overflows() {
+        let mut writer = TransformWriter::new([0u8; 4], rolling_xor);
+        let error = writer
+            .write_repeated_be(1, &0u16, usize::MAX)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::OffsetOverflow {
+                offset: 1,
+                size: usize::MAX,
+            }
+        );
+        assert_eq!(writer.into_inner(), [0; 4]);
+    }
+
+    #[test]
+    fn should_return_error_if_size_is_too_large_for_offset() {
+        let mut writer = TransformWriter::new([0u8; 4], rolling_xor);
+        let error = writer
+            .write_le(2, &0u32)
+            .expect_err("Length should have been too large");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+        );
+        assert_eq!(writer.into_inner(), [0; 4]);
+    }
+
+    #[test]
+    fn should_not_leave_decoded_bytes_if_encoding_fails() {
+        /// Writes its bytes, then fails.
+        struct Failing;
+
+        impl EndianWrite for Failing {
+            fn get_size(&self) -> usize {
+                4
+            }
+
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                dst[..2].copy_from_slice(&[0x11, 0x22]);
+                Err(Error::InvalidWrite { message: "Failed" })
+            }
+
+            fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                self.try_write_le(dst)
+            }
+        }
+
+        let mut writer = TransformWriter::new([0u8; 4], rolling_xor);
+        let error = writer
+            .write_le(0, &Failing)
+            .expect_err("This should have failed");
+
+        assert_eq!(error, Error::InvalidWrite { message: "Failed" });
+        assert_eq!(writer.into_inner(), [0; 4]);
+    }
+
+    #[test]
+    fn should_encode_bit_writes() {
+        let mut writer = TransformWriter::new([rolling_xor(0, 0), rolling_xor(1, 0)], rolling_xor);
+        writer
+            .write_bits_msb(0, 4, 8, 0xab)
+            .expect("Write should have succeeded");
+
+        assert_eq!(
+            writer.into_inner(),
+            [rolling_xor(0, 0x0a), rolling_xor(1, 0xb0)]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_round_trip_bit_writes() {
+        let mut writer = TransformWriter::new(
+            vec![rolling_xor(0, 0), rolling_xor(1, 0), rolling_xor(2, 0)],
+            rolling_xor,
+        );
+        writer
+            .write_bits_msb(0, 2, 10, 0x2d5)
+            .expect("Write should have succeeded");
+        writer
+            .write_bits_lsb(1, 0, 4, 0xa)
+            .expect("Write should have succeeded");
+        writer
+            .write_bits_lsb(2, 3, 5, 0x15)
+            .expect("Write should have succeeded");
+
+        let reader = TransformReader::new(writer.into_inner(), rolling_xor);
+        assert_eq!(reader.read_bits_msb(0, 0, 2), Ok(0));
+        assert_eq!(reader.read_bits_msb(0, 2, 10), Ok(0x2d5));
+        assert_eq!(reader.read_bits_lsb(1, 0, 4), Ok(0xa));
+        assert_eq!(reader.read_bits_lsb(2, 0, 3), Ok(0));
+        assert_eq!(reader.read_bits_lsb(2, 3, 5), Ok(0x15));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn should_round_trip_through_matching_transforms() {
+        let mut writer = TransformWriter::new(StreamContainer::new(vec![]), rolling_xor);
+        writer
+            .write_stream_le(&0x11223344u32)
+            .expect("Write should have succeeded");
+        writer
+            .write_array_stream_be(&[0xaabbu16, 0xccdd])
+            .expect("Write should have succeeded");
+
+        let encoded = writer.into_inner().into_raw();
+        assert_ne!(encoded, [0x44, 0x33, 0x22, 0x11, 0xaa, 0xbb, 0xcc, 0xdd]);
+
+        let mut reader = StreamContainer::new(TransformReader::new(encoded, rolling_xor));
+        assert_eq!(
+            reader
+                .read_stream_le::<u32>()
+                .expect("Read should have succeeded"),
+            0x11223344
+        );
+        assert_eq!(
+            reader
+                .read_array_stream_be::<2, u16>()
+                .expect("Read should have succeeded"),
+            [0xaabb, 0xccdd]
+        );
+    }
+}
@@ -0,0 +1,774 @@
+use crate::{
+    add_error_context, checked_offset_end, validate_bits, EndianRead, EndianWrite, Error,
+    ReadOutput, Reader, ReaderResult, Writer, WriterResult,
+};
+use core::{
+    ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
+
+/// The largest value [Reader::read_le_with_output], [Reader::read_be_with_output],
+/// [Writer::write_le], and [Writer::write_be] can read from or write to a [VolatileSlice].
+///
+/// Values are copied between the region and a buffer of this size on the stack with volatile accesses,
+/// and are decoded from or encoded into the buffer.
+pub const VOLATILE_STAGING_SIZE: usize = 64;
+
+/// A [Reader] and [Writer] for memory-mapped I/O, such as peripheral registers.
+///
+/// The container doesn't keep a copy of the region.
+/// Every method it overrides, including byte, fill, copy, bit, and value writes, and byte, checksum, bit, and value reads,
+/// reads and writes the region directly with volatile accesses, one byte at a time.
+/// Endian reads, including derived types, iterators, and stream reads, decode values from bytes copied onto the stack,
+/// so they never borrow the region.
+/// Only the bytes a method needs are touched, and each of them is touched once.
+/// Reads of values that don't report how many bytes they need when they're short on data,
+/// such as null terminated strings, copy up to [VOLATILE_STAGING_SIZE] bytes.
+///
+/// Methods that return a slice or a reference, such as [Reader::get_slice], [crate::ReaderExt::get_transmutable],
+/// and borrowed reads, see the region through a slice that points to it.
+pub struct VolatileSlice {
+    ptr: *mut u8,
+    len: usize,
+}
+
+/// Records which bytes tests touch, so they can check each byte is only touched once.
+#[cfg(test)]
+mod access_log {
+    extern crate std;
+
+    use core::cell::RefCell;
+    use std::vec::Vec;
+
+    std::thread_local! {
+        static READS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+        static WRITES: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub fn read(index: usize) {
+        READS.with(|reads| reads.borrow_mut().push(index));
+    }
+
+    pub fn write(index: usize) {
+        WRITES.with(|writes| writes.borrow_mut().push(index));
+    }
+
+    pub fn take_reads() -> Vec<usize> {
+        READS.with(|reads| reads.take())
+    }
+
+    pub fn take_writes() -> Vec<usize> {
+        WRITES.with(|writes| writes.take())
+    }
+}
+
+impl VolatileSlice {
+    /// Creates a new container for `len` bytes starting at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must be non-null and valid for reads and writes of `len` bytes for as long as the container is alive.
+    /// Registers may be changed by hardware at any time, except while a slice or reference into the region is borrowed,
+    /// such as from [Reader::get_slice], [Writer::get_mut_slice], or [crate::ReaderExt::get_transmutable].
+    /// The volatile methods never borrow the region.
+    #[inline(always)]
+    pub unsafe fn new(ptr: *mut u8, len: usize) -> Self {
+        Self { ptr, len }
+    }
+
+    #[inline(always)]
+    fn check_range(&self, offset: usize, len: usize) -> Result<(), Error> {
+        if checked_offset_end(offset, len)? > self.len {
+            return Err(Error::InvalidSize {
+                wanted_size: len,
+                offset,
+                data_len: self.len,
+            });
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn load(&self, index: usize) -> u8 {
+        #[cfg(test)]
+        access_log::read(index);
+
+        // Safety: callers check the index against len, and the constructor's contract guarantees len bytes are valid.
+        unsafe { ptr::read_volatile(self.ptr.add(index)) }
+    }
+
+    #[inline(always)]
+    fn store(&mut self, index: usize, value: u8) {
+        #[cfg(test)]
+        access_log::write(index);
+
+        // Safety: callers check the index against len, and the constructor's contract guarantees len bytes are valid.
+        unsafe { ptr::write_volatile(self.ptr.add(index), value) };
+    }
+
+    #[inline(always)]
+    fn store_bytes(&mut self, offset: usize, bytes: &[u8]) {
+        for (index, byte) in bytes.iter().enumerate() {
+            self.store(offset + index, *byte);
+        }
+    }
+
+    #[inline(always)]
+    fn fold_bytes<T>(
+        &self,
+        offset: usize,
+        len: usize,
+        init: T,
+        f: impl Fn(T, u8) -> T,
+    ) -> ReaderResult<T> {
+        self.check_range(offset, len)?;
+        Ok((offset..offset + len).fold(init, |acc, index| f(acc, self.load(index))))
+    }
+
+    /// Copies the bytes at an offset onto the stack as the decoder asks for them, then decodes the value from them.
+    ///
+    /// Each byte is copied once, and bytes after the value aren't touched unless the decoder fails
+    /// without reporting how many bytes it needs.
+    #[inline(always)]
+    fn read_staged<T>(
+        &self,
+        offset: usize,
+        decode: impl Fn(&[u8]) -> ReaderResult<ReadOutput<T>>,
+    ) -> ReaderResult<ReadOutput<T>> {
+        let available = core::cmp::min(self.len.saturating_sub(offset), VOLATILE_STAGING_SIZE);
+        let mut staging = [0; VOLATILE_STAGING_SIZE];
+        let mut staged = 0;
+
+        loop {
+            let result = decode(&staging[..staged]);
+            let wanted = match result.as_ref().map_err(Error::root) {
+                Ok(_) => staged,
+                Err(Error::InvalidSize {
+                    wanted_size,
+                    offset,
+                    ..
+                }) => offset.saturating_add(*wanted_size),
+                Err(Error::EndOfData { offset, requested }) => offset.saturating_add(*requested),
+                Err(_) => available,
+            };
+
+            if wanted <= staged || staged == available {
+                if wanted > staged && self.len.saturating_sub(offset) > VOLATILE_STAGING_SIZE {
+                    return Err(Error::InvalidRead {
+                        message: "Value is too large to read from volatile memory",
+                    });
+                }
+
+                return add_error_context(result, offset, self.len);
+            }
+
+            let end = core::cmp::min(wanted, available);
+
+            for (index, byte) in staging[staged..end].iter_mut().enumerate() {
+                *byte = self.load(offset + staged + index);
+            }
+
+            staged = end;
+        }
+    }
+
+    #[inline(always)]
+    fn write_staged(
+        &mut self,
+        offset: usize,
+        size: usize,
+        encode: impl FnOnce(&mut [u8]) -> WriterResult<usize>,
+    ) -> WriterResult<usize> {
+        if size > VOLATILE_STAGING_SIZE {
+            return Err(Error::InvalidWrite {
+                message: "Value is too large to write to volatile memory",
+            });
+        }
+
+        self.check_range(offset, size)?;
+
+        let mut staging = [0; VOLATILE_STAGING_SIZE];
+        let written = add_error_context(encode(&mut staging[..size]), offset, self.len)?;
+        self.store_bytes(offset, &staging[..written]);
+        Ok(written)
+    }
+}
+
+impl Reader for VolatileSlice {
+    #[inline(always)]
+    fn get_slice(&self) -> &[u8] {
+        // Safety: the constructor's contract guarantees len bytes are valid and unchanged while the slice is borrowed.
+        unsafe { core::slice::from_raw_parts(self.ptr, self.len) }
+    }
+
+    #[inline(always)]
+    fn expect_bytes(&self, offset: usize, expected: &[u8]) -> ReaderResult<()> {
+        self.check_range(offset, expected.len())?;
+
+        for (index, expected) in expected.iter().enumerate() {
+            let found = self.load(offset + index);
+
+            if found != *expected {
+                return Err(Error::UnexpectedByte {
+                    offset: offset + index,
+                    expected: *expected,
+                    found,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn checksum_sum8(&self, offset: usize, len: usize) -> ReaderResult<u8> {
+        self.fold_bytes(offset, len, 0, |sum: u8, byte| sum.wrapping_add(byte))
+    }
+
+    #[inline(always)]
+    fn checksum_sum16(&self, offset: usize, len: usize) -> ReaderResult<u16> {
+        self.fold_bytes(offset, len, 0, |sum: u16, byte| {
+            sum.wrapping_add(byte as u16)
+        })
+    }
+
+    #[inline(always)]
+    fn checksum_sum32(&self, offset: usize, len: usize) -> ReaderResult<u32> {
+        self.fold_bytes(offset, len, 0, |sum: u32, byte| {
+            sum.wrapping_add(byte as u32)
+        })
+    }
+
+    #[inline(always)]
+    fn read_bits_msb(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+    ) -> ReaderResult<u64> {
+        if bit_len > 64 {
+            return Err(Error::InvalidRead {
+                message: "Bit length must be 64 or less",
+            });
+        }
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let byte_len = bit_end.div_ceil(8);
+        self.check_range(byte_offset, byte_len)?;
+
+        let mut value = 0u64;
+        let mut bit = bit_offset;
+
+        for index in bit_offset / 8..byte_len {
+            let byte = self.load(byte_offset + index);
+
+            while bit < bit_end && bit / 8 == index {
+                value = (value << 1) | ((byte >> (7 - bit % 8)) & 1) as u64;
+                bit += 1;
+            }
+        }
+
+        Ok(value)
+    }
+
+    #[inline(always)]
+    fn read_bits_lsb(
+        &self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+    ) -> ReaderResult<u64> {
+        if bit_len > 64 {
+            return Err(Error::InvalidRead {
+                message: "Bit length must be 64 or less",
+            });
+        }
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let byte_len = bit_end.div_ceil(8);
+        self.check_range(byte_offset, byte_len)?;
+
+        let mut value = 0u64;
+        let mut bit = bit_offset;
+
+        for index in bit_offset / 8..byte_len {
+            let byte = self.load(byte_offset + index);
+
+            while bit < bit_end && bit / 8 == index {
+                value |= (((byte >> (bit % 8)) & 1) as u64) << (bit - bit_offset);
+                bit += 1;
+            }
+        }
+
+        Ok(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn read_byte_vec(&self, offset: usize, size: usize) -> ReaderResult<alloc::vec::Vec<u8>> {
+        self.check_range(offset, size)?;
+        Ok((offset..offset + size)
+            .map(|index| self.load(index))
+            .collect())
+    }
+
+    /// Errors if the value needs more than [VOLATILE_STAGING_SIZE] bytes.
+    #[inline(always)]
+    fn read_le_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>> {
+        self.read_staged(offset, T::try_read_le)
+    }
+
+    /// Errors if the value needs more than [VOLATILE_STAGING_SIZE] bytes.
+    #[inline(always)]
+    fn read_be_with_output<T: EndianRead>(&self, offset: usize) -> ReaderResult<ReadOutput<T>> {
+        self.read_staged(offset, T::try_read_be)
+    }
+}
+
+impl Writer for VolatileSlice {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        // Safety: the constructor's contract guarantees len bytes are valid and unchanged while the slice is borrowed.
+        unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
+    }
+
+    #[inline(always)]
+    fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        self.check_range(offset, bytes.len())?;
+        self.store_bytes(offset, bytes);
+        Ok(bytes.len())
+    }
+
+    #[inline(always)]
+    fn write_all_slices(&mut self, offset: usize, parts: &[&[u8]]) -> WriterResult<usize> {
+        let offset_end = parts
+            .iter()
+            .try_fold(offset, |end, part| checked_offset_end(end, part.len()))?;
+        let length = offset_end - offset;
+        self.check_range(offset, length)?;
+
+        let mut write_size = 0;
+
+        for part in parts {
+            self.store_bytes(offset + write_size, part);
+            write_size += part.len();
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        self.check_range(offset, len)?;
+
+        for index in offset..offset + len {
+            self.store(index, value);
+        }
+
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.fill(offset, len, 0)?;
+        compiler_fence(Ordering::SeqCst);
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        src_offset: usize,
+        dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        self.check_range(src_offset, len)?;
+        self.check_range(dst_offset, len)?;
+
+        // Copy backwards when the destination is after the source, so overlapping bytes are read before they're overwritten
+        if dst_offset > src_offset {
+            for index in (0..len).rev() {
+                let byte = self.load(src_offset + index);
+                self.store(dst_offset + index, byte);
+            }
+        } else {
+            for index in 0..len {
+                let byte = self.load(src_offset + index);
+                self.store(dst_offset + index, byte);
+            }
+        }
+
+        Ok(len)
+    }
+
+    /// Errors if the value is larger than [VOLATILE_STAGING_SIZE].
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.write_staged(offset, value.get_size(), |bytes| value.try_write_le(bytes))
+    }
+
+    /// Errors if the value is larger than [VOLATILE_STAGING_SIZE].
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        self.write_staged(offset, value.get_size(), |bytes| value.try_write_be(bytes))
+    }
+
+    #[inline(always)]
+    fn write_bits_msb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        validate_bits(bit_len, value)?;
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let byte_len = bit_end.div_ceil(8);
+        self.check_range(byte_offset, byte_len)?;
+
+        let mut bit = bit_offset;
+
+        for index in bit_offset / 8..byte_len {
+            let mut byte = self.load(byte_offset + index);
+
+            while bit < bit_end && bit / 8 == index {
+                let bit_value = ((value >> (bit_len - 1 - (bit - bit_offset))) & 1) as u8;
+                let mask = 1 << (7 - bit % 8);
+                byte = (byte & !mask) | (bit_value << (7 - bit % 8));
+                bit += 1;
+            }
+
+            self.store(byte_offset + index, byte);
+        }
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    fn write_bits_lsb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        validate_bits(bit_len, value)?;
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let byte_len = bit_end.div_ceil(8);
+        self.check_range(byte_offset, byte_len)?;
+
+        let mut bit = bit_offset;
+
+        for index in bit_offset / 8..byte_len {
+            let mut byte = self.load(byte_offset + index);
+
+            while bit < bit_end && bit / 8 == index {
+                let bit_value = ((value >> (bit - bit_offset)) & 1) as u8;
+                let mask = 1 << (bit % 8);
+                byte = (byte & !mask) | (bit_value << (bit % 8));
+                bit += 1;
+            }
+
+            self.store(byte_offset + index, byte);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{ReaderExt, StreamContainer, StreamReader, StreamWriter};
+
+    #[test]
+    fn should_read_the_region() {
+        let mut region: [u8; 8] = [0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let value = volatile
+            .read_le::<u32>(4)
+            .expect("Read should have succeeded");
+
+        assert_eq!(value, 0xddccbbaa);
+    }
+
+    #[test]
+    fn should_see_changes_to_the_region() {
+        let mut region: [u8; 4] = [0; 4];
+        let ptr = region.as_mut_ptr();
+        let volatile = unsafe { VolatileSlice::new(ptr, 4) };
+        unsafe { ptr::write_volatile(ptr.add(2), 0xaa) };
+
+        assert_eq!(volatile.expect_bytes(0, &[0, 0, 0xaa, 0]), Ok(()));
+    }
+
+    #[test]
+    fn should_touch_each_byte_once() {
+        let mut region: [u8; 8] = [0xff; 8];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        access_log::take_reads();
+        access_log::take_writes();
+
+        volatile
+            .write_le(1, &0xaabbccddu32)
+            .expect("Write should have succeeded");
+        assert_eq!(access_log::take_writes(), [1, 2, 3, 4]);
+
+        volatile
+            .write_bits_msb(5, 4, 8, 0x12)
+            .expect("Write should have succeeded");
+        assert_eq!(access_log::take_reads(), [5, 6]);
+        assert_eq!(access_log::take_writes(), [5, 6]);
+
+        volatile
+            .copy_within(1, 2, 3)
+            .expect("Copy should have succeeded");
+        assert_eq!(access_log::take_reads(), [3, 2, 1]);
+        assert_eq!(access_log::take_writes(), [4, 3, 2]);
+
+        let sum = volatile
+            .checksum_sum8(0, 8)
+            .expect("Sum should have succeeded");
+        assert_eq!(access_log::take_reads(), [0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(sum, 0x5f);
+        assert_eq!(region, [0xff, 0xdd, 0xdd, 0xcc, 0xbb, 0xf1, 0x2f, 0xff]);
+    }
+
+    #[test]
+    fn should_read_values_with_volatile_reads() {
+        let mut region: [u8; 8] = [0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        access_log::take_reads();
+
+        let value = volatile
+            .read_be::<u16>(2)
+            .expect("Read should have succeeded");
+        assert_eq!(value, 0x3344);
+        assert_eq!(access_log::take_reads(), [2, 3]);
+
+        let values: [u16; 2] = volatile
+            .read_array_le(4)
+            .expect("Read should have succeeded");
+        assert_eq!(values, [0xbbaa, 0xddcc]);
+        assert_eq!(access_log::take_reads(), [4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn should_stream_values_with_volatile_reads() {
+        let mut region: [u8; 8] = [0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let mut stream = StreamContainer::new_at(volatile, 2);
+        access_log::take_reads();
+
+        let value = stream
+            .read_stream_be::<u16>()
+            .expect("Read should have succeeded");
+        assert_eq!(value, 0x3344);
+        assert_eq!(access_log::take_reads(), [2, 3]);
+    }
+
+    #[test]
+    fn should_read_derived_types_with_volatile_reads() {
+        #[derive(Debug, PartialEq, crate::EndianRead)]
+        struct Registers {
+            status: u8,
+            #[no_std_io(pad_before = 1)]
+            control: u16,
+        }
+
+        let mut region: [u8; 8] = [0x01, 0xff, 0x22, 0x33, 0, 0, 0, 0];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        access_log::take_reads();
+
+        let registers: Registers = volatile.read_le(0).expect("Read should have succeeded");
+        assert_eq!(
+            registers,
+            Registers {
+                status: 0x01,
+                control: 0x3322,
+            }
+        );
+        assert_eq!(access_log::take_reads(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn should_return_error_if_value_is_past_the_region() {
+        let mut region: [u8; 4] = [0; 4];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let error = volatile
+            .read_le::<u32>(2)
+            .expect_err("Read should have been past the region");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 2,
+                data_len: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_value_is_too_large_to_read() {
+        let mut region: [u8; 128] = [0; 128];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let error = volatile
+            .read_le::<[u8; 65]>(0)
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::InvalidRead {
+                message: "Value is too large to read from volatile memory",
+            }
+        );
+    }
+
+    #[test]
+    fn should_write_bytes() {
+        let mut region: [u8; 8] = [0; 8];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let written = volatile
+            .write_bytes(4, &[0xaa, 0xbb])
+            .expect("Write should have succeeded");
+
+        assert_eq!(written, 2);
+        assert_eq!(region, [0, 0, 0, 0, 0xaa, 0xbb, 0, 0]);
+    }
+
+    #[test]
+    fn should_write_all_slices() {
+        let mut region: [u8; 8] = [0; 8];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let written = volatile
+            .write_all_slices(1, &[&[0xaa, 0xbb], &[0xcc]])
+            .expect("Write should have succeeded");
+
+        assert_eq!(written, 3);
+        assert_eq!(region, [0, 0xaa, 0xbb, 0xcc, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn should_fill_bytes() {
+        let mut region: [u8; 8] = [0; 8];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let written = volatile
+            .fill(1, 3, 0xff)
+            .expect("Fill should have succeeded");
+
+        assert_eq!(written, 3);
+        assert_eq!(region, [0, 0xff, 0xff, 0xff, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn should_copy_within() {
+        let mut region: [u8; 8] = [1, 2, 3, 4, 5, 6, 7, 8];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let written = volatile
+            .copy_within(3, 1, 3)
+            .expect("Copy should have succeeded");
+
+        assert_eq!(written, 3);
+        assert_eq!(region, [1, 4, 5, 6, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn should_erase_bytes() {
+        let mut region: [u8; 4] = [0xaa; 4];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let written = volatile.erase(1, 2).expect("Erase should have succeeded");
+
+        assert_eq!(written, 2);
+        assert_eq!(region, [0xaa, 0, 0, 0xaa]);
+    }
+
+    #[test]
+    fn should_read_and_write_bits() {
+        let mut region: [u8; 2] = [0; 2];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        volatile
+            .write_bits_lsb(0, 6, 4, 0b1011)
+            .expect("Write should have succeeded");
+
+        volatile
+            .write_bits_msb(0, 2, 3, 0b101)
+            .expect("Write should have succeeded");
+
+        assert_eq!(volatile.read_bits_lsb(0, 6, 4), Ok(0b1011));
+        assert_eq!(volatile.read_bits_msb(0, 2, 3), Ok(0b101));
+        assert_eq!(region, [0b1110_1000, 0b0000_0010]);
+    }
+
+    #[test]
+    fn should_return_error_if_bytes_do_not_match() {
+        let mut region: [u8; 4] = [0x11, 0x22, 0x33, 0x44];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let error = volatile
+            .expect_bytes(1, &[0x22, 0xff])
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::UnexpectedByte {
+                offset: 2,
+                expected: 0xff,
+                found: 0x33,
+            }
+        );
+    }
+
+    #[test]
+    fn should_return_error_if_size_is_too_large_for_offset() {
+        let mut region: [u8; 8] = [0; 8];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let error = volatile
+            .write_be(6, &0xaabbccddu32)
+            .expect_err("Length should have been too large");
+
+        assert_eq!(
+            error,
+            Error::InvalidSize {
+                wanted_size: 4,
+                offset: 6,
+                data_len: 8,
+            }
+        );
+        assert_eq!(region, [0; 8]);
+    }
+
+    #[test]
+    fn should_return_error_if_value_is_too_large_to_stage() {
+        let mut region: [u8; 128] = [0; 128];
+        let mut volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let error = volatile
+            .write_le(0, &[0u8; 65])
+            .expect_err("This should have failed");
+
+        assert_eq!(
+            error,
+            Error::InvalidWrite {
+                message: "Value is too large to write to volatile memory",
+            }
+        );
+    }
+
+    #[test]
+    fn should_work_with_streams() {
+        let mut region: [u8; 8] = [0x11, 0x22, 0, 0, 0, 0, 0, 0];
+        let volatile = unsafe { VolatileSlice::new(region.as_mut_ptr(), region.len()) };
+        let mut stream = StreamContainer::new(volatile);
+        let first: u16 = stream.read_stream_be().expect("Read should have succeeded");
+        stream
+            .write_stream_be(&0xaabbu16)
+            .expect("Write should have succeeded");
+        stream
+            .write_stream_bytes(&[0xcc])
+            .expect("Write should have succeeded");
+
+        assert_eq!(first, 0x1122);
+        assert_eq!(region, [0x11, 0x22, 0xaa, 0xbb, 0xcc, 0, 0, 0]);
+    }
+}
@@ -1,8 +1,14 @@
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
-
-use super::{add_error_context, EndianWrite, Error};
-use core::mem;
+use alloc::{boxed::Box, vec::Vec};
+
+use super::{
+    add_error_context, checked_offset_end, checked_total_size, incomplete_iter_write, EndianWrite,
+    Error,
+};
+use core::{
+    mem, ptr,
+    sync::atomic::{compiler_fence, Ordering},
+};
 use safe_transmute::{transmute_one_to_bytes, TriviallyTransmutable};
 
 pub type WriterResult<T> = Result<T, Error>;
@@ -11,9 +17,14 @@ pub type WriterResult<T> = Result<T, Error>;
 ///
 /// Blanket implementations are provided for byte slices and vectors.
 /// Vectors will grow if there isn't enough space.  If this isn't desirable, use a slice from a vector as the writer.
+/// Checked methods only promise not to return an error, so they grow vectors the same way.
 ///
 /// To forward [Writer] methods to containers with vectors, implement both
 /// [Writer::get_mut_slice] and [Writer::get_sized_mut_slice] instead of only [Writer::get_mut_slice].
+///
+/// The trait is object safe, so it can be used as a trait object such as `&mut dyn Writer`.
+/// Methods that writers may override with generic parameters, such as [Writer::write_le], require `Self: Sized`,
+/// and the other generic methods are in [WriterExt].
 pub trait Writer {
     /// Returns the data to be read from.
     fn get_mut_slice(&mut self) -> &mut [u8];
@@ -34,10 +45,11 @@ pub trait Writer {
     /// Gets a slice of bytes with a specified length from an offset of a source.
     ///
     /// An error should be returned if the size is invalid.
+    /// An [Error::OffsetOverflow] is returned if `offset + length` overflows.
     #[inline(always)]
     fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
         let data = self.get_mut_slice();
-        let offset_end = offset + length;
+        let offset_end = checked_offset_end(offset, length)?;
 
         if data.len() < offset_end {
             return Err(Error::InvalidSize {
@@ -50,11 +62,14 @@ pub trait Writer {
         Ok(&mut data[offset..offset_end])
     }
 
-    /// Same as [Writer::get_sized_mut_slice], except the length comes from `T.len()`.
+    /// Returns the number of bytes that can be written at an offset,
+    /// or `None` if the writer grows to fit any write, such as a vector.
+    ///
+    /// Checked writes use this to decide whether a write fits.
+    /// Growable writers should override this so checked writes are allowed to grow them.
     #[inline(always)]
-    fn get_type_sized_mut_slice<T: Sized>(&mut self, offset: usize) -> WriterResult<&mut [u8]> {
-        let length = mem::size_of::<T>();
-        self.get_sized_mut_slice(offset, length)
+    fn writable_len(&mut self, offset: usize) -> Option<usize> {
+        Some(self.get_mut_slice_at_offset(offset).len())
     }
 
     /// Writes bytes to an offset and returns the number of bytes written.
@@ -76,17 +91,120 @@ pub trait Writer {
         self.write_bytes(offset, bytes).unwrap_or(0)
     }
 
-    /// Same as [Writer::write_bytes], but writes a [TriviallyTransmutable] type by converting it to bytes.
+    /// Same as [Writer::write_bytes], but returns `None` instead of an error if the bytes weren't written.
+    ///
+    /// Unlike [Writer::checked_write_bytes], a successful write of zero bytes can be told apart from a failed write.
     #[inline(always)]
-    fn write<T: TriviallyTransmutable>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
-        let bytes = transmute_one_to_bytes(value);
-        self.write_bytes(offset, bytes)
+    fn write_bytes_opt(&mut self, offset: usize, bytes: &[u8]) -> Option<usize> {
+        self.write_bytes(offset, bytes).ok()
     }
 
-    /// Same as [Writer::checked_write_bytes], but writes a [TriviallyTransmutable] type by converting it to bytes.
+    /// Writes as many bytes as will fit at an offset and returns the number of bytes written.
+    ///
+    /// Unlike [Writer::checked_write_bytes], which writes nothing if the bytes won't fit,
+    /// this writes the start of the bytes up to the end of the data.
+    /// Growable writers, such as vectors, grow to fit all of the bytes.
     #[inline(always)]
-    fn checked_write<T: TriviallyTransmutable>(&mut self, offset: usize, value: &T) -> usize {
-        self.write(offset, value).unwrap_or(0)
+    fn write_bytes_truncated(&mut self, offset: usize, bytes: &[u8]) -> usize {
+        if let Ok(length) = self.write_bytes(offset, bytes) {
+            return length;
+        }
+
+        let writable = self.writable_len(offset).unwrap_or(bytes.len());
+        let length = core::cmp::min(bytes.len(), writable);
+        self.checked_write_bytes(offset, &bytes[..length])
+    }
+
+    /// Writes each byte slice consecutively starting at an offset and returns the total number of bytes written.
+    ///
+    /// Errors if the combined length will not fit at the offset.
+    /// The combined length is checked before anything is written, so nothing is written on error.
+    #[inline(always)]
+    fn write_all_slices(&mut self, offset: usize, parts: &[&[u8]]) -> WriterResult<usize> {
+        let offset_end = parts
+            .iter()
+            .try_fold(offset, |end, part| checked_offset_end(end, part.len()))?;
+        let length = offset_end - offset;
+        let slice = self.get_sized_mut_slice(offset, length)?;
+        let mut write_size = 0;
+
+        for part in parts {
+            slice[write_size..write_size + part.len()].copy_from_slice(part);
+            write_size += part.len();
+        }
+
+        Ok(write_size)
+    }
+
+    /// Sets `len` bytes starting at an offset to `value` and returns the number of bytes written.
+    ///
+    /// Errors if the length will not fit at the offset.
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        let slice = self.get_sized_mut_slice(offset, len)?;
+
+        slice.fill(value);
+        Ok(len)
+    }
+
+    /// Same as [Writer::fill], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_fill(&mut self, offset: usize, len: usize, value: u8) -> usize {
+        self.fill(offset, len, value).unwrap_or(0)
+    }
+
+    /// Same as [Writer::fill], but sets the bytes to zero.
+    #[inline(always)]
+    fn write_zeroes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        self.fill(offset, len, 0)
+    }
+
+    /// Sets `len` bytes starting at an offset to zero with volatile writes, so the compiler can't remove the writes.
+    /// Returns the number of bytes erased.
+    ///
+    /// This is intended for clearing sensitive data, such as keys or passwords.
+    /// Errors if the length will not fit at the offset, in which case nothing is erased.
+    #[inline(always)]
+    fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        let slice = self.get_sized_mut_slice(offset, len)?;
+        erase_bytes(slice);
+        Ok(len)
+    }
+
+    /// Copies `len` bytes from one offset to another and returns the number of bytes copied.
+    ///
+    /// The source and destination may overlap.
+    /// Errors if either range will not fit in the data.
+    #[inline(always)]
+    fn copy_within(
+        &mut self,
+        src_offset: usize,
+        dst_offset: usize,
+        len: usize,
+    ) -> WriterResult<usize> {
+        let data_len = self.get_mut_slice().len();
+        let src_end = checked_offset_end(src_offset, len)?;
+
+        if src_end > data_len {
+            return Err(Error::InvalidSize {
+                wanted_size: len,
+                offset: src_offset,
+                data_len,
+            });
+        }
+
+        self.get_sized_mut_slice(dst_offset, len)?;
+        self.get_mut_slice()
+            .copy_within(src_offset..src_end, dst_offset);
+        Ok(len)
+    }
+
+    /// Same as [Writer::copy_within], but checks to make sure both ranges fit in the data.
+    /// Returns 0 as the write size if either range won't fit.
+    #[inline(always)]
+    fn checked_copy_within(&mut self, src_offset: usize, dst_offset: usize, len: usize) -> usize {
+        self.copy_within(src_offset, dst_offset, len).unwrap_or(0)
     }
 
     /// Writes a value in its little endian representation.
@@ -95,7 +213,10 @@ pub trait Writer {
     /// This should only be used when reading data from a format or protocol
     /// that explicitly defines little endian.
     #[inline(always)]
-    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
         let bytes = self.get_mut_slice_at_offset(offset);
         add_error_context(
             value.try_write_le(bytes),
@@ -104,20 +225,16 @@ pub trait Writer {
         )
     }
 
-    /// Same as [Writer::write_le], but checks to make sure the bytes can safely be written to the offset.
-    /// Returns 0 as the write size if the bytes won't fit into the offset.
-    #[inline(always)]
-    fn checked_write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> usize {
-        self.write_le(offset, value).unwrap_or(0)
-    }
-
     /// Writes a value in its big endian representation.
     ///
     /// Prefer endian agnostic methods when possible.
     /// This should only be used when reading data from a format or protocol
     /// that explicitly defines big endian.
     #[inline(always)]
-    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
         let bytes = self.get_mut_slice_at_offset(offset);
         add_error_context(
             value.try_write_be(bytes),
@@ -126,11 +243,55 @@ pub trait Writer {
         )
     }
 
-    /// Same as [Writer::write_be], but checks to make sure the bytes can safely be written to the offset.
-    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    /// Writes the lowest `bit_len` bits of `value` starting at `bit_offset` bits into the byte at `byte_offset`,
+    /// with the most significant bit of each byte first.  Bits outside of the field are left untouched.
+    ///
+    /// Bit fields may span multiple bytes.  An error is returned if `bit_len` is greater than 64,
+    /// if `value` does not fit in `bit_len` bits, or if the bits exceed the available data.
     #[inline(always)]
-    fn checked_write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> usize {
-        self.write_be(offset, value).unwrap_or(0)
+    fn write_bits_msb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        validate_bits(bit_len, value)?;
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let bytes = self.get_sized_mut_slice(byte_offset, bit_end.div_ceil(8))?;
+
+        for (index, bit) in (bit_offset..bit_end).enumerate() {
+            let bit_value = ((value >> (bit_len - 1 - index)) & 1) as u8;
+            let mask = 1 << (7 - bit % 8);
+            bytes[bit / 8] = (bytes[bit / 8] & !mask) | (bit_value << (7 - bit % 8));
+        }
+
+        Ok(())
+    }
+
+    /// Same as [Writer::write_bits_msb], but writes the least significant bit of each byte first.
+    /// The least significant bit of `value` is written first.
+    #[inline(always)]
+    fn write_bits_lsb(
+        &mut self,
+        byte_offset: usize,
+        bit_offset: usize,
+        bit_len: usize,
+        value: u64,
+    ) -> WriterResult<()> {
+        validate_bits(bit_len, value)?;
+
+        let bit_end = checked_offset_end(bit_offset, bit_len)?;
+        let bytes = self.get_sized_mut_slice(byte_offset, bit_end.div_ceil(8))?;
+
+        for (index, bit) in (bit_offset..bit_end).enumerate() {
+            let bit_value = ((value >> index) & 1) as u8;
+            let mask = 1 << (bit % 8);
+            bytes[bit / 8] = (bytes[bit / 8] & !mask) | (bit_value << (bit % 8));
+        }
+
+        Ok(())
     }
 
     /// Writes an array in its little endian representation.
@@ -145,38 +306,20 @@ pub trait Writer {
         &mut self,
         offset: usize,
         value: &[T; SIZE],
-    ) -> WriterResult<usize> {
+    ) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
         let mut write_size = 0;
 
         for val in value {
-            self.write_le(offset + write_size, val)?;
+            self.write_le(checked_offset_end(offset, write_size)?, val)?;
             write_size += val.get_size();
         }
 
         Ok(write_size)
     }
 
-    /// Same as [Writer::write_array_le], but checks to make sure the bytes can safely be written to the offset.
-    /// Returns 0 as the write size if the bytes won't fit into the offset.
-    #[inline(always)]
-    fn checked_write_array_le<const SIZE: usize, T: EndianWrite>(
-        &mut self,
-        offset: usize,
-        value: &[T; SIZE],
-    ) -> usize {
-        if value.is_empty() {
-            return 0;
-        }
-
-        let size = value.iter().map(|val| val.get_size()).sum::<usize>();
-        let len = self.get_mut_slice().len();
-        if offset + size > len {
-            return 0;
-        }
-
-        self.write_array_le(offset, value).unwrap_or(0)
-    }
-
     /// Writes an array in its big endian representation.
     ///
     /// The array will be written fully or until an error is encountered. The error will contain
@@ -189,1232 +332,4725 @@ pub trait Writer {
         &mut self,
         offset: usize,
         value: &[T; SIZE],
-    ) -> WriterResult<usize> {
+    ) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
         let mut write_size = 0;
 
         for val in value {
-            self.write_be(offset + write_size, val)?;
+            self.write_be(checked_offset_end(offset, write_size)?, val)?;
             write_size += val.get_size();
         }
 
         Ok(write_size)
     }
 
-    /// Same as [Writer::write_array_be], but checks to make sure the bytes can safely be written to the offset.
-    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    /// Same as [Writer::write_array_le], but writes a slice with a length that's only known at runtime.
     #[inline(always)]
-    fn checked_write_array_be<const SIZE: usize, T: EndianWrite>(
-        &mut self,
-        offset: usize,
-        value: &[T; SIZE],
-    ) -> usize {
-        if value.is_empty() {
-            return 0;
-        }
+    fn write_slice_le<T: EndianWrite>(&mut self, offset: usize, values: &[T]) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
+        let mut write_size = 0;
 
-        let size = value.iter().map(|val| val.get_size()).sum::<usize>();
-        let len = self.get_mut_slice().len();
-        if offset + size > len {
-            return 0;
+        for val in values {
+            self.write_le(checked_offset_end(offset, write_size)?, val)?;
+            write_size += val.get_size();
         }
 
-        self.write_array_be(offset, value).unwrap_or(0)
+        Ok(write_size)
     }
-}
 
-impl<const SIZE: usize> Writer for [u8; SIZE] {
+    /// Same as [Writer::write_array_be], but writes a slice with a length that's only known at runtime.
     #[inline(always)]
-    fn get_mut_slice(&mut self) -> &mut [u8] {
-        self
-    }
-}
+    fn write_slice_be<T: EndianWrite>(&mut self, offset: usize, values: &[T]) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
+        let mut write_size = 0;
 
-impl Writer for &mut [u8] {
-    #[inline(always)]
-    fn get_mut_slice(&mut self) -> &mut [u8] {
-        self
-    }
-}
+        for val in values {
+            self.write_be(checked_offset_end(offset, write_size)?, val)?;
+            write_size += val.get_size();
+        }
 
-#[cfg(feature = "alloc")]
-impl Writer for Vec<u8> {
-    #[inline(always)]
-    fn get_mut_slice(&mut self) -> &mut [u8] {
-        self.as_mut_slice()
+        Ok(write_size)
     }
 
+    /// Writes the little endian representation of a value `count` times back to back
+    /// and returns the total number of bytes written.
+    ///
+    /// The value is only encoded once, and the encoded bytes are copied for each repetition.
+    /// Errors if all of the repetitions will not fit at the offset, in which case nothing is written.
     #[inline(always)]
-    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
-        let offset_end = offset + value.get_size();
-        let self_len = self.len();
-
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
+    fn write_repeated_le<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
+        if count == 0 {
+            return Ok(0);
         }
 
-        add_error_context(
-            value.try_write_le(&mut self[offset..]),
-            offset,
-            self.get_mut_slice().len(),
-        )
-    }
-
-    #[inline(always)]
-    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
-        let offset_end = offset + value.get_size();
-        let self_len = self.len();
+        self.get_sized_mut_slice(offset, checked_total_size(offset, value.get_size(), count)?)?;
+        let size = self.write_le(offset, value)?;
+        let mut copy_offset = offset;
 
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
+        for _ in 1..count {
+            copy_offset = checked_offset_end(copy_offset, size)?;
+            self.copy_within(offset, copy_offset, size)?;
         }
 
-        add_error_context(
-            value.try_write_be(&mut self[offset..]),
-            offset,
-            self.get_mut_slice().len(),
-        )
+        checked_total_size(offset, size, count)
     }
 
+    /// Same as [Writer::write_repeated_le], but writes the big endian representation of the value.
     #[inline(always)]
-    fn write_array_le<const SIZE: usize, T: EndianWrite>(
+    fn write_repeated_be<T: EndianWrite>(
         &mut self,
         offset: usize,
-        value: &[T; SIZE],
-    ) -> WriterResult<usize> {
-        if value.is_empty() {
+        value: &T,
+        count: usize,
+    ) -> WriterResult<usize>
+    where
+        Self: Sized,
+    {
+        if count == 0 {
             return Ok(0);
         }
-        let offset_end = value.iter().map(|val| val.get_size()).sum::<usize>() + offset;
-        let self_len = self.len();
-
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
-        }
 
-        let mut write_size = 0;
+        self.get_sized_mut_slice(offset, checked_total_size(offset, value.get_size(), count)?)?;
+        let size = self.write_be(offset, value)?;
+        let mut copy_offset = offset;
 
-        for val in value {
-            self.write_le(offset + write_size, val)?;
-            write_size += val.get_size();
+        for _ in 1..count {
+            copy_offset = checked_offset_end(copy_offset, size)?;
+            self.copy_within(offset, copy_offset, size)?;
         }
 
-        Ok(write_size)
+        checked_total_size(offset, size, count)
     }
 
+    /// Writes the UTF-8 bytes of a string to an offset and returns the number of bytes written.
+    ///
+    /// No length prefix or terminator is written.
     #[inline(always)]
-    fn write_array_be<const SIZE: usize, T: EndianWrite>(
-        &mut self,
-        offset: usize,
-        value: &[T; SIZE],
-    ) -> WriterResult<usize> {
-        if value.is_empty() {
-            return Ok(0);
-        }
-        let offset_end = value.iter().map(|val| val.get_size()).sum::<usize>() + offset;
-        let self_len = self.len();
-
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
-        }
+    fn write_str(&mut self, offset: usize, value: &str) -> WriterResult<usize> {
+        self.write_bytes(offset, value.as_bytes())
+    }
 
-        let mut write_size = 0;
+    /// Same as [Writer::write_str], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_str(&mut self, offset: usize, value: &str) -> usize {
+        self.write_str(offset, value).unwrap_or(0)
+    }
 
-        for val in value {
-            self.write_be(offset + write_size, val)?;
-            write_size += val.get_size();
+    /// Same as [Writer::write_str], but errors if the string contains non-ASCII characters.
+    #[inline(always)]
+    fn write_ascii(&mut self, offset: usize, value: &str) -> WriterResult<usize> {
+        if !value.is_ascii() {
+            return Err(Error::InvalidWrite {
+                message: "String contains non-ASCII characters",
+            });
         }
 
-        Ok(write_size)
+        self.write_str(offset, value)
     }
 
+    /// Same as [Writer::write_ascii], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the string is invalid or won't fit into the offset.
     #[inline(always)]
-    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
-        let offset_end = offset + length;
-        let self_len = self.len();
+    fn checked_write_ascii(&mut self, offset: usize, value: &str) -> usize {
+        self.write_ascii(offset, value).unwrap_or(0)
+    }
 
-        if offset_end > self_len {
-            self.resize(offset_end, 0);
+    /// Writes a string followed by a null terminator and returns the number of bytes written,
+    /// including the terminator.
+    ///
+    /// Errors if the string contains a null byte, since it would be truncated when read back,
+    /// or if the string and terminator will not fit at the offset.
+    #[inline(always)]
+    fn write_c_string(&mut self, offset: usize, value: &str) -> WriterResult<usize> {
+        let bytes = value.as_bytes();
+
+        if bytes.contains(&0) {
+            return Err(Error::InvalidWrite {
+                message: "String contains an interior null byte",
+            });
         }
 
-        let slice = self.get_mut_slice();
-        Ok(&mut slice[offset..offset_end])
+        let length = bytes.len() + 1;
+        self.get_sized_mut_slice(offset, length)?;
+        self.write_bytes(offset, bytes)?;
+        self.write_bytes(offset + bytes.len(), &[0])?;
+        Ok(length)
     }
-}
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use crate::Reader;
 
-    pub struct MockWriter {
-        bytes: [u8; 8],
+    /// Same as [Writer::write_c_string], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the string is invalid or won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_c_string(&mut self, offset: usize, value: &str) -> usize {
+        self.write_c_string(offset, value).unwrap_or(0)
     }
 
-    impl MockWriter {
-        fn new(bytes: [u8; 8]) -> Self {
-            Self { bytes }
-        }
+    /// Writes a string followed by a null terminator into a field of `field_len` bytes,
+    /// padding the rest of the field with zeroes, and returns `field_len`.
+    ///
+    /// Errors if the string contains a null byte, if the string and terminator don't fit in the field,
+    /// or if the field will not fit at the offset.
+    #[inline(always)]
+    fn write_c_string_padded(
+        &mut self,
+        offset: usize,
+        value: &str,
+        field_len: usize,
+    ) -> WriterResult<usize> {
+        let bytes = value.as_bytes();
 
-        fn get_bytes(&self) -> [u8; 8] {
-            self.bytes.clone()
+        if bytes.contains(&0) {
+            return Err(Error::InvalidWrite {
+                message: "String contains an interior null byte",
+            });
         }
-    }
 
-    impl Writer for MockWriter {
-        fn get_mut_slice(&mut self) -> &mut [u8] {
-            &mut self.bytes
+        if bytes.len() >= field_len {
+            return Err(Error::InvalidWrite {
+                message: "String does not fit in the field width",
+            });
         }
+
+        self.get_sized_mut_slice(offset, field_len)?;
+        self.write_bytes(offset, bytes)?;
+        self.fill(offset + bytes.len(), field_len - bytes.len(), 0)?;
+        Ok(field_len)
     }
 
-    impl Reader for MockWriter {
-        fn get_slice(&self) -> &[u8] {
-            &self.bytes
-        }
+    /// Writes a string as little endian UTF-16 and returns the number of bytes written.
+    ///
+    /// If `field_units` is provided, the string is written into a field of that many UTF-16 code units,
+    /// and the rest of the field is padded with `0x0000` code units.
+    /// Errors if the string doesn't fit in the field, or if the bytes will not fit at the offset.
+    #[inline(always)]
+    fn write_utf16_le_string(
+        &mut self,
+        offset: usize,
+        value: &str,
+        field_units: Option<usize>,
+    ) -> WriterResult<usize> {
+        write_utf16_string(self, offset, value, field_units, u16::to_le_bytes)
     }
 
-    mod get_sized_mut_slice {
-        use super::*;
+    /// Same as [Writer::write_utf16_le_string], but writes the big endian representation.
+    #[inline(always)]
+    fn write_utf16_be_string(
+        &mut self,
+        offset: usize,
+        value: &str,
+        field_units: Option<usize>,
+    ) -> WriterResult<usize> {
+        write_utf16_string(self, offset, value, field_units, u16::to_be_bytes)
+    }
+}
 
-        use alloc::{vec, vec::Vec};
+/// Generic methods for every [Writer].
+///
+/// These are kept out of [Writer] so it stays object safe.
+/// The trait is implemented for every sized writer, including references to trait objects such as `&mut dyn Writer`.
+pub trait WriterExt: Writer + Sized {
+    /// Same as [Writer::get_sized_mut_slice], except the length comes from `T.len()`.
+    #[inline(always)]
+    fn get_type_sized_mut_slice<T: Sized>(&mut self, offset: usize) -> WriterResult<&mut [u8]> {
+        let length = mem::size_of::<T>();
+        self.get_sized_mut_slice(offset, length)
+    }
+
+    /// Same as [Writer::write_bytes], but writes a [TriviallyTransmutable] type by converting it to bytes.
+    #[inline(always)]
+    fn write<T: TriviallyTransmutable>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let bytes = transmute_one_to_bytes(value);
+        self.write_bytes(offset, bytes)
+    }
+
+    /// Same as [Writer::checked_write_bytes], but writes a [TriviallyTransmutable] type by converting it to bytes.
+    #[inline(always)]
+    fn checked_write<T: TriviallyTransmutable>(&mut self, offset: usize, value: &T) -> usize {
+        self.write(offset, value).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_le], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> usize {
+        self.write_le(offset, value).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_le], but returns `None` instead of an error if the value wasn't written.
+    ///
+    /// Unlike [WriterExt::checked_write_le], a successful write of a zero sized value can be told apart from a failed write.
+    #[inline(always)]
+    fn write_le_opt<T: EndianWrite>(&mut self, offset: usize, value: &T) -> Option<usize> {
+        self.write_le(offset, value).ok()
+    }
+
+    /// Same as [Writer::write_be], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> usize {
+        self.write_be(offset, value).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_be], but returns `None` instead of an error if the value wasn't written.
+    ///
+    /// Unlike [WriterExt::checked_write_be], a successful write of a zero sized value can be told apart from a failed write.
+    #[inline(always)]
+    fn write_be_opt<T: EndianWrite>(&mut self, offset: usize, value: &T) -> Option<usize> {
+        self.write_be(offset, value).ok()
+    }
+
+    /// Writes a value in its native endian representation.
+    ///
+    /// This should only be used for data that will be consumed by the same machine,
+    /// such as shared memory or in-memory IPC.
+    #[inline(always)]
+    fn write_ne<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        if cfg!(target_endian = "little") {
+            self.write_le(offset, value)
+        } else {
+            self.write_be(offset, value)
+        }
+    }
+
+    /// Same as [WriterExt::write_ne], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_ne<T: EndianWrite>(&mut self, offset: usize, value: &T) -> usize {
+        self.write_ne(offset, value).unwrap_or(0)
+    }
+
+    /// Writes a value in its little endian representation at the next multiple of `align` from an offset.
+    /// The skipped bytes are set to zero.
+    ///
+    /// Returns the number of bytes written from the original offset, including the padding.
+    /// Errors if `align` is not a power of two, or if the padding and value will not fit at the offset.
+    /// Nothing is written on error.
+    #[inline(always)]
+    fn write_le_aligned<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        align: usize,
+    ) -> WriterResult<usize> {
+        let padding = alignment_padding(offset, align)?;
+        let value_offset = checked_offset_end(offset, padding)?;
+        let value_end = checked_offset_end(value_offset, value.get_size())?;
+        self.get_sized_mut_slice(offset, value_end - offset)?;
+        self.fill(offset, padding, 0)?;
+        Ok(padding + self.write_le(value_offset, value)?)
+    }
+
+    /// Same as [WriterExt::write_le_aligned], but writes the value in its big endian representation.
+    #[inline(always)]
+    fn write_be_aligned<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &T,
+        align: usize,
+    ) -> WriterResult<usize> {
+        let padding = alignment_padding(offset, align)?;
+        let value_offset = checked_offset_end(offset, padding)?;
+        let value_end = checked_offset_end(value_offset, value.get_size())?;
+        self.get_sized_mut_slice(offset, value_end - offset)?;
+        self.fill(offset, padding, 0)?;
+        Ok(padding + self.write_be(value_offset, value)?)
+    }
+
+    /// Same as [Writer::write_array_le], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_array_le<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &[T; SIZE],
+    ) -> usize {
+        if value.is_empty() {
+            return 0;
+        }
+
+        let size = match total_size(value) {
+            Some(size) => size,
+            None => return 0,
+        };
+        if !fits_at(self, offset, size) {
+            return 0;
+        }
+
+        self.write_array_le(offset, value).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_array_be], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_array_be<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &[T; SIZE],
+    ) -> usize {
+        if value.is_empty() {
+            return 0;
+        }
+
+        let size = match total_size(value) {
+            Some(size) => size,
+            None => return 0,
+        };
+        if !fits_at(self, offset, size) {
+            return 0;
+        }
+
+        self.write_array_be(offset, value).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_slice_le], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_slice_le<T: EndianWrite>(&mut self, offset: usize, values: &[T]) -> usize {
+        if values.is_empty() {
+            return 0;
+        }
+
+        let size = match total_size(values) {
+            Some(size) => size,
+            None => return 0,
+        };
+        if !fits_at(self, offset, size) {
+            return 0;
+        }
+
+        self.write_slice_le(offset, values).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_slice_be], but checks to make sure the bytes can safely be written to the offset.
+    /// Returns 0 as the write size if the bytes won't fit into the offset.
+    #[inline(always)]
+    fn checked_write_slice_be<T: EndianWrite>(&mut self, offset: usize, values: &[T]) -> usize {
+        if values.is_empty() {
+            return 0;
+        }
+
+        let size = match total_size(values) {
+            Some(size) => size,
+            None => return 0,
+        };
+        if !fits_at(self, offset, size) {
+            return 0;
+        }
+
+        self.write_slice_be(offset, values).unwrap_or(0)
+    }
+
+    /// Same as [Writer::write_array_le], but never leaves a partially written array behind.
+    ///
+    /// The space needed for every value is validated before anything is written.
+    /// If a value still fails to write, the original bytes are restored before the error is returned.
+    /// Growable writers, such as vectors, may keep their grown length.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_array_le_all<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &[T; SIZE],
+    ) -> WriterResult<usize> {
+        self.write_slice_le_all(offset, value)
+    }
+
+    /// Same as [WriterExt::write_array_le_all], but writes the big endian representation of each value.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_array_be_all<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &[T; SIZE],
+    ) -> WriterResult<usize> {
+        self.write_slice_be_all(offset, value)
+    }
+
+    /// Same as [WriterExt::write_array_le_all], but writes a slice with a length that's only known at runtime.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_slice_le_all<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        values: &[T],
+    ) -> WriterResult<usize> {
+        write_all_values(self, offset, values, Self::write_le)
+    }
+
+    /// Same as [WriterExt::write_array_be_all], but writes a slice with a length that's only known at runtime.
+    #[cfg(feature = "alloc")]
+    #[inline(always)]
+    fn write_slice_be_all<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        values: &[T],
+    ) -> WriterResult<usize> {
+        write_all_values(self, offset, values, Self::write_be)
+    }
+
+    /// Writes each value from an iterator sequentially in its little endian representation
+    /// and returns the total number of bytes written.
+    ///
+    /// If a value can't be written, an [Error::IncompleteIterWrite] is returned
+    /// with the offset of the value, the number of values that were written before it,
+    /// and the error the value returned.
+    #[inline(always)]
+    fn write_iter_le<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        offset: usize,
+        iter: I,
+    ) -> WriterResult<usize> {
+        let mut write_size = 0;
+
+        for (items_written, value) in iter.into_iter().enumerate() {
+            let value_offset = checked_offset_end(offset, write_size)?;
+            write_size += self
+                .write_le(value_offset, &value)
+                .map_err(|error| incomplete_iter_write(error, value_offset, items_written))?;
+        }
+
+        Ok(write_size)
+    }
+
+    /// Same as [WriterExt::write_iter_le], but writes the big endian representation of each value.
+    #[inline(always)]
+    fn write_iter_be<T: EndianWrite, I: IntoIterator<Item = T>>(
+        &mut self,
+        offset: usize,
+        iter: I,
+    ) -> WriterResult<usize> {
+        let mut write_size = 0;
+
+        for (items_written, value) in iter.into_iter().enumerate() {
+            let value_offset = checked_offset_end(offset, write_size)?;
+            write_size += self
+                .write_be(value_offset, &value)
+                .map_err(|error| incomplete_iter_write(error, value_offset, items_written))?;
+        }
+
+        Ok(write_size)
+    }
+}
+
+impl<T: Writer> WriterExt for T {}
+
+#[inline(always)]
+fn write_utf16_string<W: Writer + ?Sized>(
+    writer: &mut W,
+    offset: usize,
+    value: &str,
+    field_units: Option<usize>,
+    to_bytes: fn(u16) -> [u8; 2],
+) -> WriterResult<usize> {
+    let string_units = value.encode_utf16().count();
+    let total_units = match field_units {
+        Some(field_units) if string_units > field_units => {
+            return Err(Error::InvalidWrite {
+                message: "String does not fit in the field width",
+            })
+        }
+        Some(field_units) => field_units,
+        None => string_units,
+    };
+
+    let length = checked_total_size(offset, 2, total_units)?;
+    writer.get_sized_mut_slice(offset, length)?;
+
+    for (index, unit) in value.encode_utf16().enumerate() {
+        writer.write_bytes(offset + index * 2, &to_bytes(unit))?;
+    }
+
+    let string_length = string_units * 2;
+    writer.fill(offset + string_length, length - string_length, 0)?;
+    Ok(length)
+}
+
+#[cfg(feature = "alloc")]
+#[inline(always)]
+fn write_all_values<W: Writer + ?Sized, T: EndianWrite>(
+    writer: &mut W,
+    offset: usize,
+    values: &[T],
+    write: fn(&mut W, usize, &T) -> WriterResult<usize>,
+) -> WriterResult<usize> {
+    let offset_end = values
+        .iter()
+        .try_fold(offset, |end, val| checked_offset_end(end, val.get_size()))?;
+    let original = writer
+        .get_sized_mut_slice(offset, offset_end - offset)?
+        .to_vec();
+    let mut write_size = 0;
+
+    for val in values {
+        if let Err(error) = write(writer, offset + write_size, val) {
+            // The range was validated above, so restoring can't fail
+            let _ = writer.write_bytes(offset, &original);
+            return Err(error);
+        }
+
+        write_size += val.get_size();
+    }
+
+    Ok(write_size)
+}
+
+/// Returns the combined size of the values, or `None` if it would overflow.
+#[inline(always)]
+pub(crate) fn total_size<T: EndianWrite>(values: &[T]) -> Option<usize> {
+    values
+        .iter()
+        .try_fold(0usize, |size, val| size.checked_add(val.get_size()))
+}
+
+/// Returns whether `size` bytes can be written at an offset, according to [Writer::writable_len].
+#[inline(always)]
+pub(crate) fn fits_at<W: Writer + ?Sized>(writer: &mut W, offset: usize, size: usize) -> bool {
+    match writer.writable_len(offset) {
+        Some(len) => size <= len,
+        None => true,
+    }
+}
+
+#[inline(always)]
+pub(crate) fn erase_bytes(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        // Safety: the pointer comes from a mutable reference, so it's valid and aligned for writes.
+        unsafe { ptr::write_volatile(byte, 0) };
+    }
+
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Returns the number of bytes needed to move an offset to the next multiple of `align`.
+#[inline(always)]
+pub(crate) fn alignment_padding(offset: usize, align: usize) -> WriterResult<usize> {
+    if !align.is_power_of_two() {
+        return Err(Error::InvalidWrite {
+            message: "Alignment must be a power of two",
+        });
+    }
+
+    Ok(offset.wrapping_neg() & (align - 1))
+}
+
+#[inline(always)]
+pub(crate) fn validate_bits(bit_len: usize, value: u64) -> WriterResult<()> {
+    if bit_len > 64 {
+        return Err(Error::InvalidWrite {
+            message: "Bit length must be 64 or less",
+        });
+    }
+
+    if bit_len < 64 && value >> bit_len != 0 {
+        return Err(Error::InvalidWrite {
+            message: "Value does not fit in the bit length",
+        });
+    }
+
+    Ok(())
+}
+
+impl<const SIZE: usize> Writer for [u8; SIZE] {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+impl Writer for &mut [u8] {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Writer for Box<[u8]> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Writer for Vec<u8> {
+    #[inline(always)]
+    fn get_mut_slice(&mut self) -> &mut [u8] {
+        self.as_mut_slice()
+    }
+
+    #[inline(always)]
+    fn writable_len(&mut self, _offset: usize) -> Option<usize> {
+        None
+    }
+
+    #[inline(always)]
+    fn write_le<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let offset_end = checked_offset_end(offset, value.get_size())?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        add_error_context(
+            value.try_write_le(&mut self[offset..]),
+            offset,
+            self.get_mut_slice().len(),
+        )
+    }
+
+    #[inline(always)]
+    fn write_be<T: EndianWrite>(&mut self, offset: usize, value: &T) -> WriterResult<usize> {
+        let offset_end = checked_offset_end(offset, value.get_size())?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        add_error_context(
+            value.try_write_be(&mut self[offset..]),
+            offset,
+            self.get_mut_slice().len(),
+        )
+    }
+
+    #[inline(always)]
+    fn write_array_le<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &[T; SIZE],
+    ) -> WriterResult<usize> {
+        if value.is_empty() {
+            return Ok(0);
+        }
+        let offset_end = value
+            .iter()
+            .try_fold(offset, |end, val| checked_offset_end(end, val.get_size()))?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        let mut write_size = 0;
+
+        for val in value {
+            self.write_le(checked_offset_end(offset, write_size)?, val)?;
+            write_size += val.get_size();
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn write_array_be<const SIZE: usize, T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        value: &[T; SIZE],
+    ) -> WriterResult<usize> {
+        if value.is_empty() {
+            return Ok(0);
+        }
+        let offset_end = value
+            .iter()
+            .try_fold(offset, |end, val| checked_offset_end(end, val.get_size()))?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        let mut write_size = 0;
+
+        for val in value {
+            self.write_be(checked_offset_end(offset, write_size)?, val)?;
+            write_size += val.get_size();
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn write_slice_le<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        values: &[T],
+    ) -> WriterResult<usize> {
+        if values.is_empty() {
+            return Ok(0);
+        }
+        let offset_end = values
+            .iter()
+            .try_fold(offset, |end, val| checked_offset_end(end, val.get_size()))?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        let mut write_size = 0;
+
+        for val in values {
+            self.write_le(checked_offset_end(offset, write_size)?, val)?;
+            write_size += val.get_size();
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn write_slice_be<T: EndianWrite>(
+        &mut self,
+        offset: usize,
+        values: &[T],
+    ) -> WriterResult<usize> {
+        if values.is_empty() {
+            return Ok(0);
+        }
+        let offset_end = values
+            .iter()
+            .try_fold(offset, |end, val| checked_offset_end(end, val.get_size()))?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        let mut write_size = 0;
+
+        for val in values {
+            self.write_be(checked_offset_end(offset, write_size)?, val)?;
+            write_size += val.get_size();
+        }
+
+        Ok(write_size)
+    }
+
+    #[inline(always)]
+    fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+        if len == 0 {
+            return Ok(0);
+        }
+
+        let slice = self.get_sized_mut_slice(offset, len)?;
+        slice.fill(value);
+        Ok(len)
+    }
+
+    #[inline(always)]
+    fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+        let offset_end = checked_offset_end(offset, length)?;
+        let self_len = self.len();
+
+        if offset_end > self_len {
+            self.resize(offset_end, 0);
+        }
+
+        let slice = self.get_mut_slice();
+        Ok(&mut slice[offset..offset_end])
+    }
+}
+
+/// An interface for writers that can insert and remove bytes, shifting the data after them.
+pub trait GrowableWriter: Writer {
+    /// Inserts bytes at an offset, shifting everything after the offset back, and returns the number of bytes inserted.
+    ///
+    /// If the offset is past the end of the data, the gap is filled with zeroes.
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize>;
+
+    /// Removes `len` bytes at an offset, shifting everything after them forward, and returns the number of bytes removed.
+    ///
+    /// Errors if the range is past the end of the data.
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize>;
+}
+
+#[cfg(feature = "alloc")]
+impl GrowableWriter for Vec<u8> {
+    #[inline(always)]
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        if offset > self.len() {
+            self.resize(offset, 0);
+        }
+
+        self.splice(offset..offset, bytes.iter().copied());
+        Ok(bytes.len())
+    }
+
+    #[inline(always)]
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        let data_len = self.len();
+        let offset_end = checked_offset_end(offset, len)?;
+
+        if offset_end > data_len {
+            return Err(Error::InvalidSize {
+                wanted_size: len,
+                offset,
+                data_len,
+            });
+        }
+
+        self.drain(offset..offset_end);
+        Ok(len)
+    }
+}
+
+/// Forwards the object safe [Writer] methods through a pointer, or to a field with `this => this.field`.
+macro_rules! forward_writer_methods {
+    () => {
+        forward_writer_methods!(this => **this);
+    };
+    ($this:ident => $target:expr) => {
+        #[inline(always)]
+        fn get_mut_slice(&mut self) -> &mut [u8] {
+            let $this = self;
+            $target.get_mut_slice()
+        }
+
+        #[inline(always)]
+        fn get_sized_mut_slice(&mut self, offset: usize, length: usize) -> WriterResult<&mut [u8]> {
+            let $this = self;
+            $target.get_sized_mut_slice(offset, length)
+        }
+
+        #[inline(always)]
+        fn writable_len(&mut self, offset: usize) -> Option<usize> {
+            let $this = self;
+            $target.writable_len(offset)
+        }
+
+        #[inline(always)]
+        fn write_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_bytes(offset, bytes)
+        }
+
+        #[inline(always)]
+        fn write_all_slices(&mut self, offset: usize, parts: &[&[u8]]) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_all_slices(offset, parts)
+        }
+
+        #[inline(always)]
+        fn fill(&mut self, offset: usize, len: usize, value: u8) -> WriterResult<usize> {
+            let $this = self;
+            $target.fill(offset, len, value)
+        }
+
+        #[inline(always)]
+        fn erase(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+            let $this = self;
+            $target.erase(offset, len)
+        }
+
+        #[inline(always)]
+        fn copy_within(
+            &mut self,
+            src_offset: usize,
+            dst_offset: usize,
+            len: usize,
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.copy_within(src_offset, dst_offset, len)
+        }
+
+        #[inline(always)]
+        fn write_bits_msb(
+            &mut self,
+            byte_offset: usize,
+            bit_offset: usize,
+            bit_len: usize,
+            value: u64,
+        ) -> WriterResult<()> {
+            let $this = self;
+            $target.write_bits_msb(byte_offset, bit_offset, bit_len, value)
+        }
+
+        #[inline(always)]
+        fn write_bits_lsb(
+            &mut self,
+            byte_offset: usize,
+            bit_offset: usize,
+            bit_len: usize,
+            value: u64,
+        ) -> WriterResult<()> {
+            let $this = self;
+            $target.write_bits_lsb(byte_offset, bit_offset, bit_len, value)
+        }
+    };
+}
+pub(crate) use forward_writer_methods;
+
+/// Forwards the [Writer] methods that require `Self: Sized` through a pointer, or to a field with `this => this.field`,
+/// so writers that customize them, such as growing vectors, behave the same behind the pointer.
+macro_rules! forward_sized_writer_methods {
+    () => {
+        forward_sized_writer_methods!(this => **this);
+    };
+    ($this:ident => $target:expr) => {
+        #[inline(always)]
+        fn write_le<U: EndianWrite>(&mut self, offset: usize, value: &U) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_le(offset, value)
+        }
+
+        #[inline(always)]
+        fn write_be<U: EndianWrite>(&mut self, offset: usize, value: &U) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_be(offset, value)
+        }
+
+        #[inline(always)]
+        fn write_array_le<const SIZE: usize, U: EndianWrite>(
+            &mut self,
+            offset: usize,
+            value: &[U; SIZE],
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_array_le(offset, value)
+        }
+
+        #[inline(always)]
+        fn write_array_be<const SIZE: usize, U: EndianWrite>(
+            &mut self,
+            offset: usize,
+            value: &[U; SIZE],
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_array_be(offset, value)
+        }
+
+        #[inline(always)]
+        fn write_slice_le<U: EndianWrite>(
+            &mut self,
+            offset: usize,
+            values: &[U],
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_slice_le(offset, values)
+        }
+
+        #[inline(always)]
+        fn write_slice_be<U: EndianWrite>(
+            &mut self,
+            offset: usize,
+            values: &[U],
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_slice_be(offset, values)
+        }
+
+        #[inline(always)]
+        fn write_repeated_le<U: EndianWrite>(
+            &mut self,
+            offset: usize,
+            value: &U,
+            count: usize,
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_repeated_le(offset, value, count)
+        }
+
+        #[inline(always)]
+        fn write_repeated_be<U: EndianWrite>(
+            &mut self,
+            offset: usize,
+            value: &U,
+            count: usize,
+        ) -> WriterResult<usize> {
+            let $this = self;
+            $target.write_repeated_be(offset, value, count)
+        }
+    };
+}
+pub(crate) use forward_sized_writer_methods;
+
+/// Writes endian values to trait objects through [Writer::get_sized_mut_slice],
+/// since trait objects can't forward the methods that require `Self: Sized`.
+/// Writers that grow in [Writer::get_sized_mut_slice], such as vectors, still grow behind a trait object.
+macro_rules! sized_slice_writer_methods {
+    () => {
+        #[inline(always)]
+        fn write_le<U: EndianWrite>(&mut self, offset: usize, value: &U) -> WriterResult<usize> {
+            let data_len = self.get_mut_slice().len();
+            let bytes = self.get_sized_mut_slice(offset, value.get_size())?;
+            add_error_context(value.try_write_le(bytes), offset, data_len)
+        }
+
+        #[inline(always)]
+        fn write_be<U: EndianWrite>(&mut self, offset: usize, value: &U) -> WriterResult<usize> {
+            let data_len = self.get_mut_slice().len();
+            let bytes = self.get_sized_mut_slice(offset, value.get_size())?;
+            add_error_context(value.try_write_be(bytes), offset, data_len)
+        }
+    };
+}
+
+impl<T: Writer> Writer for &mut T {
+    forward_writer_methods!();
+    forward_sized_writer_methods!();
+}
+
+/// Trait objects can't forward the methods that require `Self: Sized`,
+/// so those are built on the object safe methods instead.
+///
+/// Method calls on a `&mut dyn Writer` resolve to the trait object itself,
+/// so call those methods on a reference to it, such as `(&mut writer).write_le(0, &value)`.
+impl<'a> Writer for &mut (dyn Writer + 'a) {
+    forward_writer_methods!();
+    sized_slice_writer_methods!();
+}
+
+impl<T: GrowableWriter> GrowableWriter for &mut T {
+    #[inline(always)]
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        (**self).insert_bytes(offset, bytes)
+    }
+
+    #[inline(always)]
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        (**self).remove_bytes(offset, len)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Writer> Writer for Box<T> {
+    forward_writer_methods!();
+    forward_sized_writer_methods!();
+}
+
+/// Same as the `&mut dyn Writer` implementation, the methods that require `Self: Sized` aren't forwarded.
+#[cfg(feature = "alloc")]
+impl<'a> Writer for Box<dyn Writer + 'a> {
+    forward_writer_methods!();
+    sized_slice_writer_methods!();
+}
+
+#[cfg(feature = "alloc")]
+impl<T: GrowableWriter> GrowableWriter for Box<T> {
+    #[inline(always)]
+    fn insert_bytes(&mut self, offset: usize, bytes: &[u8]) -> WriterResult<usize> {
+        (**self).insert_bytes(offset, bytes)
+    }
+
+    #[inline(always)]
+    fn remove_bytes(&mut self, offset: usize, len: usize) -> WriterResult<usize> {
+        (**self).remove_bytes(offset, len)
+    }
+}
+
+#[cfg(test)]
+#[allow(dead_code, clippy::clone_on_copy)]
+mod test {
+    use super::*;
+    use crate::{Reader, ReaderExt};
+
+    pub struct MockWriter {
+        bytes: [u8; 8],
+    }
+
+    impl MockWriter {
+        fn new(bytes: [u8; 8]) -> Self {
+            Self { bytes }
+        }
+
+        fn get_bytes(&self) -> [u8; 8] {
+            self.bytes.clone()
+        }
+    }
+
+    impl Writer for MockWriter {
+        fn get_mut_slice(&mut self) -> &mut [u8] {
+            &mut self.bytes
+        }
+    }
+
+    impl Reader for MockWriter {
+        fn get_slice(&self) -> &[u8] {
+            &self.bytes
+        }
+    }
+
+    mod get_sized_mut_slice {
+        use super::*;
+
+        #[cfg(feature = "alloc")]
+        use alloc::{vec, vec::Vec};
+
+        #[test]
+        fn should_return_mut_slice() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let slice = writer
+                .get_sized_mut_slice(2, 4)
+                .expect("Should have succeeded");
+
+            let expected_result = [3, 4, 5, 6];
+            assert_eq!(slice, expected_result);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .get_sized_mut_slice(2, 100)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 100,
+                    offset: 2,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer: Vec<u8> = vec![];
+
+            let result = writer
+                .get_sized_mut_slice(2, 4)
+                .expect("Should have succeeded");
+
+            let expected_result = [0; 4];
+            assert_eq!(result, expected_result);
+            assert_eq!(writer.len(), 6);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_not_needed() {
+            let mut writer: Vec<u8> = vec![0; 4];
+
+            let result = writer
+                .get_sized_mut_slice(0, 4)
+                .expect("Should have succeeded");
+
+            let expected_result = [0; 4];
+            assert_eq!(result, expected_result);
+            assert_eq!(writer.len(), 4);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_not_error_if_vector_size_is_larger_than_write_size() {
+            let mut writer: Vec<u8> = vec![0; 10];
+
+            let result = writer
+                .get_sized_mut_slice(2, 4)
+                .expect("Should have succeeded");
+
+            let expected_result = [0; 4];
+            assert_eq!(result, expected_result);
+            assert_eq!(writer.len(), 10);
+        }
+    }
+
+    mod get_type_sized_mut_slice {
+        use super::*;
+
+        #[test]
+        fn should_return_mut_slice() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let slice = writer
+                .get_type_sized_mut_slice::<u32>(2)
+                .expect("Should have succeeded");
+
+            let expected_result = [3, 4, 5, 6];
+            assert_eq!(slice, expected_result);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .get_type_sized_mut_slice::<u32>(6)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod write_bytes {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            let written_length = writer
+                .write_bytes(2, &bytes)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let inner = writer.get_bytes();
+            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            let error = writer
+                .write_bytes(6, &bytes)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            let written_length = writer
+                .write_bytes(2, &bytes)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            assert_eq!(writer, vec![0, 0, 0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(writer.len(), 6);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_not_needed() {
+            let mut writer = vec![0; 4];
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            let written_length = writer
+                .write_bytes(0, &bytes)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(writer.len(), 4);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed_and_written_to_twice() {
+            let mut writer = vec![];
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            let written_length = writer
+                .write_bytes(0, &bytes)
+                .expect("Write should have succeeded");
+            assert_eq!(written_length, 4);
+
+            let written_length = writer
+                .write_bytes(4, &bytes)
+                .expect("Write should have succeeded");
+            assert_eq!(written_length, 4);
+
+            assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd, 0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(writer.len(), 8);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed_with_le() {
+            let mut writer = vec![];
+            let written_length = writer
+                .write_le(0, &0xaabbccddu32)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, vec![0xdd, 0xcc, 0xbb, 0xaa]);
+            assert_eq!(writer.len(), 4);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed_with_be() {
+            let mut writer = vec![];
+            let written_length = writer
+                .write_be(0, &0xaabbccddu32)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd]);
+            assert_eq!(writer.len(), 4);
+        }
+    }
+
+    mod checked_write_bytes {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_an_empty_vector() {
+            let mut writer = vec![];
+            let written_length = writer.checked_write_bytes(2, &[0xaa, 0xbb]);
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer, [0, 0, 0xaa, 0xbb]);
+        }
+
+        #[test]
+        fn should_write_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+            let written_length = writer.checked_write_bytes(2, &bytes);
+
+            assert_eq!(written_length, 4);
+
+            let inner = writer.get_bytes();
+            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(initial_bytes.clone());
+            let bytes_to_write = [0xaa, 0xbb, 0xcc, 0xdd];
+            let written_length = writer.checked_write_bytes(6, &bytes_to_write);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), initial_bytes);
+        }
+    }
+
+    mod fill {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_fill_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.fill(2, 4, 0xff).expect("Fill should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 0xff, 0xff, 0xff, 0xff, 7, 8]);
+        }
+
+        #[test]
+        fn should_fill_nothing_if_length_is_zero() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.fill(8, 0, 0xff).expect("Fill should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .fill(6, 4, 0)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer.fill(2, 3, 0xaa).expect("Fill should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer, [1, 2, 0xaa, 0xaa, 0xaa]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_length_is_zero() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer.fill(8, 0, 0xaa).expect("Fill should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer, [1, 2, 3]);
+        }
+    }
+
+    mod checked_fill {
+        use super::*;
+
+        #[test]
+        fn should_fill_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.checked_fill(4, 4, 0);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(initial_bytes);
+            let written_length = writer.checked_fill(5, 4, 0);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), initial_bytes);
+        }
+    }
+
+    mod write_zeroes {
+        use super::*;
+
+        #[test]
+        fn should_zero_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_zeroes(1, 3)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [1, 0, 0, 0, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_zeroes(6, 3)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod erase {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_zero_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.erase(4, 4).expect("Erase should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .erase(5, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_zero_vector_bytes() {
+            let mut writer = vec![0xaa; 4];
+            let written_length = writer.erase(0, 4).expect("Erase should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [0; 4]);
+        }
+    }
+
+    mod copy_within {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_copy_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .copy_within(0, 6, 2)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 1, 2]);
+        }
+
+        #[test]
+        fn should_copy_overlapping_bytes_forward() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .copy_within(1, 3, 4)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 2, 3, 4, 5, 8]);
+        }
+
+        #[test]
+        fn should_copy_overlapping_bytes_backward() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .copy_within(3, 1, 4)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 4, 5, 6, 7, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_source_is_out_of_range() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .copy_within(6, 0, 4)
+                .expect_err("Source should have been out of range");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_destination_is_out_of_range() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .copy_within(0, 5, 4)
+                .expect_err("Destination should have been out of range");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2, 3, 4];
+            let written_length = writer
+                .copy_within(1, 3, 3)
+                .expect("Copy should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer, [1, 2, 3, 2, 3, 4]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_source_is_out_of_range() {
+            let mut writer = vec![1, 2, 3, 4];
+            let error = writer
+                .copy_within(2, 4, 3)
+                .expect_err("Source should have been out of range");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 2,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(writer, [1, 2, 3, 4]);
+        }
+    }
+
+    mod checked_copy_within {
+        use super::*;
+
+        #[test]
+        fn should_copy_bytes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.checked_copy_within(4, 0, 4);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [5, 6, 7, 8, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_0_if_a_range_is_out_of_range() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(initial_bytes);
+            let written_length = writer.checked_copy_within(0, 7, 2);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), initial_bytes);
+        }
+    }
+
+    mod write_all_slices {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_slices_consecutively() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_all_slices(1, &[&[0xaa], &[0xbb, 0xcc], &[], &[0xdd, 0xee]])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_bytes(), [1, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_nothing_for_no_slices() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_all_slices(8, &[])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_write_if_slices_are_too_large() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_all_slices(4, &[&[0xaa, 0xbb], &[0xcc, 0xdd], &[0xee]])
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 5,
+                    offset: 4,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2];
+            let written_length = writer
+                .write_all_slices(1, &[&[0xaa, 0xbb], &[0xcc]])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer, [1, 0xaa, 0xbb, 0xcc]);
+        }
+    }
+
+    mod writable_len {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_return_the_remaining_length_of_fixed_writers() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            assert_eq!(writer.writable_len(2), Some(6));
+            assert_eq!(writer.writable_len(10), Some(0));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_return_none_for_vectors() {
+            let mut writer = vec![1, 2];
+            assert_eq!(writer.writable_len(10), None);
+        }
+    }
+
+    mod write_bytes_truncated {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_bytes_that_fit_exactly() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.write_bytes_truncated(4, &[0xaa, 0xbb, 0xcc, 0xdd]);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0xaa, 0xbb, 0xcc, 0xdd]);
+        }
+
+        #[test]
+        fn should_write_bytes_that_fit() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.write_bytes_truncated(6, &[0xaa, 0xbb, 0xcc, 0xdd]);
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 0xaa, 0xbb]);
+        }
+
+        #[test]
+        fn should_write_nothing_if_there_is_no_space() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.write_bytes_truncated(8, &[0xaa, 0xbb]);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_nothing_if_offset_is_out_of_range() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer.write_bytes_truncated(10, &[0xaa, 0xbb]);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2];
+            let written_length = writer.write_bytes_truncated(1, &[0xaa, 0xbb, 0xcc]);
+
+            assert_eq!(written_length, 3);
+            assert_eq!(writer, [1, 0xaa, 0xbb, 0xcc]);
+        }
+    }
+
+    mod write {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write(4, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer.read::<u32>(4).expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let error = writer
+                .write(6, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod checked_write {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write(4, &value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer.read::<u32>(4).expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes.clone());
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_le(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let error = writer
+                .write_le(6, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_le(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.len(), 6);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_write_value_to_a_boxed_slice() {
+            let mut writer: Box<[u8]> = vec![0; 6].into_boxed_slice();
+            let written_length = writer
+                .write_le(2, &0xaabbccddu32)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_boxed_slice() {
+            let mut writer: Box<[u8]> = vec![0; 4].into_boxed_slice();
+            let error = writer
+                .write_le(2, &0xaabbccddu32)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 2,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(writer.len(), 4);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_not_needed() {
+            let mut writer = vec![0; 4];
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_le(0, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(0)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.len(), 4);
+        }
+
+        #[derive(Debug)]
+        struct CustomErrorTest(u32);
+
+        impl EndianWrite for CustomErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidRead {
+                    message: "Custom error!",
+                })
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_custom_errors_for_vec() {
+            let value = CustomErrorTest(0);
+            let mut bytes = vec![];
+            let result = bytes.write_le(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_custom_errors_for_slice() {
+            let value = CustomErrorTest(0);
+            let bytes = &mut [];
+            let result = bytes.write_le(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[derive(Debug)]
+        struct OffsetErrorTest(u32);
+
+        impl EndianWrite for OffsetErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 1,
+                    data_len: 0,
+                })
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_error_offsets_for_vec() {
+            let value = OffsetErrorTest(0);
+            let mut bytes = vec![];
+            let result = bytes.write_le(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 2,
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_error_offsets_for_slice() {
+            let value = OffsetErrorTest(0);
+            let bytes = &mut [];
+            let result = bytes.write_le(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 0,
+            };
+            assert_eq!(result, expected)
+        }
+    }
+
+    mod checked_write_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_an_empty_vector() {
+            let mut writer = vec![];
+            let written_length = writer.checked_write_le(10, &0xaabbccddu32);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.len(), 14);
+            assert_eq!(writer[10..], [0xdd, 0xcc, 0xbb, 0xaa]);
+        }
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write_le(2, &value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_le::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes.clone());
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write_le(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_be {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_be(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_be::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let error = writer
+                .write_be(6, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_be(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_be::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.len(), 6);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_not_needed() {
+            let mut writer = vec![0; 4];
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_be(0, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_be::<u32>(0)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.len(), 4);
+        }
+
+        #[derive(Debug)]
+        struct CustomErrorTest(u32);
+
+        impl EndianWrite for CustomErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidRead {
+                    message: "Custom error!",
+                })
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_custom_errors_for_vec() {
+            let value = CustomErrorTest(0);
+            let mut bytes = vec![];
+            let result = bytes.write_be(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_custom_errors_for_slice() {
+            let value = CustomErrorTest(0);
+            let bytes = &mut [];
+            let result = bytes.write_be(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[derive(Debug)]
+        struct OffsetErrorTest(u32);
+
+        impl EndianWrite for OffsetErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 1,
+                    data_len: 0,
+                })
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_error_offsets_for_vec() {
+            let value = OffsetErrorTest(0);
+            let mut bytes = vec![];
+            let result = bytes.write_be(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 2,
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_error_offsets_for_slice() {
+            let value = OffsetErrorTest(0);
+            let bytes = &mut [];
+            let result = bytes.write_be(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 0,
+            };
+            assert_eq!(result, expected)
+        }
+    }
+
+    mod checked_write_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write_be(2, &value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_be::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes.clone());
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write_be(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_bytes_opt {
+        use super::*;
+
+        #[test]
+        fn should_return_the_write_size() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            assert_eq!(writer.write_bytes_opt(2, &[0xaa, 0xbb]), Some(2));
+            assert_eq!(writer.get_bytes(), [1, 2, 0xaa, 0xbb, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_tell_empty_writes_apart_from_failures() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            assert_eq!(writer.write_bytes_opt(8, &[]), Some(0));
+            assert_eq!(writer.write_bytes_opt(8, &[0xaa]), None);
+            assert_eq!(writer.checked_write_bytes(8, &[0xaa]), 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_le_opt {
+        use super::*;
+
+        #[test]
+        fn should_return_the_write_size() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_le_opt(0, &0xaabbccddu32), Some(4));
+            assert_eq!(writer.get_bytes(), [0xdd, 0xcc, 0xbb, 0xaa, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_some_for_zero_sized_values() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_le_opt(8, &()), Some(0));
+            assert_eq!(
+                writer.write_le_opt(8, &core::marker::PhantomData::<u32>),
+                Some(0)
+            );
+            assert_eq!(writer.checked_write_le(8, &()), 0);
+        }
+
+        #[test]
+        fn should_return_none_if_the_value_was_not_written() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_le_opt(6, &0xaabbccddu32), None);
+            assert_eq!(writer.checked_write_le(6, &0xaabbccddu32), 0);
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+    }
+
+    mod write_be_opt {
+        use super::*;
+
+        #[test]
+        fn should_return_the_write_size() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_be_opt(0, &0xaabbccddu32), Some(4));
+            assert_eq!(writer.get_bytes(), [0xaa, 0xbb, 0xcc, 0xdd, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_return_some_for_zero_sized_values() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_be_opt(8, &()), Some(0));
+            assert_eq!(
+                writer.write_be_opt(8, &core::marker::PhantomData::<u32>),
+                Some(0)
+            );
+            assert_eq!(writer.checked_write_be(8, &()), 0);
+        }
+
+        #[test]
+        fn should_return_none_if_the_value_was_not_written() {
+            let mut writer = MockWriter::new([0; 8]);
+            assert_eq!(writer.write_be_opt(6, &0xaabbccddu32), None);
+            assert_eq!(writer.checked_write_be(6, &0xaabbccddu32), 0);
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+    }
+
+    mod write_le_aligned {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_pad_to_the_alignment() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_le_aligned(1, &0x1122u16, 4)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_bytes(), [1, 0, 0, 0, 0x22, 0x11, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_pad_aligned_offsets() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_le_aligned(4, &0x1122u16, 4)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0x22, 0x11, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer
+                .write_le_aligned(3, &0x1122u16, 16)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 15);
+            assert_eq!(writer.len(), 18);
+            assert!(writer[3..16].iter().all(|byte| *byte == 0));
+            assert_eq!(writer[16..], [0x22, 0x11]);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_alignments() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+
+            for align in [0, 3, 6] {
+                let error = writer
+                    .write_le_aligned(1, &0x1122u16, align)
+                    .expect_err("Alignment should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidWrite {
+                        message: "Alignment must be a power of two",
+                    }
+                );
+            }
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_value_end_overflows() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_le_aligned(usize::MAX - 2, &0x1122u16, 2)
+                .expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX - 1,
+                    size: 2,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_write_if_the_padded_value_does_not_fit() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_le_aligned(5, &0x1122u16, 8)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 5,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_be_aligned {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_pad_to_the_alignment() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_be_aligned(1, &0x1122u16, 4)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_bytes(), [1, 0, 0, 0, 0x11, 0x22, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_pad_aligned_offsets() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_be_aligned(4, &0x1122u16, 4)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 0x11, 0x22, 7, 8]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer
+                .write_be_aligned(3, &0x1122u16, 16)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 15);
+            assert_eq!(writer.len(), 18);
+            assert!(writer[3..16].iter().all(|byte| *byte == 0));
+            assert_eq!(writer[16..], [0x11, 0x22]);
+        }
+
+        #[test]
+        fn should_return_error_for_invalid_alignments() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+
+            for align in [0, 3, 6] {
+                let error = writer
+                    .write_be_aligned(1, &0x1122u16, align)
+                    .expect_err("Alignment should have been invalid");
+
+                assert_eq!(
+                    error,
+                    Error::InvalidWrite {
+                        message: "Alignment must be a power of two",
+                    }
+                );
+            }
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_value_end_overflows() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_be_aligned(usize::MAX - 2, &0x1122u16, 2)
+                .expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX - 1,
+                    size: 2,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_not_write_if_the_padded_value_does_not_fit() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_be_aligned(5, &0x1122u16, 8)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 5,
+                    offset: 5,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+    }
+
+    mod write_ne {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_ne(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            #[cfg(target_endian = "little")]
+            assert_eq!(writer.get_bytes(), [1, 2, 0xdd, 0xcc, 0xbb, 0xaa, 7, 8]);
+            #[cfg(target_endian = "big")]
+            assert_eq!(writer.get_bytes(), [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let error = writer
+                .write_ne(6, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let value = 0xaabbccddu32;
+            let written_length = writer
+                .write_ne(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_ne::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.len(), 6);
+        }
+    }
+
+    mod checked_write_ne {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write_ne(2, &value);
+
+            assert_eq!(written_length, 4);
+
+            let result = writer
+                .read_ne::<u32>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, 0xaabbccddu32);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes);
+            let value = 0xaabbccddu32;
+            let written_length = writer.checked_write_ne(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_bits_msb {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([0xff, 0, 0, 0, 0, 0, 0, 0]);
+            writer
+                .write_bits_msb(0, 0, 3, 0b010)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_bytes(), [0b0101_1111, 0, 0, 0, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_write_across_bytes() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer
+                .write_bits_msb(0, 3, 13, 0x1fff)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_bytes(), [0b0001_1111, 0xff, 0, 0, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_write_byte_aligned_fields() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer
+                .write_bits_msb(4, 0, 16, 0xaabb)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_bytes(), [0, 0, 0, 0, 0xaa, 0xbb, 0, 0]);
+        }
+
+        #[test]
+        fn should_write_64_bits() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer
+                .write_bits_msb(0, 0, 64, 0x11223344aabbccdd)
+                .expect("Write should have succeeded");
+            assert_eq!(
+                writer.get_bytes(),
+                [0x11, 0x22, 0x33, 0x44, 0xaa, 0xbb, 0xcc, 0xdd]
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            writer
+                .write_bits_msb(1, 4, 8, 0xab)
+                .expect("Write should have succeeded");
+            assert_eq!(writer, vec![0, 0x0a, 0xb0]);
+        }
+
+        #[test]
+        fn should_return_error_if_bits_are_out_of_range() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_bits_msb(7, 4, 8, 0)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_value_is_too_large() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_bits_msb(0, 0, 3, 0b1000)
+                .expect_err("Value should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Value does not fit in the bit length",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [0; 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_bit_length_is_too_large() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_bits_msb(0, 0, 65, 0)
+                .expect_err("Bit length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Bit length must be 64 or less",
+                }
+            );
+        }
+    }
+
+    mod write_bits_lsb {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([0xff, 0, 0, 0, 0, 0, 0, 0]);
+            writer
+                .write_bits_lsb(0, 0, 3, 0b010)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_bytes(), [0b1111_1010, 0, 0, 0, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_write_across_bytes() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer
+                .write_bits_lsb(0, 3, 13, 0x1fff)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_bytes(), [0b1111_1000, 0xff, 0, 0, 0, 0, 0, 0]);
+        }
+
+        #[test]
+        fn should_write_byte_aligned_fields() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer
+                .write_bits_lsb(4, 0, 16, 0xaabb)
+                .expect("Write should have succeeded");
+            assert_eq!(writer.get_bytes(), [0, 0, 0, 0, 0xbb, 0xaa, 0, 0]);
+        }
+
+        #[test]
+        fn should_round_trip_with_read_bits_lsb() {
+            let mut writer = MockWriter::new([0; 8]);
+            writer
+                .write_bits_lsb(2, 5, 21, 0x12345)
+                .expect("Write should have succeeded");
+            let value = writer
+                .read_bits_lsb(2, 5, 21)
+                .expect("Read should have succeeded");
+            assert_eq!(value, 0x12345);
+        }
+
+        #[test]
+        fn should_return_error_if_bits_are_out_of_range() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_bits_lsb(7, 4, 8, 0)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 7,
+                    data_len: 8,
+                }
+            );
+        }
+    }
+
+    mod write_array_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer
+                .write_array_le(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_le::<3, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let error = writer
+                .write_array_le(6, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer
+                .write_array_le(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_le::<3, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+            assert_eq!(writer.len(), 8);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_not_needed() {
+            let mut writer = vec![0; 6];
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer
+                .write_array_le(0, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_le::<3, u16>(0)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+            assert_eq!(writer.len(), 6);
+        }
+
+        #[derive(Debug)]
+        struct CustomErrorTest(u32);
+
+        impl EndianWrite for CustomErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidRead {
+                    message: "Custom error!",
+                })
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_custom_errors_for_vec() {
+            let value = [CustomErrorTest(0)];
+            let mut bytes = vec![];
+            let result = bytes.write_array_le(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_custom_errors_for_slice() {
+            let value = [CustomErrorTest(0)];
+            let bytes = &mut [];
+            let result = bytes.write_array_le(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[derive(Debug)]
+        struct OffsetErrorTest(u32);
+
+        impl EndianWrite for OffsetErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 1,
+                    data_len: 0,
+                })
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_error_offsets_for_vec() {
+            let value = [OffsetErrorTest(0)];
+            let mut bytes = vec![];
+            let result = bytes.write_array_le(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 2,
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_error_offsets_for_slice() {
+            let value = [OffsetErrorTest(0)];
+            let bytes = &mut [];
+            let result = bytes.write_array_le(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 0,
+            };
+            assert_eq!(result, expected)
+        }
+    }
+
+    mod checked_write_array_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_an_empty_vector() {
+            let mut writer = vec![];
+            let written_length = writer.checked_write_array_le(1, &[0x1122u16, 0x3344]);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [0, 0x22, 0x11, 0x44, 0x33]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![1, 2];
+            let written_length = writer.checked_write_array_le(2, &[0x1122u16, 0x3344]);
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [1, 2, 0x22, 0x11, 0x44, 0x33]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_a_slice() {
+            let mut bytes = [1, 2, 3, 4];
+            let mut writer = &mut bytes[..];
+            let written_length = writer.checked_write_array_le(2, &[0x1122u16, 0x3344]);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(bytes, [1, 2, 3, 4]);
+        }
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_array_le(2, &value);
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_le::<3, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes.clone());
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_array_le(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_array_be {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer
+                .write_array_be(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_be::<3, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let error = writer
+                .write_array_be(6, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer
+                .write_array_be(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_be::<3, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+            assert_eq!(writer.len(), 8);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_not_needed() {
+            let mut writer = vec![0; 6];
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer
+                .write_array_be(0, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_be::<3, u16>(0)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+            assert_eq!(writer.len(), 6);
+        }
+
+        #[derive(Debug)]
+        struct CustomErrorTest(u32);
+
+        impl EndianWrite for CustomErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidRead {
+                    message: "Custom error!",
+                })
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_custom_errors_for_vec() {
+            let value = [CustomErrorTest(0)];
+            let mut bytes = vec![];
+            let result = bytes.write_array_be(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_custom_errors_for_slice() {
+            let value = [CustomErrorTest(0)];
+            let bytes = &mut [];
+            let result = bytes.write_array_be(0, &value).unwrap_err();
+            let expected = Error::InvalidRead {
+                message: "Custom error!",
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[derive(Debug)]
+        struct OffsetErrorTest(u32);
+
+        impl EndianWrite for OffsetErrorTest {
+            fn get_size(&self) -> usize {
+                0
+            }
+            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                Err(Error::InvalidSize {
+                    wanted_size: 8,
+                    offset: 1,
+                    data_len: 0,
+                })
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_bubble_up_error_offsets_for_vec() {
+            let value = [OffsetErrorTest(0)];
+            let mut bytes = vec![];
+            let result = bytes.write_array_be(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 2,
+            };
+            assert_eq!(result, expected)
+        }
+
+        #[test]
+        fn should_bubble_up_error_offsets_for_slice() {
+            let value = [OffsetErrorTest(0)];
+            let bytes = &mut [];
+            let result = bytes.write_array_be(2, &value).unwrap_err();
+            let expected = Error::InvalidSize {
+                wanted_size: 8,
+                offset: 3,
+                data_len: 0,
+            };
+            assert_eq!(result, expected)
+        }
+    }
+
+    mod checked_write_array_be {
+        use super::*;
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_array_be(2, &value);
+
+            assert_eq!(written_length, 6);
+
+            let result = writer
+                .read_array_be::<3, u16>(2)
+                .expect("Read should have succeeded");
+            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        }
+
+        #[test]
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes.clone());
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_array_be(6, &value);
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_slice_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::{vec, vec::Vec};
+
+        #[derive(Debug)]
+        struct Repeat(u8, usize);
+
+        impl EndianWrite for Repeat {
+            fn get_size(&self) -> usize {
+                self.1
+            }
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                if dst.len() < self.1 {
+                    return Err(Error::InvalidSize {
+                        wanted_size: self.1,
+                        offset: 0,
+                        data_len: dst.len(),
+                    });
+                }
+                dst[..self.1].fill(self.0);
+                Ok(self.1)
+            }
+            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
+                unimplemented!()
+            }
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value: Vec<u16> = vec![0x1122, 0x3344, 0x5566];
+            let written_length = writer
+                .write_slice_le(2, &value)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, 2, 0x22, 0x11, 0x44, 0x33, 0x66, 0x55]
+            );
+        }
+
+        #[test]
+        fn should_write_nothing_for_empty_slices() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_slice_le::<u16>(8, &[])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_values_with_dynamic_sizes() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer
+                .write_slice_le(1, &[Repeat(0xaa, 1), Repeat(0xbb, 3), Repeat(0xcc, 2)])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [0, 0xaa, 0xbb, 0xbb, 0xbb, 0xcc, 0xcc, 0]
+            );
+        }
+
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let error = writer
+                .write_slice_le(4, &value)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 8,
+                    data_len: 8,
+                }
+            );
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let written_length = writer
+                .write_slice_le(2, &[Repeat(0xaa, 1), Repeat(0xbb, 3)])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [0, 0, 0xaa, 0xbb, 0xbb, 0xbb]);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_for_empty_slices() {
+            let mut writer: Vec<u8> = vec![];
+            let written_length = writer
+                .write_slice_le::<u32>(4, &[])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 0);
+            assert!(writer.is_empty());
+        }
+    }
+
+    mod checked_write_slice_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let written_length = writer.checked_write_slice_le(1, [0x1122u16].as_slice());
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer, [0, 0x22, 0x11]);
+        }
+
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_slice_le(2, value.as_slice());
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, 2, 0x22, 0x11, 0x44, 0x33, 0x66, 0x55]
+            );
+        }
 
         #[test]
-        fn should_return_mut_slice() {
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_slice_le(4, value.as_slice());
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
+        }
+    }
+
+    mod write_slice_be {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::{vec, vec::Vec};
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_write_value() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let slice = writer
-                .get_sized_mut_slice(2, 4)
-                .expect("Should have succeeded");
+            let value: Vec<u16> = vec![0x1122, 0x3344, 0x5566];
+            let written_length = writer
+                .write_slice_be(2, &value)
+                .expect("Write should have succeeded");
 
-            let expected_result = [3, 4, 5, 6];
-            assert_eq!(slice, expected_result);
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, 2, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66]
+            );
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
             let error = writer
-                .get_sized_mut_slice(2, 100)
+                .write_slice_be(4, &value)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
-                    wanted_size: 100,
-                    offset: 2,
+                    wanted_size: 2,
+                    offset: 8,
                     data_len: 8,
                 }
             );
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_grow_a_vector_if_needed() {
-            let mut writer: Vec<u8> = vec![];
-
-            let result = writer
-                .get_sized_mut_slice(2, 4)
-                .expect("Should have succeeded");
+            let mut writer = vec![];
+            let written_length = writer
+                .write_slice_be(1, &[0x1122u16, 0x3344])
+                .expect("Write should have succeeded");
 
-            let expected_result = [0; 4];
-            assert_eq!(result, expected_result);
-            assert_eq!(writer.len(), 6);
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [0, 0x11, 0x22, 0x33, 0x44]);
         }
+    }
 
-        #[test]
-        fn should_not_grow_a_vector_if_not_needed() {
-            let mut writer: Vec<u8> = vec![0; 4];
+    mod checked_write_slice_be {
+        use super::*;
 
-            let result = writer
-                .get_sized_mut_slice(0, 4)
-                .expect("Should have succeeded");
+        #[test]
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_slice_be(2, value.as_slice());
 
-            let expected_result = [0; 4];
-            assert_eq!(result, expected_result);
-            assert_eq!(writer.len(), 4);
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, 2, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66]
+            );
         }
 
         #[test]
-        fn should_not_not_error_if_vector_size_is_larger_than_write_size() {
-            let mut writer: Vec<u8> = vec![0; 10];
-
-            let result = writer
-                .get_sized_mut_slice(2, 4)
-                .expect("Should have succeeded");
+        fn should_return_0_if_size_is_too_large_for_offset() {
+            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(bytes);
+            let value = [0x1122u16, 0x3344, 0x5566];
+            let written_length = writer.checked_write_slice_be(4, value.as_slice());
 
-            let expected_result = [0; 4];
-            assert_eq!(result, expected_result);
-            assert_eq!(writer.len(), 10);
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), bytes);
         }
     }
 
-    mod get_type_sized_mut_slice {
+    mod write_repeated_le {
         use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[test]
-        fn should_return_mut_slice() {
+        fn should_return_error_if_the_total_size_overflows() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let slice = writer
-                .get_type_sized_mut_slice::<u32>(2)
-                .expect("Should have succeeded");
+            let error = writer
+                .write_repeated_le(1, &0xaabbu16, usize::MAX)
+                .expect_err("This should have failed");
 
-            let expected_result = [3, 4, 5, 6];
-            assert_eq!(slice, expected_result);
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: 1,
+                    size: usize::MAX,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_write_value_repeatedly() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let error = writer
-                .get_type_sized_mut_slice::<u32>(6)
-                .expect_err("Length should have been too large");
+            let written_length = writer
+                .write_repeated_le(1, &0xaabbu16, 3)
+                .expect("Write should have succeeded");
 
+            assert_eq!(written_length, 6);
             assert_eq!(
-                error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
-                    data_len: 8,
-                }
+                writer.get_bytes(),
+                [1, 0xbb, 0xaa, 0xbb, 0xaa, 0xbb, 0xaa, 8]
             );
         }
-    }
-
-    mod write_bytes {
-        use super::*;
-        use alloc::vec;
 
         #[test]
-        fn should_write_bytes() {
+        fn should_write_nothing_if_count_is_zero() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
             let written_length = writer
-                .write_bytes(2, &bytes)
+                .write_repeated_le(8, &0xaabbu16, 0)
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 4);
-
-            let inner = writer.get_bytes();
-            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
             let error = writer
-                .write_bytes(6, &bytes)
+                .write_repeated_le(2, &0xffffffffu32, 2)
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
+                    wanted_size: 8,
+                    offset: 2,
                     data_len: 8,
                 }
             );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_grow_a_vector_if_needed() {
             let mut writer = vec![];
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
             let written_length = writer
-                .write_bytes(2, &bytes)
+                .write_repeated_le(0, &0x11223344u32, 2)
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 4);
+            assert_eq!(written_length, 8);
+            assert_eq!(writer, [0x44, 0x33, 0x22, 0x11, 0x44, 0x33, 0x22, 0x11]);
+        }
+    }
 
-            assert_eq!(writer, vec![0, 0, 0xaa, 0xbb, 0xcc, 0xdd]);
-            assert_eq!(writer.len(), 6);
+    mod write_repeated_be {
+        use super::*;
+
+        #[test]
+        fn should_return_error_if_the_total_size_overflows() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_repeated_be(1, &0xaabbu16, usize::MAX)
+                .expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: 1,
+                    size: usize::MAX,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_not_grow_a_vector_if_not_needed() {
-            let mut writer = vec![0; 4];
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+        fn should_write_value_repeatedly() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let written_length = writer
-                .write_bytes(0, &bytes)
+                .write_repeated_be(0, &0xaabbu16, 4)
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 4);
+            assert_eq!(written_length, 8);
+            assert_eq!(
+                writer.get_bytes(),
+                [0xaa, 0xbb, 0xaa, 0xbb, 0xaa, 0xbb, 0xaa, 0xbb]
+            );
+        }
 
-            assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd]);
-            assert_eq!(writer.len(), 4);
+        #[test]
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer
+                .write_repeated_be(1, &0xaabbu16, 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
+    }
+
+    mod write_str {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[test]
-        fn should_grow_a_vector_if_needed_and_written_to_twice() {
-            let mut writer = vec![];
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
+        fn should_write_string() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let written_length = writer
-                .write_bytes(0, &bytes)
+                .write_str(2, "abc")
                 .expect("Write should have succeeded");
-            assert_eq!(written_length, 4);
 
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [1, 2, b'a', b'b', b'c', 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_multi_byte_characters() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let written_length = writer
-                .write_bytes(4, &bytes)
+                .write_str(1, "a\u{e9}\u{20ac}")
                 .expect("Write should have succeeded");
-            assert_eq!(written_length, 4);
 
-            assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd, 0xaa, 0xbb, 0xcc, 0xdd]);
-            assert_eq!(writer.len(), 8);
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, b'a', 0xc3, 0xa9, 0xe2, 0x82, 0xac, 8]
+            );
         }
 
         #[test]
-        fn should_grow_a_vector_if_needed_with_le() {
-            let mut writer = vec![];
-            let written_length = writer
-                .write_le(0, &0xaabbccddu32)
-                .expect("Write should have succeeded");
+        fn should_return_error_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_str(6, "abc")
+                .expect_err("Length should have been too large");
 
-            assert_eq!(written_length, 4);
-            assert_eq!(writer, vec![0xdd, 0xcc, 0xbb, 0xaa]);
-            assert_eq!(writer.len(), 4);
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 3,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
-        fn should_grow_a_vector_if_needed_with_be() {
+        fn should_grow_a_vector_if_needed() {
             let mut writer = vec![];
             let written_length = writer
-                .write_be(0, &0xaabbccddu32)
+                .write_str(1, "hi")
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 4);
-            assert_eq!(writer, vec![0xaa, 0xbb, 0xcc, 0xdd]);
-            assert_eq!(writer.len(), 4);
+            assert_eq!(written_length, 2);
+            assert_eq!(writer, [0, b'h', b'i']);
         }
     }
 
-    mod checked_write_bytes {
+    mod checked_write_str {
         use super::*;
 
         #[test]
-        fn should_write_bytes() {
+        fn should_write_string() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let bytes = [0xaa, 0xbb, 0xcc, 0xdd];
-            let written_length = writer.checked_write_bytes(2, &bytes);
-
-            assert_eq!(written_length, 4);
+            let written_length = writer.checked_write_str(5, "abc");
 
-            let inner = writer.get_bytes();
-            assert_eq!(inner, [1, 2, 0xaa, 0xbb, 0xcc, 0xdd, 7, 8]);
+            assert_eq!(written_length, 3);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, b'a', b'b', b'c']);
         }
 
         #[test]
         fn should_return_0_if_size_is_too_large_for_offset() {
             let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockWriter::new(initial_bytes.clone());
-            let bytes_to_write = [0xaa, 0xbb, 0xcc, 0xdd];
-            let written_length = writer.checked_write_bytes(6, &bytes_to_write);
+            let mut writer = MockWriter::new(initial_bytes);
+            let written_length = writer.checked_write_str(6, "abc");
 
             assert_eq!(written_length, 0);
             assert_eq!(writer.get_bytes(), initial_bytes);
         }
     }
 
-    mod write {
+    mod write_ascii {
         use super::*;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_string() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             let written_length = writer
-                .write(4, &value)
+                .write_ascii(0, "NAME")
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
-
-            let result = writer.read::<u32>(4).expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(writer.get_bytes(), [b'N', b'A', b'M', b'E', 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_return_error_if_string_is_not_ascii() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             let error = writer
-                .write(6, &value)
-                .expect_err("Length should have been too large");
-
-            assert_eq!(
-                error,
-                Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
-                    data_len: 8,
-                }
-            );
-        }
-    }
-
-    mod checked_write {
-        use super::*;
-
-        #[test]
-        fn should_write_value() {
-            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write(4, &value);
-
-            assert_eq!(written_length, 4);
-
-            let result = writer.read::<u32>(4).expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+                .write_ascii(0, "caf\u{e9}")
+                .expect_err("String should have been rejected");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String contains non-ASCII characters",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
+    }
+
+    mod checked_write_ascii {
+        use super::*;
 
         #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockWriter::new(bytes.clone());
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write(6, &value);
+        fn should_return_0_if_string_is_not_ascii() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(initial_bytes);
+            let written_length = writer.checked_write_ascii(0, "\u{20ac}");
 
             assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+            assert_eq!(writer.get_bytes(), initial_bytes);
         }
     }
 
-    mod write_le {
+    mod write_c_string {
         use super::*;
+        #[cfg(feature = "alloc")]
         use alloc::vec;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_string_with_terminator() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             let written_length = writer
-                .write_le(2, &value)
+                .write_c_string(2, "abc")
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, b'a', b'b', b'c', 0, 7, 8]);
+        }
 
-            let result = writer
-                .read_le::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+        #[test]
+        fn should_write_only_terminator_for_empty_strings() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_c_string(7, "")
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 1);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 0]);
+        }
+
+        #[test]
+        fn should_return_error_if_string_contains_null_byte() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_c_string(0, "ab\0c")
+                .expect_err("String should have been rejected");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String contains an interior null byte",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             let error = writer
-                .write_le(6, &value)
+                .write_c_string(5, "abc")
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
                     wanted_size: 4,
-                    offset: 6,
+                    offset: 5,
                     data_len: 8,
                 }
             );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_grow_a_vector_if_needed() {
-            let mut writer = vec![];
-            let value = 0xaabbccddu32;
+            let mut writer = vec![1];
             let written_length = writer
-                .write_le(2, &value)
+                .write_c_string(2, "hi")
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 4);
-
-            let result = writer
-                .read_le::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
-            assert_eq!(writer.len(), 6);
+            assert_eq!(written_length, 3);
+            assert_eq!(writer, [1, 0, b'h', b'i', 0]);
         }
+    }
+
+    mod write_c_string_padded {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[test]
-        fn should_not_grow_a_vector_if_not_needed() {
-            let mut writer = vec![0; 4];
-            let value = 0xaabbccddu32;
+        fn should_pad_the_field_with_zeroes() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let written_length = writer
-                .write_le(0, &value)
+                .write_c_string_padded(1, "ab", 5)
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 4);
-
-            let result = writer
-                .read_le::<u32>(0)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
-            assert_eq!(writer.len(), 4);
+            assert_eq!(written_length, 5);
+            assert_eq!(writer.get_bytes(), [1, b'a', b'b', 0, 0, 0, 7, 8]);
         }
 
-        #[derive(Debug)]
-        struct CustomErrorTest(u32);
+        #[test]
+        fn should_write_string_that_fills_the_field() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_c_string_padded(0, "abc", 4)
+                .expect("Write should have succeeded");
 
-        impl EndianWrite for CustomErrorTest {
-            fn get_size(&self) -> usize {
-                0
-            }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidRead {
-                    message: "Custom error!",
-                })
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [b'a', b'b', b'c', 0, 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_bubble_up_custom_errors_for_vec() {
-            let value = CustomErrorTest(0);
-            let mut bytes = vec![];
-            let result = bytes.write_le(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
-        }
+        fn should_return_error_if_string_does_not_fit_in_the_field() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_c_string_padded(0, "abcd", 4)
+                .expect_err("String should have been too long");
 
-        #[test]
-        fn should_bubble_up_custom_errors_for_slice() {
-            let value = CustomErrorTest(0);
-            let bytes = &mut [];
-            let result = bytes.write_le(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String does not fit in the field width",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
-        #[derive(Debug)]
-        struct OffsetErrorTest(u32);
+        #[test]
+        fn should_return_error_if_string_contains_null_byte() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_c_string_padded(0, "a\0", 4)
+                .expect_err("String should have been rejected");
 
-        impl EndianWrite for OffsetErrorTest {
-            fn get_size(&self) -> usize {
-                0
-            }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidSize {
-                    wanted_size: 8,
-                    offset: 1,
-                    data_len: 0,
-                })
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String contains an interior null byte",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_bubble_up_error_offsets_for_vec() {
-            let value = OffsetErrorTest(0);
-            let mut bytes = vec![];
-            let result = bytes.write_le(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 2,
-            };
-            assert_eq!(result, expected)
+        fn should_return_error_if_field_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_c_string_padded(6, "a", 4)
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 4,
+                    offset: 6,
+                    data_len: 8,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
-        fn should_bubble_up_error_offsets_for_slice() {
-            let value = OffsetErrorTest(0);
-            let bytes = &mut [];
-            let result = bytes.write_le(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 0,
-            };
-            assert_eq!(result, expected)
+        fn should_grow_a_vector() {
+            let mut writer = vec![1];
+            writer
+                .write_c_string_padded(1, "ab", 4)
+                .expect("Write should have succeeded");
+
+            assert_eq!(writer, [1, b'a', b'b', 0, 0]);
         }
     }
 
-    mod checked_write_le {
+    mod checked_write_c_string {
         use super::*;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_string_with_terminator() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write_le(2, &value);
+            let written_length = writer.checked_write_c_string(0, "abc");
 
             assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [b'a', b'b', b'c', 0, 5, 6, 7, 8]);
+        }
 
-            let result = writer
-                .read_le::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+        #[test]
+        fn should_return_0_if_string_contains_null_byte() {
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(initial_bytes);
+            let written_length = writer.checked_write_c_string(0, "\0");
+
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), initial_bytes);
         }
 
         #[test]
         fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockWriter::new(bytes.clone());
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write_le(6, &value);
+            let initial_bytes = [1, 2, 3, 4, 5, 6, 7, 8];
+            let mut writer = MockWriter::new(initial_bytes);
+            let written_length = writer.checked_write_c_string(6, "abc");
 
             assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+            assert_eq!(writer.get_bytes(), initial_bytes);
         }
     }
 
-    mod write_be {
+    mod write_utf16_le_string {
         use super::*;
+        #[cfg(feature = "alloc")]
         use alloc::vec;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_string_without_padding() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             let written_length = writer
-                .write_be(2, &value)
+                .write_utf16_le_string(2, "ab", None)
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, b'a', 0, b'b', 0, 7, 8]);
+        }
 
-            let result = writer
-                .read_be::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+        #[test]
+        fn should_pad_string_to_field_width() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_utf16_le_string(0, "a", Some(3))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_bytes(), [b'a', 0, 0, 0, 0, 0, 7, 8]);
+        }
+
+        #[test]
+        fn should_write_string_that_exactly_fits_field_width() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_utf16_le_string(0, "abcd", Some(4))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 8);
+            assert_eq!(writer.get_bytes(), [b'a', 0, b'b', 0, b'c', 0, b'd', 0]);
+        }
+
+        #[test]
+        fn should_write_surrogate_pairs() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_utf16_le_string(0, "\u{1f600}", Some(3))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_bytes(), [0x3d, 0xd8, 0x00, 0xde, 0, 0, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_string_exceeds_field_width() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_utf16_le_string(0, "\u{1f600}", Some(1))
+                .expect_err("String should have been too long");
+
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "String does not fit in the field width",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
+
+        #[test]
+        fn should_return_error_if_the_field_size_overflows() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_utf16_le_string(4, "a", Some(usize::MAX))
+                .expect_err("This should have failed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: 4,
+                    size: usize::MAX,
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
         fn should_return_error_if_size_is_too_large_for_offset() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
             let error = writer
-                .write_be(6, &value)
+                .write_utf16_le_string(4, "a", Some(3))
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
-                    wanted_size: 4,
-                    offset: 6,
+                    wanted_size: 6,
+                    offset: 4,
                     data_len: 8,
                 }
             );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
         fn should_grow_a_vector_if_needed() {
-            let mut writer = vec![];
-            let value = 0xaabbccddu32;
+            let mut writer = vec![0xff];
             let written_length = writer
-                .write_be(2, &value)
+                .write_utf16_le_string(1, "a", Some(2))
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
-
-            let result = writer
-                .read_be::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
-            assert_eq!(writer.len(), 6);
+            assert_eq!(writer, [0xff, b'a', 0, 0, 0]);
         }
+    }
+
+    mod write_utf16_be_string {
+        use super::*;
 
         #[test]
-        fn should_not_grow_a_vector_if_not_needed() {
-            let mut writer = vec![0; 4];
-            let value = 0xaabbccddu32;
+        fn should_write_string_without_padding() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let written_length = writer
-                .write_be(0, &value)
+                .write_utf16_be_string(2, "ab", None)
                 .expect("Write should have succeeded");
 
             assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 0, b'a', 0, b'b', 7, 8]);
+        }
 
-            let result = writer
-                .read_be::<u32>(0)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
-            assert_eq!(writer.len(), 4);
+        #[test]
+        fn should_write_surrogate_pairs_with_padding() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_utf16_be_string(0, "\u{1f600}", Some(4))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 8);
+            assert_eq!(writer.get_bytes(), [0xd8, 0x3d, 0xde, 0x00, 0, 0, 0, 0]);
         }
+    }
+
+    mod write_iter_le {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[derive(Debug)]
-        struct CustomErrorTest(u32);
+        struct Repeat(u8, usize);
 
-        impl EndianWrite for CustomErrorTest {
+        impl EndianWrite for Repeat {
             fn get_size(&self) -> usize {
-                0
+                self.1
             }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                if dst.len() < self.1 {
+                    return Err(Error::InvalidSize {
+                        wanted_size: self.1,
+                        offset: 0,
+                        data_len: dst.len(),
+                    });
+                }
+                dst[..self.1].fill(self.0);
+                Ok(self.1)
             }
             fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidRead {
-                    message: "Custom error!",
-                })
+                unimplemented!()
             }
         }
 
         #[test]
-        fn should_bubble_up_custom_errors_for_vec() {
-            let value = CustomErrorTest(0);
-            let mut bytes = vec![];
-            let result = bytes.write_be(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
+        fn should_write_values() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_iter_le(2, (1..=3u16).map(|value| value * 0x1111))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, 2, 0x11, 0x11, 0x22, 0x22, 0x33, 0x33]
+            );
         }
 
         #[test]
-        fn should_bubble_up_custom_errors_for_slice() {
-            let value = CustomErrorTest(0);
-            let bytes = &mut [];
-            let result = bytes.write_be(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
+        fn should_write_values_with_dynamic_sizes() {
+            let mut writer = MockWriter::new([0; 8]);
+            let values = [(0xaa, 1), (0xbb, 3), (0xcc, 2)];
+            let written_length = writer
+                .write_iter_le(1, values.iter().map(|(byte, len)| Repeat(*byte, *len)))
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [0, 0xaa, 0xbb, 0xbb, 0xbb, 0xcc, 0xcc, 0]
+            );
         }
 
-        #[derive(Debug)]
-        struct OffsetErrorTest(u32);
+        #[test]
+        fn should_write_nothing_for_empty_iterators() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let written_length = writer
+                .write_iter_le(8, core::iter::empty::<u32>())
+                .expect("Write should have succeeded");
 
-        impl EndianWrite for OffsetErrorTest {
-            fn get_size(&self) -> usize {
-                0
-            }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidSize {
-                    wanted_size: 8,
-                    offset: 1,
-                    data_len: 0,
-                })
-            }
+            assert_eq!(written_length, 0);
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_bubble_up_error_offsets_for_vec() {
-            let value = OffsetErrorTest(0);
-            let mut bytes = vec![];
-            let result = bytes.write_be(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 2,
-            };
-            assert_eq!(result, expected)
+        fn should_return_error_if_space_runs_out_partway() {
+            let mut writer = MockWriter::new([0; 8]);
+            let values = [(0xaa, 2), (0xbb, 3), (0xcc, 4)];
+            let error = writer
+                .write_iter_le(1, values.iter().map(|(byte, len)| Repeat(*byte, *len)))
+                .expect_err("Length should have been too large");
+
+            assert_eq!(
+                error,
+                Error::IncompleteIterWrite {
+                    offset: 6,
+                    items_written: 2,
+                    #[cfg(feature = "alloc")]
+                    source: Box::new(Error::InvalidSize {
+                        wanted_size: 4,
+                        offset: 6,
+                        data_len: 8,
+                    }),
+                }
+            );
+            assert_eq!(writer.get_bytes(), [0, 0xaa, 0xaa, 0xbb, 0xbb, 0xbb, 0, 0]);
         }
 
+        #[cfg(feature = "alloc")]
         #[test]
-        fn should_bubble_up_error_offsets_for_slice() {
-            let value = OffsetErrorTest(0);
-            let bytes = &mut [];
-            let result = bytes.write_be(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 0,
-            };
-            assert_eq!(result, expected)
+        fn should_grow_a_vector_if_needed() {
+            let mut writer = vec![];
+            let written_length = writer
+                .write_iter_le(1, [0x1122u16, 0x3344])
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            assert_eq!(writer, [0, 0x22, 0x11, 0x44, 0x33]);
         }
     }
 
-    mod checked_write_be {
+    mod write_iter_be {
         use super::*;
 
         #[test]
-        fn should_write_value() {
+        fn should_write_values() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write_be(2, &value);
-
-            assert_eq!(written_length, 4);
+            let written_length = writer
+                .write_iter_be(2, [0x1122u16, 0x3344, 0x5566])
+                .expect("Write should have succeeded");
 
-            let result = writer
-                .read_be::<u32>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, 0xaabbccddu32);
+            assert_eq!(written_length, 6);
+            assert_eq!(
+                writer.get_bytes(),
+                [1, 2, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66]
+            );
         }
 
         #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockWriter::new(bytes.clone());
-            let value = 0xaabbccddu32;
-            let written_length = writer.checked_write_be(6, &value);
+        fn should_return_error_if_space_runs_out_partway() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let error = writer
+                .write_iter_be(3, [0x1122u16, 0x3344, 0x5566])
+                .expect_err("Length should have been too large");
 
-            assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+            assert_eq!(
+                error,
+                Error::IncompleteIterWrite {
+                    offset: 7,
+                    items_written: 2,
+                    #[cfg(feature = "alloc")]
+                    source: Box::new(Error::InvalidSize {
+                        wanted_size: 2,
+                        offset: 7,
+                        data_len: 8,
+                    }),
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 0x11, 0x22, 0x33, 0x44, 8]);
         }
     }
 
-    mod write_array_le {
+    #[cfg(feature = "alloc")]
+    mod write_array_le_all {
         use super::*;
         use alloc::vec;
 
+        #[derive(Debug)]
+        struct FailingValue(u16, bool);
+
+        impl EndianWrite for FailingValue {
+            fn get_size(&self) -> usize {
+                2
+            }
+            fn try_write_le(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                if self.1 {
+                    // Leave behind partial data to make sure it gets restored
+                    dst[0] = 0xff;
+                    return Err(Error::InvalidWrite {
+                        message: "Custom error!",
+                    });
+                }
+                dst[..2].copy_from_slice(&self.0.to_le_bytes());
+                Ok(2)
+            }
+            fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                if self.1 {
+                    dst[0] = 0xff;
+                    return Err(Error::InvalidWrite {
+                        message: "Custom error!",
+                    });
+                }
+                dst[..2].copy_from_slice(&self.0.to_be_bytes());
+                Ok(2)
+            }
+        }
+
         #[test]
         fn should_write_value() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344, 0x5566];
             let written_length = writer
-                .write_array_le(2, &value)
+                .write_array_le_all(2, &[0x1122u16, 0x3344])
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 6);
-
-            let result = writer
-                .read_array_le::<3, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x22, 0x11, 0x44, 0x33, 7, 8]);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
+        fn should_not_write_anything_if_size_is_too_large_for_offset() {
             let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344, 0x5566];
             let error = writer
-                .write_array_le(6, &value)
+                .write_array_le_all(4, &[0x1122u16, 0x3344, 0x5566])
                 .expect_err("Length should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
-                    wanted_size: 2,
-                    offset: 8,
+                    wanted_size: 6,
+                    offset: 4,
                     data_len: 8,
                 }
             );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
 
         #[test]
-        fn should_grow_a_vector_if_needed() {
-            let mut writer = vec![];
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer
-                .write_array_le(2, &value)
-                .expect("Write should have succeeded");
+        fn should_restore_original_bytes_if_a_value_fails() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let values = [
+                FailingValue(0x1122, false),
+                FailingValue(0x3344, true),
+                FailingValue(0x5566, false),
+            ];
+            let error = writer
+                .write_array_le_all(1, &values)
+                .expect_err("Write should have failed");
 
-            assert_eq!(written_length, 6);
+            assert_eq!(
+                error,
+                Error::InvalidWrite {
+                    message: "Custom error!",
+                }
+            );
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        }
 
-            let result = writer
-                .read_array_le::<3, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
-            assert_eq!(writer.len(), 8);
+        #[test]
+        fn should_restore_original_vector_bytes_if_a_value_fails() {
+            let mut writer = vec![1, 2, 3, 4];
+            let values = [FailingValue(0x1122, false), FailingValue(0x3344, true)];
+            writer
+                .write_array_le_all(0, &values)
+                .expect_err("Write should have failed");
+
+            assert_eq!(writer, [1, 2, 3, 4]);
         }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod write_array_be_all {
+        use super::*;
 
         #[test]
-        fn should_not_grow_a_vector_if_not_needed() {
-            let mut writer = vec![0; 6];
-            let value = [0x1122u16, 0x3344, 0x5566];
+        fn should_write_value() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
             let written_length = writer
-                .write_array_le(0, &value)
+                .write_array_be_all(2, &[0x1122u16, 0x3344])
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 6);
-
-            let result = writer
-                .read_array_le::<3, u16>(0)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
-            assert_eq!(writer.len(), 6);
-        }
-
-        #[derive(Debug)]
-        struct CustomErrorTest(u32);
-
-        impl EndianWrite for CustomErrorTest {
-            fn get_size(&self) -> usize {
-                0
-            }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidRead {
-                    message: "Custom error!",
-                })
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_bytes(), [1, 2, 0x11, 0x22, 0x33, 0x44, 7, 8]);
         }
 
         #[test]
-        fn should_bubble_up_custom_errors_for_vec() {
-            let value = [CustomErrorTest(0)];
-            let mut bytes = vec![];
-            let result = bytes.write_array_le(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
-        }
+        fn should_not_write_anything_if_size_is_too_large_for_offset() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            writer
+                .write_array_be_all(6, &[0x1122u16, 0x3344])
+                .expect_err("Length should have been too large");
 
-        #[test]
-        fn should_bubble_up_custom_errors_for_slice() {
-            let value = [CustomErrorTest(0)];
-            let bytes = &mut [];
-            let result = bytes.write_array_le(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
+    }
+
+    #[cfg(feature = "alloc")]
+    mod write_slice_be_all {
+        use super::*;
+        use alloc::vec::Vec;
 
         #[derive(Debug)]
-        struct OffsetErrorTest(u32);
+        struct FailingValue(u16, bool);
 
-        impl EndianWrite for OffsetErrorTest {
+        impl EndianWrite for FailingValue {
             fn get_size(&self) -> usize {
-                0
+                2
             }
             fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidSize {
-                    wanted_size: 8,
-                    offset: 1,
-                    data_len: 0,
-                })
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
                 unimplemented!()
             }
+            fn try_write_be(&self, dst: &mut [u8]) -> Result<usize, Error> {
+                if self.1 {
+                    return Err(Error::InvalidWrite {
+                        message: "Custom error!",
+                    });
+                }
+                dst[..2].copy_from_slice(&self.0.to_be_bytes());
+                Ok(2)
+            }
         }
 
         #[test]
-        fn should_bubble_up_error_offsets_for_vec() {
-            let value = [OffsetErrorTest(0)];
-            let mut bytes = vec![];
-            let result = bytes.write_array_le(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 2,
-            };
-            assert_eq!(result, expected)
+        fn should_write_values() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let values: Vec<u16> = (1..=3).collect();
+            let written_length = writer
+                .write_slice_be_all(2, &values)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 6);
+            assert_eq!(writer.get_bytes(), [1, 2, 0, 1, 0, 2, 0, 3]);
         }
 
         #[test]
-        fn should_bubble_up_error_offsets_for_slice() {
-            let value = [OffsetErrorTest(0)];
-            let bytes = &mut [];
-            let result = bytes.write_array_le(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 0,
-            };
-            assert_eq!(result, expected)
+        fn should_restore_original_bytes_if_a_value_fails() {
+            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
+            let values = [FailingValue(0x1122, false), FailingValue(0x3344, true)];
+            writer
+                .write_slice_be_all(0, &values)
+                .expect_err("Write should have failed");
+
+            assert_eq!(writer.get_bytes(), [1, 2, 3, 4, 5, 6, 7, 8]);
         }
     }
 
-    mod checked_write_array_le {
+    #[cfg(feature = "alloc")]
+    mod insert_bytes {
         use super::*;
+        use alloc::vec;
 
         #[test]
-        fn should_write_value() {
-            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer.checked_write_array_le(2, &value);
+        fn should_insert_bytes_at_the_start() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer
+                .insert_bytes(0, &[0xaa, 0xbb])
+                .expect("Insert should have succeeded");
 
-            assert_eq!(written_length, 6);
+            assert_eq!(written_length, 2);
+            assert_eq!(writer, [0xaa, 0xbb, 1, 2, 3]);
+        }
 
-            let result = writer
-                .read_array_le::<3, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        #[test]
+        fn should_insert_bytes_in_the_middle() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer
+                .insert_bytes(2, &[0xaa, 0xbb])
+                .expect("Insert should have succeeded");
+
+            assert_eq!(written_length, 2);
+            assert_eq!(writer, [1, 2, 0xaa, 0xbb, 3]);
         }
 
         #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockWriter::new(bytes.clone());
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer.checked_write_array_le(6, &value);
+        fn should_insert_bytes_at_the_end() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer
+                .insert_bytes(3, &[0xaa])
+                .expect("Insert should have succeeded");
 
-            assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+            assert_eq!(written_length, 1);
+            assert_eq!(writer, [1, 2, 3, 0xaa]);
+        }
+
+        #[test]
+        fn should_fill_the_gap_if_offset_is_past_the_end() {
+            let mut writer = vec![1, 2, 3];
+            let written_length = writer
+                .insert_bytes(5, &[0xaa])
+                .expect("Insert should have succeeded");
+
+            assert_eq!(written_length, 1);
+            assert_eq!(writer, [1, 2, 3, 0, 0, 0xaa]);
         }
     }
 
-    mod write_array_be {
+    #[cfg(feature = "alloc")]
+    mod remove_bytes {
         use super::*;
         use alloc::vec;
 
         #[test]
-        fn should_write_value() {
-            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer
-                .write_array_be(2, &value)
-                .expect("Write should have succeeded");
+        fn should_remove_bytes_at_the_start() {
+            let mut writer = vec![1, 2, 3, 4];
+            let removed_length = writer
+                .remove_bytes(0, 2)
+                .expect("Remove should have succeeded");
+
+            assert_eq!(removed_length, 2);
+            assert_eq!(writer, [3, 4]);
+        }
 
-            assert_eq!(written_length, 6);
+        #[test]
+        fn should_remove_bytes_in_the_middle() {
+            let mut writer = vec![1, 2, 3, 4];
+            let removed_length = writer
+                .remove_bytes(1, 2)
+                .expect("Remove should have succeeded");
+
+            assert_eq!(removed_length, 2);
+            assert_eq!(writer, [1, 4]);
+        }
 
-            let result = writer
-                .read_array_be::<3, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        #[test]
+        fn should_remove_bytes_that_reach_the_end() {
+            let mut writer = vec![1, 2, 3, 4];
+            let removed_length = writer
+                .remove_bytes(2, 2)
+                .expect("Remove should have succeeded");
+
+            assert_eq!(removed_length, 2);
+            assert_eq!(writer, [1, 2]);
         }
 
         #[test]
-        fn should_return_error_if_size_is_too_large_for_offset() {
-            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344, 0x5566];
+        fn should_return_error_if_range_is_past_the_end() {
+            let mut writer = vec![1, 2, 3, 4];
             let error = writer
-                .write_array_be(6, &value)
-                .expect_err("Length should have been too large");
+                .remove_bytes(3, 2)
+                .expect_err("Range should have been too large");
 
             assert_eq!(
                 error,
                 Error::InvalidSize {
                     wanted_size: 2,
-                    offset: 8,
-                    data_len: 8,
+                    offset: 3,
+                    data_len: 4,
                 }
             );
+            assert_eq!(writer, [1, 2, 3, 4]);
         }
+    }
+
+    mod dyn_writer {
+        use super::*;
 
         #[test]
-        fn should_grow_a_vector_if_needed() {
-            let mut writer = vec![];
-            let value = [0x1122u16, 0x3344, 0x5566];
+        fn should_be_usable_as_a_trait_object() {
+            let mut bytes = [0; 4];
+            let writer: &mut dyn Writer = &mut bytes;
             let written_length = writer
-                .write_array_be(2, &value)
+                .write_bytes(1, &[0xaa, 0xbb])
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 6);
+            assert_eq!(written_length, 2);
+            assert_eq!(bytes, [0, 0xaa, 0xbb, 0]);
+        }
 
-            let result = writer
-                .read_array_be::<3, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
-            assert_eq!(writer.len(), 8);
+        #[test]
+        fn should_call_extension_methods_on_a_trait_object() {
+            let mut bytes = [0; 4];
+            let mut writer: &mut dyn Writer = &mut bytes;
+            let written_length = writer.checked_write_le(0, &0x2211u16);
+
+            assert_eq!(written_length, 2);
+            assert_eq!(bytes, [0x11, 0x22, 0, 0]);
         }
 
         #[test]
-        fn should_not_grow_a_vector_if_not_needed() {
-            let mut writer = vec![0; 6];
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer
-                .write_array_be(0, &value)
+        fn should_write_endian_values_through_a_reference_to_a_trait_object() {
+            let mut bytes = [0; 4];
+            let mut writer: &mut dyn Writer = &mut bytes;
+            let written_length = (&mut writer)
+                .write_be(2, &0x1122u16)
                 .expect("Write should have succeeded");
 
-            assert_eq!(written_length, 6);
+            assert_eq!(written_length, 2);
+            assert_eq!(bytes, [0, 0, 0x11, 0x22]);
+        }
 
-            let result = writer
-                .read_array_be::<3, u16>(0)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
-            assert_eq!(writer.len(), 6);
+        #[test]
+        fn should_return_error_if_endian_value_does_not_fit_a_trait_object() {
+            let mut bytes = [0; 4];
+            let mut writer: &mut dyn Writer = &mut bytes;
+            let error = (&mut writer)
+                .write_le(3, &0x1122u16)
+                .expect_err("Write should have failed");
+
+            assert_eq!(
+                error,
+                Error::InvalidSize {
+                    wanted_size: 2,
+                    offset: 3,
+                    data_len: 4,
+                }
+            );
+            assert_eq!(bytes, [0; 4]);
         }
 
-        #[derive(Debug)]
-        struct CustomErrorTest(u32);
+        #[test]
+        fn should_call_extension_methods_on_concrete_types() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer.checked_write_be(4, &0x11223344u32);
 
-        impl EndianWrite for CustomErrorTest {
-            fn get_size(&self) -> usize {
-                0
-            }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidRead {
-                    message: "Custom error!",
-                })
-            }
+            assert_eq!(written_length, 4);
+            assert_eq!(writer.get_mut_slice(), [0, 0, 0, 0, 0x11, 0x22, 0x33, 0x44]);
         }
 
         #[test]
-        fn should_bubble_up_custom_errors_for_vec() {
-            let value = [CustomErrorTest(0)];
-            let mut bytes = vec![];
-            let result = bytes.write_array_be(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
+        #[cfg(feature = "alloc")]
+        fn should_grow_a_boxed_vector_trait_object() {
+            let mut vec = Vec::new();
+            let mut writer: Box<dyn Writer + '_> = Box::new(&mut vec);
+            let written_length = writer
+                .write_le(0, &0x11223344u32)
+                .expect("Write should have succeeded");
+
+            assert_eq!(written_length, 4);
+            drop(writer);
+            assert_eq!(vec, [0x44, 0x33, 0x22, 0x11]);
         }
+    }
+
+    mod offset_overflow {
+        use super::*;
+        #[cfg(feature = "alloc")]
+        use alloc::vec;
 
         #[test]
-        fn should_bubble_up_custom_errors_for_slice() {
-            let value = [CustomErrorTest(0)];
-            let bytes = &mut [];
-            let result = bytes.write_array_be(0, &value).unwrap_err();
-            let expected = Error::InvalidRead {
-                message: "Custom error!",
-            };
-            assert_eq!(result, expected)
+        fn should_return_error_if_slice_end_overflows() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .get_sized_mut_slice(usize::MAX, 2)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 2,
+                }
+            );
         }
 
-        #[derive(Debug)]
-        struct OffsetErrorTest(u32);
+        #[test]
+        fn should_return_error_if_copy_source_overflows() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .copy_within(usize::MAX, 0, 2)
+                .expect_err("Offset should have overflowed");
 
-        impl EndianWrite for OffsetErrorTest {
-            fn get_size(&self) -> usize {
-                0
-            }
-            fn try_write_le(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                unimplemented!()
-            }
-            fn try_write_be(&self, _dst: &mut [u8]) -> Result<usize, Error> {
-                Err(Error::InvalidSize {
-                    wanted_size: 8,
-                    offset: 1,
-                    data_len: 0,
-                })
-            }
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 2,
+                }
+            );
         }
 
         #[test]
-        fn should_bubble_up_error_offsets_for_vec() {
-            let value = [OffsetErrorTest(0)];
-            let mut bytes = vec![];
-            let result = bytes.write_array_be(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 2,
-            };
-            assert_eq!(result, expected)
+        fn should_return_error_if_bit_range_overflows() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_bits_lsb(0, usize::MAX, 4, 0)
+                .expect_err("Bit offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 4,
+                }
+            );
+            assert_eq!(writer.get_mut_slice(), [0; 8]);
         }
 
         #[test]
-        fn should_bubble_up_error_offsets_for_slice() {
-            let value = [OffsetErrorTest(0)];
-            let bytes = &mut [];
-            let result = bytes.write_array_be(2, &value).unwrap_err();
-            let expected = Error::InvalidSize {
-                wanted_size: 8,
-                offset: 3,
-                data_len: 0,
-            };
-            assert_eq!(result, expected)
+        fn should_return_error_if_aligned_offset_overflows() {
+            let mut writer = MockWriter::new([0; 8]);
+            let error = writer
+                .write_le_aligned(usize::MAX, &0u8, 2)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 1,
+                }
+            );
         }
-    }
 
-    mod checked_write_array_be {
-        use super::*;
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_not_grow_a_vector_if_the_end_overflows() {
+            let mut writer: Vec<u8> = Vec::new();
+            let error = writer
+                .write_le(usize::MAX - 1, &0u32)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX - 1,
+                    size: 4,
+                }
+            );
+            assert!(writer.is_empty());
+        }
 
+        #[cfg(feature = "alloc")]
         #[test]
-        fn should_write_value() {
-            let mut writer = MockWriter::new([1, 2, 3, 4, 5, 6, 7, 8]);
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer.checked_write_array_be(2, &value);
+        fn should_not_grow_a_vector_if_the_array_end_overflows() {
+            let mut writer: Vec<u8> = Vec::new();
+            let error = writer
+                .write_array_le(usize::MAX - 5, &[0u32, 0])
+                .expect_err("Offset should have overflowed");
 
-            assert_eq!(written_length, 6);
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX - 1,
+                    size: 4,
+                }
+            );
+            assert!(writer.is_empty());
+        }
 
-            let result = writer
-                .read_array_be::<3, u16>(2)
-                .expect("Read should have succeeded");
-            assert_eq!(result, [0x1122u16, 0x3344, 0x5566]);
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn should_return_error_if_removed_range_overflows() {
+            let mut writer = vec![1, 2, 3, 4];
+            let error = writer
+                .remove_bytes(usize::MAX, 2)
+                .expect_err("Offset should have overflowed");
+
+            assert_eq!(
+                error,
+                Error::OffsetOverflow {
+                    offset: usize::MAX,
+                    size: 2,
+                }
+            );
+            assert_eq!(writer, [1, 2, 3, 4]);
         }
 
         #[test]
-        fn should_return_0_if_size_is_too_large_for_offset() {
-            let bytes = [1, 2, 3, 4, 5, 6, 7, 8];
-            let mut writer = MockWriter::new(bytes.clone());
-            let value = [0x1122u16, 0x3344, 0x5566];
-            let written_length = writer.checked_write_array_be(6, &value);
+        fn should_return_zero_if_checked_array_end_overflows() {
+            let mut writer = MockWriter::new([0; 8]);
+            let written_length = writer.checked_write_array_le(usize::MAX - 1, &[0u16, 0]);
 
             assert_eq!(written_length, 0);
-            assert_eq!(writer.get_bytes(), bytes);
+            assert_eq!(writer.get_mut_slice(), [0; 8]);
         }
     }
 }